@@ -2,18 +2,15 @@
 use crate::{
     error::{SystemError, WorgenXError},
     password::{self, PasswordConfig},
-    system,
+    progress::{ProgressGoal, RunContext},
+    rng::WorgenXRng,
 };
 
 // External crates.
-use indicatif::ProgressBar;
 use std::{
-    sync::{
-        atomic::{AtomicBool, AtomicU64, Ordering},
-        mpsc, Arc, Mutex,
-    },
+    sync::Arc,
     thread::{self, JoinHandle},
-    time::{Duration, Instant},
+    time::Duration,
 };
 
 /// This constant structure is used to define the password configuration for the benchmark.
@@ -25,14 +22,54 @@ const PASSWORD_CONFIG: PasswordConfig = PasswordConfig {
     uppercase: true,
     lowercase: true,
     length: 10000,
+    max_length: 0,
     number_of_passwords: 1, // Don't change this value, it's used to generate a single password on each iteration.
+    class_coverage: false,
+    min_numbers: 0,
+    min_special_characters: 0,
+    min_uppercase: 0,
+    min_lowercase: 0,
+    charset: String::new(),
+    exclude: String::new(),
+    no_ambiguous: false,
+    pronounceable: false,
+    template: String::new(),
+    unique: false,
+    max_repeated: 0,
+    forbidden_sequences: Vec::new(),
 };
 
-/// This static variable is used to track the number of passwords generated.
-/// It is used to update the progress bar.
-/// It is wrapped in a AtomicU64 to avoid data sharing issues between the threads.
+/// This constant is used to define the duration of the benchmark.
 ///
-static GLOBAL_COUNTER: AtomicU64 = AtomicU64::new(0);
+const BENCHMARK_DURATION: Duration = Duration::from_secs(60);
+
+/// This constant is used to define the number of passwords generated by a thread between two updates of the progress reporter.
+///
+const PROGRESS_BATCH_SIZE: u64 = 100;
+
+/// This constant is used to define the duration of the short benchmark measuring the guess rate of the CPU.
+///
+const GUESS_RATE_BENCHMARK_DURATION: Duration = Duration::from_secs(5);
+
+/// This constant is used to define the number of characters of a guess, to convert the characters generated by the benchmark into guesses.
+///
+const GUESS_LENGTH: f64 = 10.0;
+
+/// This constant defines the number of guesses per second of the online attack scenarios, used to estimate the time to crack a password.
+/// These rates are set by the throttling of the service attacked, not by the hardware of the attacker.
+///
+pub const ONLINE_CRACKING_RATES: [(&str, f64); 2] = [
+    ("online attack, throttled (100 guesses per hour)", 100.0 / 3600.0),
+    ("online attack, unthrottled (10 guesses per second)", 10.0),
+];
+
+/// This constant defines the reference number of guesses per second of the offline attack scenarios.
+/// They are the orders of magnitude of a cracking rig with several GPUs, used as a fallback when the offline rate is neither given nor measured.
+///
+pub const OFFLINE_CRACKING_RATES: [(&str, f64); 2] = [
+    ("offline attack, slow hash like bcrypt (reference rate, 10^4 guesses per second)", 1e4),
+    ("offline attack, fast hash like MD5 or SHA-1 (reference rate, 10^10 guesses per second)", 1e10),
+];
 
 /// This function is responsible for loading the CPU benchmark.
 ///
@@ -45,44 +82,54 @@ static GLOBAL_COUNTER: AtomicU64 = AtomicU64::new(0);
 /// * `Result<u64, WorgenXError>` - The number of passwords generated in 60 seconds, WorgenXError otherwise.
 ///
 pub fn load_cpu_benchmark(nb_of_threads: usize) -> Result<u64, WorgenXError> {
-    let (tx_progress_bar, rx_progress_bar) = mpsc::channel::<Result<u64, WorgenXError>>();
-    let pb: Arc<Mutex<ProgressBar>> = Arc::new(Mutex::new(system::get_progress_bar()));
-    let pb_clone: Arc<Mutex<ProgressBar>> = Arc::clone(&pb);
-    let mut threads: Vec<JoinHandle<()>> = Vec::new();
-    let shared_signal: Arc<AtomicBool> = Arc::new(AtomicBool::new(true));
-    
-    let progress_bar_thread: JoinHandle<Result<(), WorgenXError>> = thread::spawn(move || {
-        println!("WorgenX CPU Benchmark is in progress...");
-        for received in rx_progress_bar {
-            build_wordlist_progress_bar(received?, &pb_clone);
-        }
+    run_cpu_benchmark(nb_of_threads, BENCHMARK_DURATION, false)
+}
 
-        build_wordlist_progress_bar(60, &pb_clone);
-        Ok(())
-    });
+/// This function is responsible for measuring the number of guesses per second the CPU can generate, without the cost of any hash.
+/// The characters generated by a short benchmark are converted into guesses of 10 characters.
+///
+/// # Arguments
+///
+/// * `nb_of_threads` - The number of threads to use for the stress test.
+///
+/// # Returns
+///
+/// * `Result<f64, WorgenXError>` - The number of guesses per second, WorgenXError otherwise.
+///
+pub fn measure_guess_rate(nb_of_threads: usize) -> Result<f64, WorgenXError> {
+    let nb_of_passwords: u64 = run_cpu_benchmark(nb_of_threads, GUESS_RATE_BENCHMARK_DURATION, true)?;
+    Ok(nb_of_passwords as f64 * PASSWORD_CONFIG.length as f64 / GUESS_LENGTH / GUESS_RATE_BENCHMARK_DURATION.as_secs_f64())
+}
 
-    let start: Instant = Instant::now();
+/// This function is responsible for running the CPU benchmark during a given duration.
+/// Every call works on its own context, so the result doesn't depend on the previous benchmarks.
+///
+/// # Arguments
+///
+/// * `nb_of_threads` - The number of threads to use for the stress test.
+/// * `duration` - The duration of the benchmark.
+/// * `no_loading_bar` - A boolean to specify if the loading bar should be displayed or not.
+///
+/// # Returns
+///
+/// * `Result<u64, WorgenXError>` - The number of passwords generated during the benchmark, WorgenXError otherwise.
+///
+fn run_cpu_benchmark(nb_of_threads: usize, duration: Duration, no_loading_bar: bool) -> Result<u64, WorgenXError> {
+    let context: Arc<RunContext> = Arc::new(RunContext::new(ProgressGoal::Duration(duration), no_loading_bar));
+    let mut threads: Vec<JoinHandle<()>> = Vec::new();
+
+    if !no_loading_bar {
+        println!("WorgenX CPU Benchmark is in progress...");
+    }
     for _ in 0..nb_of_threads {
-        let shared_signal_rst: Arc<AtomicBool> = Arc::clone(&shared_signal);
+        let context: Arc<RunContext> = Arc::clone(&context);
         threads.push(thread::spawn(move || {
-            run_stress_test(&shared_signal_rst).unwrap_or_else(|e| println!("{}", e));
+            run_stress_test(&context).unwrap_or_else(|e| println!("{}", e));
         }));
     }
 
-    while start.elapsed().as_secs() <= 60 {
-        tx_progress_bar
-            .send(Ok(start.elapsed().as_secs()))
-            .unwrap_or(());
-        thread::sleep(Duration::from_millis(500));
-    }
-    shared_signal.store(false, Ordering::SeqCst); // Stop the stress test
-    drop(tx_progress_bar); // Drop the channel to stop the progress bar thread
-
-    let _: Result<(), WorgenXError> = progress_bar_thread.join().map_err(|_| {
-        WorgenXError::SystemError(SystemError::ThreadError(
-            "Something went wrong with the progress bar thread".to_string(),
-        ))
-    })?;
+    thread::sleep(duration);
+    context.stop(); // Stop the stress test
 
     for thread in threads {
         thread.join().map_err(|_| {
@@ -91,55 +138,37 @@ pub fn load_cpu_benchmark(nb_of_threads: usize) -> Result<u64, WorgenXError> {
             ))
         })?;
     }
+    context.progress.finish("Benchmark done!");
 
-    let nb_of_passwd: u64 = GLOBAL_COUNTER.load(Ordering::SeqCst);
-    Ok(nb_of_passwd)
+    Ok(context.progress.get_nb_of_passwords())
 }
 
 /// This function is responsible for running the stress test in a thread.
-/// It generates a lot of passwords in a loop.
-/// The number of passwords generated is stored in a shared counter.
+/// It generates a lot of passwords in a loop until the run is stopped.
+/// The number of passwords generated is reported in batches to the progress reporter of the run.
 ///
 /// # Arguments
 ///
-/// * `stop_signal` - The stop signal to stop the stress test.
+/// * `context` - The context of the benchmark run.
 ///
 /// # Returns
 ///
 /// Ok(()) if the stress test succeed, WorgenXError otherwise.
 ///
-fn run_stress_test(stop_signal: &Arc<AtomicBool>) -> Result<(), WorgenXError> {
-    let mut nb_of_passwd: u64 = 0;
+fn run_stress_test(context: &RunContext) -> Result<(), WorgenXError> {
+    let mut nb_of_passwd_in_batch: u64 = 0;
+    let mut rng: WorgenXRng = WorgenXRng::new(None);
     loop {
-        if !stop_signal.load(Ordering::SeqCst) {
-            GLOBAL_COUNTER.fetch_add(nb_of_passwd, Ordering::SeqCst);
+        if context.is_stopped() {
+            context.progress.add(nb_of_passwd_in_batch, 0);
             return Ok(());
         }
 
-        password::generate_random_passwords(&PASSWORD_CONFIG);
-        nb_of_passwd += 1;
-    }
-}
-
-/// This function is responsible for building the progress bar during the benchmark.
-///
-/// # Arguments
-///
-/// * `seconds` - The number of seconds elapsed.
-/// * `pb` - The progress bar instance (from the indicatif crate).
-///
-fn build_wordlist_progress_bar(seconds: u64, pb: &Arc<Mutex<ProgressBar>>) {
-    let mut pourcentage: u64 = (seconds * 100) / 60;
-    if pourcentage == 0 {
-        pourcentage += 1;
-    }
-    if let Ok(pb) = pb.try_lock() {
-        if pourcentage < 100 {
-            pb.set_position(pourcentage);
-            pb.set_message("Loading...");
-        } else {
-            pb.set_position(100);
-            pb.finish_with_message(String::from("Benchmark done!"));
+        password::generate_random_passwords(&PASSWORD_CONFIG, &mut rng);
+        nb_of_passwd_in_batch += 1;
+        if nb_of_passwd_in_batch == PROGRESS_BATCH_SIZE {
+            context.progress.add(nb_of_passwd_in_batch, 0);
+            nb_of_passwd_in_batch = 0;
         }
     }
 }
@@ -153,4 +182,18 @@ mod tests {
         let result: Result<u64, WorgenXError> = load_cpu_benchmark(4);
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_run_cpu_benchmark_twice() {
+        let first: u64 = run_cpu_benchmark(1, Duration::from_secs(1), true).unwrap();
+        let second: u64 = run_cpu_benchmark(1, Duration::ZERO, true).unwrap();
+        assert!(first > 1);
+        assert!(second < first); // The second run must not include the passwords of the first one
+    }
+
+    #[test]
+    fn test_measure_guess_rate() {
+        let rate: f64 = measure_guess_rate(1).unwrap();
+        assert!(rate > 0.0);
+    }
 }
@@ -4,3 +4,77 @@ pub const LOWERCASE: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
 pub const UPPERCASE: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ";
 pub const NUMBERS: &[u8] = b"0123456789";
 pub const SPECIAL_CHARACTERS: &[u8] = b"!\"#$%&'()*+,-./:;<=>?@[\\]_{|}";
+
+/// The characters that look alike in many fonts, removed with the no-ambiguous option.
+///
+pub const AMBIGUOUS_CHARACTERS: &[u8] = b"0O1lI|";
+
+/// The letters used to build the syllables of the pronounceable passwords and the password templates.
+///
+pub const CONSONANTS: &[u8] = b"bcdfghjklmnpqrstvwxz";
+pub const VOWELS: &[u8] = b"aeiou";
+pub const UPPERCASE_CONSONANTS: &[u8] = b"BCDFGHJKLMNPQRSTVWXZ";
+pub const UPPERCASE_VOWELS: &[u8] = b"AEIOU";
+
+/// The alphabets of the hexadecimal, Base64 (RFC 4648) and Base58 (Bitcoin, without 0, O, I and l) encodings.
+///
+pub const HEX_LOWERCASE: &[u8] = b"0123456789abcdef";
+pub const HEX_UPPERCASE: &[u8] = b"0123456789ABCDEF";
+pub const BASE64: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+pub const BASE58: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// All the characters of the default dictionaries, used by the `?a` placeholder of the password templates.
+///
+pub const ALL_CHARACTERS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789!\"#$%&'()*+,-./:;<=>?@[\\]_{|}";
+
+/// The EFF large wordlist, the default list of words of the passphrases.
+/// It's embedded in the binary so passphrases can be generated without any file.
+///
+pub const EFF_LARGE_WORDLIST: &str = include_str!("wordlists/eff_large_wordlist.txt");
+
+/// The most common passwords and words, one per line, sorted from the most to the least common.
+/// The leaked passwords come first, then the common first names and surnames, then the most frequent English words.
+/// The rank of a word is used by the strength checker to estimate the number of guesses needed to find it.
+///
+pub const COMMON_WORDS: &str = include_str!("wordlists/common_words.txt");
+
+/// The rows of a QWERTY keyboard, without and with the shift key, used by the strength checker to detect the keyboard walks.
+///
+pub const KEYBOARD_ROWS: [&str; 4] = ["`1234567890-=", "qwertyuiop[]\\", "asdfghjkl;'", "zxcvbnm,./"];
+pub const SHIFTED_KEYBOARD_ROWS: [&str; 4] = ["~!@#$%^&*()_+", "QWERTYUIOP{}|", "ASDFGHJKL:\"", "ZXCVBNM<>?"];
+
+/// The horizontal offset of each row of a QWERTY keyboard, in keys.
+///
+pub const KEYBOARD_ROW_OFFSETS: [f64; 4] = [0.0, 1.5, 1.75, 2.25];
+
+/// The built-in charsets that can be added by name to the alphabet of the passwords and the wordlists.
+/// The keyboard rows are the rows of a QWERTY keyboard without the shift key.
+///
+pub const BUILTIN_CHARSETS: [(&str, &[u8]); 10] = [
+    ("hex", HEX_LOWERCASE),
+    ("hex-upper", HEX_UPPERCASE),
+    ("base64", BASE64),
+    ("base58", BASE58),
+    ("vowels", VOWELS),
+    ("consonants", CONSONANTS),
+    ("keyboard-numbers", KEYBOARD_ROWS[0].as_bytes()),
+    ("keyboard-top", KEYBOARD_ROWS[1].as_bytes()),
+    ("keyboard-home", KEYBOARD_ROWS[2].as_bytes()),
+    ("keyboard-bottom", KEYBOARD_ROWS[3].as_bytes()),
+];
+
+/// The default l33t substitutions of the mutation mode: each letter and the characters it can be replaced with.
+/// They are the substitutions detected by the strength checker.
+///
+pub const L33T_SUBSTITUTIONS: [(char, &[&str]); 10] = [
+    ('a', &["4", "@"]),
+    ('b', &["8"]),
+    ('e', &["3"]),
+    ('g', &["9", "6"]),
+    ('i', &["1", "!", "|"]),
+    ('l', &["1", "|"]),
+    ('o', &["0"]),
+    ('s', &["5", "$"]),
+    ('t', &["7", "+"]),
+    ('z', &["2"]),
+];
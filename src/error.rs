@@ -3,14 +3,12 @@ use thiserror::Error;
 
 #[derive(Debug, Error, Clone)]
 pub enum WorgenXError {
-    #[cfg(feature = "cli")]
     #[error("{0}")]
     ArgError(ArgError),
     #[error("{0}")]
     SystemError(SystemError),
 }
 
-#[cfg(feature = "cli")]
 #[derive(Debug, Error, Clone)]
 pub enum ArgError {
     /// This error is raised if the user doesn't specify any argument.
@@ -19,6 +17,48 @@ pub enum ArgError {
     /// This error is raised if there isn't any configuration given by the user (for example just wordlist feature without any type of characters specified).
     #[error("Error: no configuration given for argument.\nPlease specify the mandatory parameters and at least one type of characters.\nUsage: worgenX <command> [options]\nTry 'worgenX --help' for more information.")]
     MissingConfiguration,
+    /// This error is raised if a minimum number of characters is given for a type of characters that isn't selected.
+    #[error("Error: a minimum number of {0} is given but this type of characters is not selected or all its characters are excluded.\nTry 'worgenX --help' for more information.")]
+    MinimumForUnselectedClass(String),
+    /// This error is raised if the minimum numbers of characters don't fit in the length of the password.
+    #[error("Error: {0} characters are required by the types of characters but the password length is {1}.\nPlease increase the size or decrease the minimums.")]
+    UnsatisfiableMinimums(u64, u32),
+    /// This error is raised if all the characters of the selected types are excluded.
+    #[error("Error: all the characters are excluded, there isn't any character left to generate the passwords")]
+    EmptyCharacterPool,
+    /// This error is raised if a custom or excluded character is a control character.
+    #[error("Error: the character `{0:?}` is not supported.\nControl characters can't be used")]
+    InvalidCharacter(char),
+    /// This error is raised if the list of words of the passphrases doesn't contain at least two different words.
+    #[error("Error: the list of words `{0}` must contain at least two different words")]
+    NotEnoughWords(String),
+    /// This error is raised if the list of words of the dictionary mode doesn't contain any word.
+    #[error("Error: the list of words `{0}` doesn't contain any word")]
+    EmptyWordlist(String),
+    /// This error is raised if a rule contains an unknown, unsupported or incomplete function.
+    #[error("Error: the rule `{0}` is invalid, {1}.\nTry 'worgenX --help' for more information.")]
+    InvalidRule(String, String),
+    /// This error is raised if the rule file doesn't contain any rule.
+    #[error("Error: the rule file `{0}` doesn't contain any rule")]
+    EmptyRuleFile(String),
+    /// This error is raised if a line of the substitution table isn't a character followed by its substitutions.
+    #[error("Error: the line `{0}` of the substitution table is invalid.\nEach line must be a character followed by its substitutions, separated by spaces (e.g. `a 4 @`)")]
+    InvalidSubstitution(String),
+    /// This error is raised if the password template contains an unknown placeholder or ends with `?` or `\`.
+    #[error("Error: the template `{0}` is invalid.\nTry 'worgenX --help' for more information.")]
+    InvalidTemplate(String),
+    /// This error is raised if more unique passwords are requested than the number of passwords that can be generated.
+    #[error("Error: {0} unique passwords are requested but only {1} different passwords can be generated.\nPlease decrease the count or increase the size or the types of characters.")]
+    NotEnoughUniquePasswords(u64, String),
+    /// This error is raised if the password policy can't be read or can't be met.
+    #[error("Error: invalid password policy, {0}.")]
+    InvalidPolicy(String),
+    /// This error is raised if the length range of the passwords isn't a number or a range like 12-20.
+    #[error("Error: the size `{0}` is invalid, it must be a number greater than 0 or a range like 12-20.")]
+    InvalidLengthRange(String),
+    /// This error is raised if an escape sequence of the separator of the raw output is invalid.
+    #[error("Error: the separator `{0}` is invalid, the supported escape sequences are \\n, \\r, \\t, \\0, \\\\ and \\xHH.")]
+    InvalidSeparator(String),
 }
 
 #[derive(Debug, Error, Clone)]
@@ -40,7 +80,6 @@ pub enum SystemError {
     #[error("Error: unable to write to file `{0}`.\n[{1}]")]
     UnableToWriteToFile(String, String),
     /// This error is raised if the passwords or wordlists folder can't be created (for GUI mode only).
-    #[cfg(feature = "gui")]
     #[error("Error: unable to create folder `{0}`.\n{1}")]
     UnableToCreateFolder(String, String),
     /// This error is raised when the file name contains invalid characters.
@@ -52,4 +91,7 @@ pub enum SystemError {
     /// This error is raised if the hash algorithm is not supported.
     #[error("Error: the hash algorithm `{0}` is not supported")]
     UnsupportedHashAlgorithm(String),
+    /// This error is raised if a file given by the user can't be read.
+    #[error("Error: unable to read file `{0}`.\n{1}")]
+    UnableToReadFile(String, String),
 }
@@ -1,37 +1,160 @@
 // Internal crates.
-use crate::password::PasswordConfig;
+use worgen_x::{
+    passphrase::{self, PassphraseConfig},
+    password::{self, PasswordConfig},
+    strength::{self, StrengthReport},
+};
 
 // External crates.
 use serde_json::json;
 
-/// This function is responsible for returning a JSON String of the password config structure.
+/// This constant closes the array of passwords and the JSON object opened by `password_config_to_json_header()`.
+///
+pub const PASSWORDS_JSON_FOOTER: &str = "]}";
+
+/// This function is responsible for returning the beginning of the JSON String of the password config structure.
+/// The passwords are streamed after this header with `passwords_to_json_items()`, then the output is closed with `PASSWORDS_JSON_FOOTER`.
 /// The output can be used to interact with other programs.
+/// The entropy is rounded to two decimal places.
+/// `password_length` is kept for the existing consumers, it's the minimum length with a length range.
 ///
 /// # Arguments
 ///
 /// * `password_config` - The password config structure.
-/// * `passwords` - The vector of passwords.
+/// * `seed` - The seed of the random generator, if any. The output is marked as insecure if a seed is given.
 ///
 /// # Returns
 ///
-/// A JSON String of the password config structure.
+/// The JSON object of the password config structure, opened up to the array of passwords.
 ///
-pub fn password_config_to_json(
-    password_config: &PasswordConfig,
-    passwords: &Vec<String>,
-) -> String {
-    json!({
+pub fn password_config_to_json_header(password_config: &PasswordConfig, seed: Option<u64>) -> String {
+    let entropy: f64 = password::get_entropy(password_config);
+    let (min_length, max_length): (u32, u32) = password::get_password_length_range(password_config);
+    let mut header: String = json!({
         "number_of_passwords": password_config.number_of_passwords,
-        "password_length": password_config.length,
+        "password_length": min_length,
+        "min_length": min_length,
+        "max_length": max_length,
         "uppercase": password_config.uppercase,
         "lowercase": password_config.lowercase,
         "numbers": password_config.numbers,
         "special_characters": password_config.special_characters,
-        "passwords": passwords
+        "pronounceable": password_config.pronounceable,
+        "template": password_config.template,
+        "unique": password_config.unique,
+        "entropy_bits": (entropy * 100.0).round() / 100.0,
+        "strength": password::get_strength_label(entropy),
+        "seed": seed,
+        "insecure": seed.is_some()
+    })
+    .to_string();
+    header.pop(); // Remove the closing brace of the object.
+    header.push_str(",\"passwords\":[");
+    header
+}
+
+/// This function is responsible for returning the JSON strings of a batch of passwords, separated by commas.
+///
+/// # Arguments
+///
+/// * `passwords` - The batch of passwords.
+///
+/// # Returns
+///
+/// The items of the JSON array, without the brackets.
+///
+pub fn passwords_to_json_items(passwords: &[String]) -> String {
+    passwords
+        .iter()
+        .map(|password| serde_json::Value::from(password.as_str()).to_string())
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+/// This function is responsible for returning a JSON String of the passphrase config structure.
+/// The output can be used to interact with other programs.
+/// The entropy is rounded to two decimal places.
+///
+/// # Arguments
+///
+/// * `passphrase_config` - The passphrase config structure.
+/// * `nb_of_words` - The number of words in the list used to generate the passphrases.
+/// * `passphrases` - The vector of passphrases.
+/// * `seed` - The seed of the random generator, if any. The output is marked as insecure if a seed is given.
+///
+/// # Returns
+///
+/// A JSON String of the passphrase config structure.
+///
+pub fn passphrase_config_to_json(
+    passphrase_config: &PassphraseConfig,
+    nb_of_words: usize,
+    passphrases: &Vec<String>,
+    seed: Option<u64>,
+) -> String {
+    let entropy: f64 = passphrase::get_entropy(passphrase_config, nb_of_words);
+    json!({
+        "number_of_passphrases": passphrase_config.number_of_passphrases,
+        "number_of_words": passphrase_config.number_of_words,
+        "wordlist_size": nb_of_words,
+        "separator": passphrase_config.separator,
+        "capitalize": passphrase_config.capitalize,
+        "append_number": passphrase_config.append_number,
+        "append_special_character": passphrase_config.append_special_character,
+        "entropy_bits": (entropy * 100.0).round() / 100.0,
+        "strength": password::get_strength_label(entropy),
+        "seed": seed,
+        "insecure": seed.is_some(),
+        "passphrases": passphrases
     })
     .to_string()
 }
 
+/// This function is responsible for returning a JSON String of the strength reports of the checked passwords.
+/// The output can be used to interact with other programs.
+/// The entropy and the base 10 logarithm of the guesses are rounded to two decimal places.
+///
+/// # Arguments
+///
+/// * `reports` - The strength reports of the passwords.
+/// * `offline_rate` - The number of guesses per second of an offline attack, None to use the reference rates.
+///
+/// # Returns
+///
+/// A JSON array of the strength reports.
+///
+pub fn strength_reports_to_json(reports: &[StrengthReport], offline_rate: Option<f64>) -> String {
+    let reports: Vec<serde_json::Value> = reports
+        .iter()
+        .map(|report| {
+            json!({
+                "password": report.password,
+                "length": report.password.chars().count(),
+                "entropy_bits": (report.entropy * 100.0).round() / 100.0,
+                "strength": password::get_strength_label(report.entropy),
+                "guesses_log10": (report.entropy * 2f64.log10() * 100.0).round() / 100.0,
+                "patterns": report.patterns.iter().map(|pattern| json!({
+                    "pattern": pattern.kind.get_name(),
+                    "token": pattern.token,
+                    "start": pattern.start,
+                    "end": pattern.end,
+                    "guesses_log10": (pattern.guesses.log10() * 100.0).round() / 100.0,
+                    "word": pattern.word,
+                    "l33t": pattern.l33t
+                })).collect::<Vec<serde_json::Value>>(),
+                "crack_times": strength::get_crack_times(report.guesses, offline_rate).iter().map(|(scenario, rate, seconds)| json!({
+                    "scenario": scenario,
+                    "guesses_per_second": rate,
+                    "seconds": seconds,
+                    "display": strength::format_crack_time(*seconds)
+                })).collect::<Vec<serde_json::Value>>()
+            })
+        })
+        .collect();
+
+    serde_json::Value::from(reports).to_string()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -45,22 +168,93 @@ mod tests {
             uppercase: true,
             lowercase: true,
             length: 10,
-            number_of_passwords: 1,
+            number_of_passwords: 2,
+            ..Default::default()
         };
-        let passwords: Vec<String> = vec!["password".to_string()];
-        let json_output: String = password_config_to_json(&password_config, &passwords);
+        let passwords: Vec<String> = vec!["password".to_string(), "pass\"word".to_string()];
+        let json_output: String = format!(
+            "{}{}{}",
+            password_config_to_json_header(&password_config, None),
+            passwords_to_json_items(&passwords),
+            PASSWORDS_JSON_FOOTER
+        );
 
         let json_from_str: Value = serde_json::from_str(&json_output).unwrap();
         let json_expected_object: Value = json!({
-            "number_of_passwords": 1,
+            "number_of_passwords": 2,
             "password_length": 10,
+            "min_length": 10,
+            "max_length": 10,
             "uppercase": true,
             "lowercase": true,
             "numbers": true,
             "special_characters": true,
-            "passwords": ["password"]
+            "pronounceable": false,
+            "template": "",
+            "unique": false,
+            "entropy_bits": 64.37,
+            "strength": "strong",
+            "seed": null,
+            "insecure": false,
+            "passwords": ["password", "pass\"word"]
         });
 
         assert_eq!(json_from_str, json_expected_object);
     }
+
+    #[test]
+    fn test_passphrase_config_to_json() {
+        let passphrase_config: PassphraseConfig = PassphraseConfig {
+            number_of_words: 6,
+            separator: String::from("-"),
+            capitalize: true,
+            append_number: false,
+            append_special_character: false,
+            number_of_passphrases: 1,
+        };
+        let passphrases: Vec<String> = vec!["Correct-Horse-Battery-Staple-Apple-Zebra".to_string()];
+        let json_output: String = passphrase_config_to_json(&passphrase_config, 7776, &passphrases, Some(42));
+
+        let json_from_str: Value = serde_json::from_str(&json_output).unwrap();
+        let json_expected_object: Value = json!({
+            "number_of_passphrases": 1,
+            "number_of_words": 6,
+            "wordlist_size": 7776,
+            "separator": "-",
+            "capitalize": true,
+            "append_number": false,
+            "append_special_character": false,
+            "entropy_bits": 77.55,
+            "strength": "strong",
+            "seed": 42,
+            "insecure": true,
+            "passphrases": ["Correct-Horse-Battery-Staple-Apple-Zebra"]
+        });
+
+        assert_eq!(json_from_str, json_expected_object);
+    }
+
+    #[test]
+    fn test_strength_reports_to_json() {
+        let checker: strength::StrengthChecker = strength::StrengthChecker::new(&[]);
+        let reports: Vec<StrengthReport> = vec![checker.check("poiuy2024"), checker.check("zzzz")];
+        let json_output: String = strength_reports_to_json(&reports, None);
+
+        let json_from_str: Value = serde_json::from_str(&json_output).unwrap();
+        assert_eq!(json_from_str.as_array().unwrap().len(), 2);
+        assert_eq!(json_from_str[0]["password"], "poiuy2024");
+        assert_eq!(json_from_str[0]["length"], 9);
+        assert_eq!(json_from_str[0]["strength"], "very weak");
+        assert_eq!(json_from_str[0]["patterns"][0]["pattern"], "keyboard walk");
+        assert_eq!(json_from_str[0]["patterns"][1]["pattern"], "date");
+        assert_eq!(json_from_str[0]["patterns"][1]["token"], "2024");
+        assert_eq!(json_from_str[0]["crack_times"].as_array().unwrap().len(), 4);
+        assert_eq!(json_from_str[0]["crack_times"][3]["display"], "less than a second");
+        assert_eq!(json_from_str[1]["patterns"][0]["pattern"], "repeat");
+
+        let json_output: String = strength_reports_to_json(&reports, Some(1e6));
+        let json_from_str: Value = serde_json::from_str(&json_output).unwrap();
+        assert_eq!(json_from_str[0]["crack_times"].as_array().unwrap().len(), 3);
+        assert_eq!(json_from_str[0]["crack_times"][2]["guesses_per_second"], 1e6);
+    }
 }
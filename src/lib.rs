@@ -0,0 +1,73 @@
+//! WorgenX is a library to generate wordlists for password cracking and random passwords with a good entropy.
+//!
+//! The `worgenX` binary (CLI and GUI modes) is a front end over this library, the modules below can be used directly:
+//!
+//! * [`wordlist`] - The mask parser, [`wordlist::WordlistConfig`], the multithreaded wordlist generation and [`wordlist::WordlistIterator`] to consume the passwords lazily without any file.
+//! * [`rules`] - The hashcat-compatible rule engine, [`rules::RulesConfig`] applies a rule file to the passwords of a mask or of a list of words.
+//! * [`mutation`] - The l33t substitutions and case permutations of a list of words, [`mutation::MutationConfig`] expands each word with a substitution table.
+//! * [`password`] - The random password generation from a [`password::PasswordConfig`].
+//! * [`passphrase`] - The diceware-style passphrase generation from a [`passphrase::PassphraseConfig`] and a list of words.
+//! * [`strength`] - The strength checker of existing passwords: the patterns found, the estimated guesses and the time to crack.
+//! * [`rng`] - The random number generator of the passwords and passphrases: the CSPRNG of the operating system or a seeded ChaCha20 generator for reproducible output.
+//! * [`system`] - The hashing of the passwords ([`system::manage_hash`]) and the size estimation of a wordlist ([`system::get_estimated_size`]).
+//! * [`benchmark`] - The CPU benchmark.
+//!
+//! The library doesn't depend on the `cli` and `gui` features, which are only used to build the binary.
+//!
+//! # Example
+//!
+//! ```
+//! use worgen_x::{
+//!     password::{self, PasswordConfig},
+//!     rng::WorgenXRng,
+//!     system,
+//!     wordlist::{self, WordlistConfig, WordlistValues},
+//! };
+//!
+//! let wordlist_values: WordlistValues = WordlistValues {
+//!     numbers: true,
+//!     special_characters: false,
+//!     uppercase: false,
+//!     lowercase: false,
+//!     charset: String::new(),
+//!     mask: String::from("pin-??"),
+//!     hash: String::new(),
+//!     raw_separator: None,
+//!     byte_placeholder: false,
+//! };
+//! let wordlist_config: WordlistConfig = wordlist::build_wordlist_config(&wordlist_values);
+//! assert_eq!(wordlist::get_nb_of_passwords(&wordlist_config), 100);
+//! assert_eq!(system::get_estimated_size(100, wordlist::get_password_size(&wordlist_config)), "700 bytes");
+//!
+//! let password_config: PasswordConfig = PasswordConfig {
+//!     numbers: true,
+//!     special_characters: true,
+//!     uppercase: true,
+//!     lowercase: true,
+//!     length: 16,
+//!     number_of_passwords: 2,
+//!     ..Default::default()
+//! };
+//! let passwords: Vec<String> = password::generate_random_passwords(&password_config, &mut WorgenXRng::new(None));
+//! assert_eq!(passwords.len(), 2);
+//!
+//! let hashed_passwd: String = system::manage_hash(&passwords[0], "sha256").unwrap();
+//! assert_eq!(hashed_passwd.len(), 64);
+//! ```
+//!
+
+// Prevents the use of unsafe code
+#![forbid(unsafe_code)]
+
+pub mod benchmark;
+pub mod dict;
+pub mod error;
+pub mod mutation;
+pub mod passphrase;
+pub mod password;
+pub mod progress;
+pub mod rng;
+pub mod rules;
+pub mod strength;
+pub mod system;
+pub mod wordlist;
@@ -8,18 +8,15 @@ compile_error!("You must specify a mode: 'gui' or 'cli'.");
 #[cfg(all(feature = "gui", feature = "cli"))]
 compile_error!("You must specify only one mode: 'gui' or 'cli'.");
 
-// Internal modules
-mod benchmark;
-mod dict;
-mod error;
+// Internal modules, the generation features are provided by the worgen_x library
 mod mode;
-mod password;
-mod system;
-mod wordlist;
 
 #[cfg(feature = "cli")]
 mod json;
 
+#[cfg(feature = "cli")]
+mod policy;
+
 /// This function is the "entry point" of the program.
 ///
 fn main() {
@@ -1,25 +1,54 @@
 // Internal crates.
-use crate::{
+use crate::{json, policy};
+use worgen_x::{
     benchmark,
+    dict,
     error::{ArgError, SystemError, WorgenXError},
-    json,
+    mutation::{self, MutationConfig},
+    passphrase::{self, PassphraseConfig},
     password::{self, PasswordConfig},
+    rng::WorgenXRng,
+    rules::{self, RulesConfig},
+    strength::{self, StrengthChecker, StrengthReport},
     system,
-    wordlist::{self, WordlistConfig, WordlistValues},
+    wordlist::{self, CombineConfig, HybridConfig, HybridPosition, PasswordSource, WordlistConfig, WordlistValues, WordsConfig},
 };
 
 // External crates.
-use clap::{value_parser, Arg, ArgAction, ArgMatches, Command};
+use clap::{value_parser, Arg, ArgAction, ArgGroup, ArgMatches, Command};
 use std::{
     env,
     fs::{File, OpenOptions},
-    sync::{Arc, Mutex},
+    io::{self, BufRead, Write},
+    sync::{Arc, Mutex, MutexGuard},
 };
 
 /// This struct is built from PasswordConfig and optional arguments that will be used to generate the random password.
 ///
 struct PasswordGenerationOptions {
     password_config: PasswordConfig,
+    seed: Option<u64>,
+    json: bool,
+    output_file: String,
+    no_display: bool,
+    threads: usize,
+}
+
+/// This struct holds the destinations of the streamed passwords, shared between the generation threads.
+///
+struct PasswordOutput {
+    file: Option<Arc<Mutex<File>>>,
+    display: bool,
+    json: bool,
+    is_first_batch: bool,
+}
+
+/// This struct is built from PassphraseConfig, the list of words and optional arguments that will be used to generate the passphrases.
+///
+struct PassphraseGenerationOptions {
+    passphrase_config: PassphraseConfig,
+    words: Vec<String>,
+    seed: Option<u64>,
     json: bool,
     output_file: String,
     no_display: bool,
@@ -29,6 +58,26 @@ struct PasswordGenerationOptions {
 ///
 struct WordlistGenerationOptions {
     wordlist_values: WordlistValues,
+    words_file: Option<String>,
+    unique: bool,
+    prepend: bool,
+    leet: bool,
+    leet_table_file: Option<String>,
+    case_permutations: bool,
+    max_changes: Option<usize>,
+    rules_file: Option<String>,
+    output_file: String,
+    no_loading_bar: bool,
+    threads: usize,
+}
+
+/// This struct is built from the arguments of the combinator mode that will be used to generate the wordlist.
+///
+struct CombineOptions {
+    lists: Vec<String>,
+    separator: String,
+    hash: String,
+    raw_separator: Option<Vec<u8>>,
     output_file: String,
     no_loading_bar: bool,
     threads: usize,
@@ -78,6 +127,21 @@ fn build_command_context() -> Command {
                 .help("Add special characters to the words")
                 .action(ArgAction::SetTrue)
         )
+        .arg(
+            Arg::new("charset_wordlist")
+                .long("charset")
+                .help("Add the given characters (any Unicode characters) to the words")
+                .value_parser(clap::builder::NonEmptyStringValueParser::new())
+                .value_name("chars"),
+        )
+        .arg(
+            Arg::new("builtin_wordlist")
+                .long("builtin")
+                .help("Add a built-in charset to the words, can be repeated")
+                .value_parser(clap::builder::PossibleValuesParser::new(dict::BUILTIN_CHARSETS.map(|(name, _)| name)))
+                .value_name("charset")
+                .action(ArgAction::Append),
+        )
         .arg(
             Arg::new("mask")
                 .short('m')
@@ -85,8 +149,71 @@ fn build_command_context() -> Command {
                 .help("Mask used to generate the words")
                 .value_parser(clap::builder::NonEmptyStringValueParser::new())
                 .value_name("mask")
-                .required(true),
+                .required_unless_present("words_wordlist"),
         )
+        .arg(
+            Arg::new("bytes_wordlist")
+                .long("bytes")
+                .help("Make ?b in the mask any byte (0x00-0xFF) instead of a variable character followed by b")
+                .action(ArgAction::SetTrue)
+                .requires("mask"),
+        )
+        .arg(
+            Arg::new("words_wordlist")
+                .long("words")
+                .help("Generate the wordlist from the words of a file (one word per line), combined with the mask if any")
+                .value_parser(clap::builder::NonEmptyStringValueParser::new())
+                .value_name("path"),
+        )
+        .arg(
+            Arg::new("prepend_wordlist")
+                .long("prepend")
+                .help("Put the mask before the words in the hybrid mode (after the words by default)")
+                .action(ArgAction::SetTrue)
+                .requires("words_wordlist")
+                .requires("mask"),
+        )
+        .arg(
+            Arg::new("unique_wordlist")
+                .long("unique")
+                .help("Remove the duplicate words of the file given with --words")
+                .action(ArgAction::SetTrue)
+                .requires("words_wordlist"),
+        )
+        .arg(
+            Arg::new("leet_wordlist")
+                .long("leet")
+                .help("Expand each word of the file given with --words into its l33t substitutions (e.g. a -> 4 or @)")
+                .action(ArgAction::SetTrue)
+                .requires("words_wordlist")
+                .conflicts_with("mask"),
+        )
+        .arg(
+            Arg::new("leet_table_wordlist")
+                .long("leet-table")
+                .help("Use the l33t substitutions of a file instead of the default ones (a character followed by its substitutions on each line), implies --leet")
+                .value_parser(clap::builder::NonEmptyStringValueParser::new())
+                .value_name("path")
+                .requires("words_wordlist")
+                .conflicts_with("mask"),
+        )
+        .arg(
+            Arg::new("case_wordlist")
+                .long("case")
+                .help("Expand each word of the file given with --words into all its case permutations")
+                .action(ArgAction::SetTrue)
+                .requires("words_wordlist")
+                .conflicts_with("mask"),
+        )
+        .arg(
+            Arg::new("max_changes_wordlist")
+                .long("max-changes")
+                .help("Maximum number of characters changed by the l33t substitutions and the case permutations in each word")
+                .value_parser(value_parser!(usize))
+                .value_name("number")
+                .requires("mutation_wordlist"),
+        )
+        .group(ArgGroup::new("mutation_wordlist").args(["leet_wordlist", "leet_table_wordlist", "case_wordlist"]).multiple(true))
         .arg(
             Arg::new("output")
                 .short('o')
@@ -111,12 +238,85 @@ fn build_command_context() -> Command {
                 .value_parser(vec!["md5", "sha1", "sha224", "sha256", "sha384", "sha512", "sha3-224", "sha3-256", "sha3-384", "sha3-512", "blake2b", "blake2s", "whirlpool"])
                 .value_name("hash"),
         )
+        .arg(
+            Arg::new("rules_wordlist")
+                .short('r')
+                .long("rules")
+                .help("Apply the rules of a file (hashcat syntax, one rule per line) to each word")
+                .value_parser(clap::builder::NonEmptyStringValueParser::new())
+                .value_name("path"),
+        )
+        .arg(
+            Arg::new("raw_separator_wordlist")
+                .long("raw-separator")
+                .help("Write the words as raw bytes followed by the given separator (e.g. \\0 or \\x1f) instead of one word per line")
+                .value_parser(|value: &str| wordlist::parse_separator(value).map_err(|e| e.to_string()))
+                .value_name("separator"),
+        )
         .arg(
             Arg::new("threads_wordlist")
                 .short('t')
                 .long("threads")
                 .help("Number of threads to generate the passwords")
-                .value_parser(value_parser!(usize))
+                .value_parser(clap::builder::RangedU64ValueParser::<usize>::new().range(1..))
+                .value_name("threads")
+                .default_value(default_threads),
+        );
+
+    let combine_command: Command = Command::new("combine")
+        .arg_required_else_help(true)
+        .arg(
+            Arg::new("lists_combine")
+                .help("Files containing the words to combine (one word per line), two or three files")
+                .value_parser(clap::builder::NonEmptyStringValueParser::new())
+                .value_name("path")
+                .num_args(2..=3)
+                .required(true),
+        )
+        .arg(
+            Arg::new("separator_combine")
+                .short('S')
+                .long("sep")
+                .help("Separator between the words (none by default)")
+                .value_name("separator"),
+        )
+        .arg(
+            Arg::new("output_combine")
+                .short('o')
+                .long("output")
+                .help("Save the wordlist in a text file")
+                .value_parser(clap::builder::NonEmptyStringValueParser::new())
+                .value_name("path")
+                .required(true),
+        )
+        .arg(
+            Arg::new("disable_loading_bar_combine")
+                .short('d')
+                .long("disable-loading-bar")
+                .help("Disable the loading bar when generating the wordlist")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("hash_combine")
+                .short('h')
+                .long("hash")
+                .help("Hash algorithm to use for the wordlist")
+                .value_parser(vec!["md5", "sha1", "sha224", "sha256", "sha384", "sha512", "sha3-224", "sha3-256", "sha3-384", "sha3-512", "blake2b", "blake2s", "whirlpool"])
+                .value_name("hash"),
+        )
+        .arg(
+            Arg::new("raw_separator_combine")
+                .long("raw-separator")
+                .help("Write the words as raw bytes followed by the given separator (e.g. \\0 or \\x1f) instead of one word per line")
+                .value_parser(|value: &str| wordlist::parse_separator(value).map_err(|e| e.to_string()))
+                .value_name("separator"),
+        )
+        .arg(
+            Arg::new("threads_combine")
+                .short('t')
+                .long("threads")
+                .help("Number of threads to generate the passwords")
+                .value_parser(clap::builder::RangedU64ValueParser::<usize>::new().range(1..))
                 .value_name("threads")
                 .default_value(default_threads),
         );
@@ -155,10 +355,10 @@ fn build_command_context() -> Command {
             Arg::new("size")
                 .short('s')
                 .long("size")
-                .help("Size of the passwords in characters")
-                .value_parser(value_parser!(u32).range(1..i64::from(u32::MAX)))
+                .help("Size of the passwords in characters, or a range like 12-20")
+                .value_parser(|value: &str| password::parse_length_range(value).map_err(|e| e.to_string()))
                 .value_name("size")
-                .required(true),
+                .required_unless_present_any(["template_password", "policy_password"]),
         )
         .arg(
             Arg::new("count")
@@ -193,6 +393,294 @@ fn build_command_context() -> Command {
                 .long("json")
                 .help("Output in JSON format")
                 .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("template_password")
+                .short('T')
+                .long("template")
+                .help("Generate the passwords from a template (e.g. Cvccvc-99-!! or ?u?l?l?l?d?d?s)")
+                .value_parser(clap::builder::NonEmptyStringValueParser::new())
+                .value_name("template")
+                .conflicts_with_all([
+                    "size",
+                    "lowercase_password",
+                    "uppercase_password",
+                    "numbers_password",
+                    "special_characters_password",
+                    "pronounceable_password",
+                    "charset_password",
+                    "builtin_password",
+                    "exclude_password",
+                    "no_ambiguous_password",
+                    "min_lowercase",
+                    "min_uppercase",
+                    "min_numbers",
+                    "min_special_characters",
+                ]),
+        )
+        .arg(
+            Arg::new("pronounceable_password")
+                .short('p')
+                .long("pronounceable")
+                .help("Generate pronounceable passwords made of consonant-vowel syllables")
+                .action(ArgAction::SetTrue)
+                .conflicts_with_all([
+                    "lowercase_password",
+                    "uppercase_password",
+                    "numbers_password",
+                    "special_characters_password",
+                    "charset_password",
+                    "builtin_password",
+                    "exclude_password",
+                    "no_ambiguous_password",
+                    "min_lowercase",
+                    "min_uppercase",
+                    "min_numbers",
+                    "min_special_characters",
+                ]),
+        )
+        .arg(
+            Arg::new("charset_password")
+                .long("charset")
+                .help("Add the given characters to the alphabet of the passwords")
+                .value_parser(clap::builder::NonEmptyStringValueParser::new())
+                .value_name("chars"),
+        )
+        .arg(
+            Arg::new("builtin_password")
+                .long("builtin")
+                .help("Add a built-in charset to the alphabet of the passwords, can be repeated")
+                .value_parser(clap::builder::PossibleValuesParser::new(dict::BUILTIN_CHARSETS.map(|(name, _)| name)))
+                .value_name("charset")
+                .action(ArgAction::Append),
+        )
+        .arg(
+            Arg::new("exclude_password")
+                .long("exclude")
+                .help("Remove the given characters from the alphabet of the passwords")
+                .value_parser(clap::builder::NonEmptyStringValueParser::new())
+                .value_name("chars"),
+        )
+        .arg(
+            Arg::new("no_ambiguous_password")
+                .long("no-ambiguous")
+                .help("Remove the look-alike characters (0O1lI|) from the alphabet of the passwords")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("threads_password")
+                .short('t')
+                .long("threads")
+                .help("Number of threads to generate the passwords")
+                .value_parser(clap::builder::RangedU64ValueParser::<usize>::new().range(1..))
+                .value_name("threads")
+                .default_value(default_threads),
+        )
+        .arg(
+            Arg::new("seed_password")
+                .long("seed")
+                .help("Seed of the random generator, for reproducible output (INSECURE, tests and demos only)")
+                .value_parser(value_parser!(u64))
+                .value_name("seed"),
+        )
+        .arg(
+            Arg::new("policy_password")
+                .long("policy")
+                .help("Generate the passwords from a policy file (TOML or JSON)")
+                .value_parser(clap::builder::NonEmptyStringValueParser::new())
+                .value_name("path")
+                .conflicts_with_all([
+                    "lowercase_password",
+                    "uppercase_password",
+                    "numbers_password",
+                    "special_characters_password",
+                    "template_password",
+                    "pronounceable_password",
+                    "charset_password",
+                    "builtin_password",
+                    "exclude_password",
+                    "no_ambiguous_password",
+                    "no_class_coverage",
+                    "min_lowercase",
+                    "min_uppercase",
+                    "min_numbers",
+                    "min_special_characters",
+                ]),
+        )
+        .arg(
+            Arg::new("unique_password")
+                .long("unique")
+                .help("Guarantee that all the generated passwords are different")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("no_class_coverage")
+                .long("no-class-coverage")
+                .help("Don't require at least one character of each selected type")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("min_lowercase")
+                .long("min-lower")
+                .help("Minimum number of lowercase characters in each password")
+                .value_parser(value_parser!(u32))
+                .value_name("count"),
+        )
+        .arg(
+            Arg::new("min_uppercase")
+                .long("min-upper")
+                .help("Minimum number of uppercase characters in each password")
+                .value_parser(value_parser!(u32))
+                .value_name("count"),
+        )
+        .arg(
+            Arg::new("min_numbers")
+                .long("min-digits")
+                .help("Minimum number of numbers in each password")
+                .value_parser(value_parser!(u32))
+                .value_name("count"),
+        )
+        .arg(
+            Arg::new("min_special_characters")
+                .long("min-special")
+                .help("Minimum number of special characters in each password")
+                .value_parser(value_parser!(u32))
+                .value_name("count"),
+        );
+
+    let passphrase_command: Command = Command::new("passphrase")
+        .arg(
+            Arg::new("wordlist_passphrase")
+                .short('f')
+                .long("wordlist")
+                .help("File containing the words of the passphrases (one word per line), the embedded EFF large wordlist by default")
+                .value_parser(clap::builder::NonEmptyStringValueParser::new())
+                .value_name("path"),
+        )
+        .arg(
+            Arg::new("words_passphrase")
+                .short('w')
+                .long("words")
+                .help("Number of words in each passphrase")
+                .value_parser(value_parser!(u32).range(1..i64::from(u32::MAX)))
+                .value_name("words")
+                .default_value("6"),
+        )
+        .arg(
+            Arg::new("count_passphrase")
+                .short('c')
+                .long("count")
+                .help("Number of passphrases to generate")
+                .value_parser(value_parser!(u64).range(1..u64::MAX))
+                .value_name("count")
+                .default_value("1"),
+        )
+        .arg(
+            Arg::new("separator_passphrase")
+                .short('S')
+                .long("separator")
+                .help("Separator between the words")
+                .value_name("separator")
+                .default_value("-"),
+        )
+        .arg(
+            Arg::new("capitalize_passphrase")
+                .short('C')
+                .long("capitalize")
+                .help("Capitalize the first letter of each word")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("numbers_passphrase")
+                .short('n')
+                .long("append-number")
+                .help("Append a random number to the passphrases")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("special_characters_passphrase")
+                .short('x')
+                .long("append-special-character")
+                .help("Append a random special character to the passphrases")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("output_passphrase")
+                .short('o')
+                .long("output")
+                .help("Save the passphrases in a file")
+                .value_parser(clap::builder::NonEmptyStringValueParser::new())
+                .value_name("path")
+                .conflicts_with("output_only_passphrase"),
+        )
+        .arg(
+            Arg::new("output_only_passphrase")
+                .short('O')
+                .long("output-only")
+                .help("Save the passphrases only in a file, not in stdout")
+                .value_parser(clap::builder::NonEmptyStringValueParser::new())
+                .value_name("path")
+                .conflicts_with("output_passphrase"),
+        )
+        .arg(
+            Arg::new("seed_passphrase")
+                .long("seed")
+                .help("Seed of the random generator, for reproducible output (INSECURE, tests and demos only)")
+                .value_parser(value_parser!(u64))
+                .value_name("seed"),
+        )
+        .arg(
+            Arg::new("json_passphrase")
+                .short('j')
+                .long("json")
+                .help("Output in JSON format")
+                .action(ArgAction::SetTrue),
+        );
+
+    let check_command: Command = Command::new("check")
+        .arg(
+            Arg::new("passwords_check")
+                .help("Passwords to check, read from stdin (one per line) if none is given")
+                .value_name("password")
+                .num_args(1..)
+                .action(ArgAction::Append),
+        )
+        .arg(
+            Arg::new("wordlist_check")
+                .short('f')
+                .long("wordlist")
+                .help("File containing additional words to detect (one word per line)")
+                .value_parser(clap::builder::NonEmptyStringValueParser::new())
+                .value_name("path"),
+        )
+        .arg(
+            Arg::new("rate_check")
+                .short('r')
+                .long("rate")
+                .help("Number of guesses per second of an offline attack, to estimate the time to crack")
+                .value_parser(|value: &str| {
+                    value
+                        .parse::<f64>()
+                        .ok()
+                        .filter(|rate| rate.is_finite() && *rate > 0.0)
+                        .ok_or("the rate must be a number of guesses per second greater than 0".to_string())
+                })
+                .value_name("guesses")
+                .conflicts_with("benchmark_check"),
+        )
+        .arg(
+            Arg::new("benchmark_check")
+                .short('b')
+                .long("benchmark")
+                .help("Measure the number of guesses per second of this CPU to estimate the time to crack offline")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("json_check")
+                .short('j')
+                .long("json")
+                .help("Output in JSON format")
+                .action(ArgAction::SetTrue),
         );
 
     let benchmark_command: Command = Command::new("benchmark")
@@ -201,102 +689,425 @@ fn build_command_context() -> Command {
                 .short('t')
                 .long("threads")
                 .help("Number of threads to use for the CPU benchmark")
-                .value_parser(value_parser!(usize))
+                .value_parser(clap::builder::RangedU64ValueParser::<usize>::new().range(1..))
                 .value_name("threads")
                 .default_value(default_threads),
         );
 
-    Command::new("worgenX")
-        .args_conflicts_with_subcommands(true)
-        .allow_external_subcommands(true)
-        .disable_help_flag(true) // Keep the help handling in the run() function
-        .disable_version_flag(true) // Keep the version handling in the run() function
-        .disable_help_subcommand(true) // Keep the help handling in the run() function
-        .arg(Arg::new("version").short('v').long("version").action(ArgAction::SetTrue))
-        .arg(Arg::new("help").short('h').long("help").action(ArgAction::SetTrue))
-        .subcommand(wordlist_command)
-        .subcommand(password_command)
-        .subcommand(benchmark_command)
+    Command::new("worgenX")
+        .args_conflicts_with_subcommands(true)
+        .allow_external_subcommands(true)
+        .disable_help_flag(true) // Keep the help handling in the run() function
+        .disable_version_flag(true) // Keep the version handling in the run() function
+        .disable_help_subcommand(true) // Keep the help handling in the run() function
+        .arg(Arg::new("version").short('v').long("version").action(ArgAction::SetTrue))
+        .arg(Arg::new("help").short('h').long("help").action(ArgAction::SetTrue))
+        .subcommand(wordlist_command)
+        .subcommand(combine_command)
+        .subcommand(password_command)
+        .subcommand(passphrase_command)
+        .subcommand(check_command)
+        .subcommand(benchmark_command)
+}
+
+/// This function is responsible for scheduling the execution of the different features of the program according to the user's choices.
+///
+/// # Returns
+///
+/// Ok(()) if the program has been executed, WorgenXError otherwise.
+///
+pub fn run() -> Result<(), WorgenXError> {
+    let mut command_context: Command = build_command_context();
+    if let Ok(matches) = command_context.clone().try_get_matches() {
+        // Call the `display_help()` function instead of clap help with the -h or --help arguments (better control of the help message).
+        if matches.get_flag("help") {
+            display_help();
+            return Ok(());
+        }
+        // Call the `println!()` macro instead of clap version with the -v or --version arguments (better control of the version message).
+        if matches.get_flag("version") {
+            println!("WorgenX v{}", env!("CARGO_PKG_VERSION"));
+            return Ok(());
+        }
+    }
+
+    command_context.build();
+    match command_context.get_matches().subcommand() {
+        Some(("wordlist", sub_matches)) => run_wordlist(sub_matches),
+        Some(("combine", sub_matches)) => run_combine(sub_matches),
+        Some(("password", sub_matches)) => run_passwd(sub_matches),
+        Some(("passphrase", sub_matches)) => run_passphrase(sub_matches),
+        Some(("check", sub_matches)) => run_check(sub_matches),
+        Some(("benchmark", sub_matches)) => run_benchmark(sub_matches),
+        _ => Err(WorgenXError::ArgError(ArgError::NoArgument))
+    }
+}
+
+/// This function is responsible for scheduling the execution of the random password generation functions of the program.
+///
+/// # Arguments
+///
+/// * `sub_matches` - A reference to ArgMatches containing the arguments passed to the program.
+///
+/// # Returns
+///
+/// Ok(()) if the password has been generated, WorgenXError otherwise.
+///
+fn run_passwd(sub_matches: &ArgMatches) -> Result<(), WorgenXError> {
+    let password_generation_parameters: PasswordGenerationOptions = allocate_passwd_config_cli(sub_matches)?;
+    let file: Option<Arc<Mutex<File>>> = if password_generation_parameters.output_file.is_empty() {
+        None
+    } else {
+        Some(Arc::new(Mutex::new(create_output_file(password_generation_parameters.output_file.clone())?)))
+    };
+    let output: Mutex<PasswordOutput> = Mutex::new(PasswordOutput {
+        file,
+        display: !password_generation_parameters.no_display,
+        json: password_generation_parameters.json,
+        is_first_batch: true,
+    });
+
+    if password_generation_parameters.json {
+        let header: String = json::password_config_to_json_header(
+            &password_generation_parameters.password_config,
+            password_generation_parameters.seed,
+        );
+        write_to_output(&mut *lock_output(&output)?, header.as_bytes())?;
+    }
+    password::generate_passwords_in_batches(
+        &password_generation_parameters.password_config,
+        password_generation_parameters.threads,
+        password_generation_parameters.seed,
+        |passwords| write_password_batch(&output, &passwords),
+    )?;
+    if password_generation_parameters.json {
+        write_to_output(&mut *lock_output(&output)?, format!("{}\n", json::PASSWORDS_JSON_FOOTER).as_bytes())?;
+    }
+
+    if !password_generation_parameters.no_display && !password_generation_parameters.json {
+        // The entropy is displayed on stderr to keep stdout usable by other programs.
+        let entropy: f64 = password::get_entropy(&password_generation_parameters.password_config);
+        eprintln!("Entropy: {:.2} bits ({})", entropy, password::get_strength_label(entropy));
+    }
+    if !password_generation_parameters.json {
+        display_seed_warning(password_generation_parameters.seed);
+    }
+
+    Ok(())
+}
+
+/// This function is responsible for writing a batch of passwords to the outputs, in text or JSON format.
+/// The batch is formatted before locking the outputs, so the threads only wait for each other while writing.
+///
+/// # Arguments
+///
+/// * `output` - The destinations of the passwords, shared between the threads.
+/// * `passwords` - The batch of passwords.
+///
+/// # Returns
+///
+/// Ok(()) if the batch has been written, WorgenXError otherwise.
+///
+fn write_password_batch(output: &Mutex<PasswordOutput>, passwords: &[String]) -> Result<(), WorgenXError> {
+    let json: bool = lock_output(output)?.json;
+    let formatted_batch: String = if json {
+        json::passwords_to_json_items(passwords)
+    } else {
+        let mut formatted_batch: String = passwords.join("\n");
+        formatted_batch.push('\n');
+        formatted_batch
+    };
+
+    let mut output: MutexGuard<PasswordOutput> = lock_output(output)?;
+    if json && !output.is_first_batch {
+        write_to_output(&mut output, b",")?;
+    }
+    output.is_first_batch = false;
+    write_to_output(&mut output, formatted_batch.as_bytes())
+}
+
+/// This function is responsible for writing a buffer to stdout (if the passwords are displayed) and to the output file (if any).
+///
+/// # Arguments
+///
+/// * `output` - The destinations of the passwords.
+/// * `buffer` - The bytes to write.
+///
+/// # Returns
+///
+/// Ok(()) if the buffer has been written, WorgenXError otherwise.
+///
+fn write_to_output(output: &mut PasswordOutput, buffer: &[u8]) -> Result<(), WorgenXError> {
+    if output.display
+        && let Err(e) = io::stdout().lock().write_all(buffer)
+    {
+        return Err(WorgenXError::SystemError(SystemError::UnableToWriteToFile(
+            "stdout".to_string(),
+            e.to_string(),
+        )));
+    }
+    if let Some(file) = &output.file {
+        system::save_buffer_to_file(file, buffer)?;
+    }
+
+    Ok(())
+}
+
+/// This function is responsible for locking the destinations of the passwords.
+///
+/// # Arguments
+///
+/// * `output` - The destinations of the passwords, shared between the threads.
+///
+/// # Returns
+///
+/// The guard of the destinations, WorgenXError if another thread panicked while holding it.
+///
+fn lock_output(output: &Mutex<PasswordOutput>) -> Result<MutexGuard<'_, PasswordOutput>, WorgenXError> {
+    output
+        .lock()
+        .map_err(|e| WorgenXError::SystemError(SystemError::ThreadError(e.to_string())))
+}
+
+/// This function is responsible for scheduling the execution of the passphrase generation functions of the program.
+///
+/// # Arguments
+///
+/// * `sub_matches` - A reference to ArgMatches containing the arguments passed to the program.
+///
+/// # Returns
+///
+/// Ok(()) if the passphrases have been generated, WorgenXError otherwise.
+///
+fn run_passphrase(sub_matches: &ArgMatches) -> Result<(), WorgenXError> {
+    let passphrase_generation_parameters: PassphraseGenerationOptions = allocate_passphrase_config_cli(sub_matches)?;
+    let passphrases: Vec<String> = passphrase::generate_random_passphrases(
+        &passphrase_generation_parameters.passphrase_config,
+        &passphrase_generation_parameters.words,
+        &mut WorgenXRng::new(passphrase_generation_parameters.seed),
+    );
+    let all_passphrases: String = if passphrase_generation_parameters.json {
+        json::passphrase_config_to_json(
+            &passphrase_generation_parameters.passphrase_config,
+            passphrase_generation_parameters.words.len(),
+            &passphrases,
+            passphrase_generation_parameters.seed,
+        )
+    } else {
+        passphrases.join("\n")
+    };
+
+    if !passphrase_generation_parameters.no_display {
+        println!("{}", all_passphrases);
+        if !passphrase_generation_parameters.json {
+            // The entropy is displayed on stderr to keep stdout usable by other programs.
+            let entropy: f64 = passphrase::get_entropy(
+                &passphrase_generation_parameters.passphrase_config,
+                passphrase_generation_parameters.words.len(),
+            );
+            eprintln!("Entropy: {:.2} bits ({})", entropy, password::get_strength_label(entropy));
+        }
+    }
+    if !passphrase_generation_parameters.json {
+        display_seed_warning(passphrase_generation_parameters.seed);
+    }
+
+    if !passphrase_generation_parameters.output_file.is_empty() {
+        save_output_to_file(passphrase_generation_parameters.output_file, &all_passphrases)?;
+    }
+
+    Ok(())
+}
+
+/// This function is responsible for checking the values of the arguments passed to the program for the passphrase generation feature.
+/// This function is called only if the user specifies the passphrase command.
+///
+/// # Arguments
+///
+/// * `sub_matches` - A reference to ArgMatches containing the arguments passed to the program.
+///
+/// # Returns
+///
+/// PassphraseGenerationOptions containing the passphrase configuration, the list of words and optional arguments, WorgenXError otherwise.
+///
+fn allocate_passphrase_config_cli(sub_matches: &ArgMatches) -> Result<PassphraseGenerationOptions, WorgenXError> {
+    // Clap framework ensures that the arguments are present or have a default value.
+    let mut passphrase_config: PassphraseConfig = PassphraseConfig {
+        number_of_words: *sub_matches.get_one::<u32>("words_passphrase").unwrap(),
+        separator: sub_matches.get_one::<String>("separator_passphrase").unwrap().clone(),
+        capitalize: false,
+        append_number: false,
+        append_special_character: false,
+        number_of_passphrases: *sub_matches.get_one::<u64>("count_passphrase").unwrap(),
+    };
+    let mut output_file: String = String::new();
+    let mut json: bool = false;
+
+    update_config(&mut passphrase_config.capitalize, sub_matches, "capitalize_passphrase");
+    update_config(&mut passphrase_config.append_number, sub_matches, "numbers_passphrase");
+    update_config(&mut passphrase_config.append_special_character, sub_matches, "special_characters_passphrase");
+    update_config(&mut json, sub_matches, "json_passphrase");
+    update_config(&mut output_file, sub_matches, "output_passphrase");
+    update_config(&mut output_file, sub_matches, "output_only_passphrase");
+
+    if !output_file.is_empty() {
+        output_file = check_output_arg(&output_file)?;
+    }
+
+    let wordlist_path: Option<&String> = sub_matches.get_one::<String>("wordlist_passphrase");
+    let mut words: Vec<String> = match wordlist_path {
+        Some(wordlist_path) => passphrase::load_words(wordlist_path)?,
+        None => passphrase::get_default_words(),
+    };
+    // "Word" and "word" give the same capitalized word, they must be counted once in the entropy.
+    if passphrase_config.capitalize {
+        words = passphrase::remove_capitalized_duplicates(words);
+        if words.len() < 2 {
+            return Err(WorgenXError::ArgError(ArgError::NotEnoughWords(wordlist_path.cloned().unwrap_or_default())));
+        }
+    }
+
+    Ok(PassphraseGenerationOptions {
+        passphrase_config,
+        words,
+        seed: sub_matches.get_one::<u64>("seed_passphrase").copied(),
+        json,
+        output_file,
+        no_display: sub_matches.get_one::<String>("output_only_passphrase").is_some(),
+    })
+}
+
+/// This function is responsible for scheduling the execution of the password strength checker.
+/// The passwords are read from the arguments, or from stdin if there isn't any (one password per line).
+///
+/// # Arguments
+///
+/// * `sub_matches` - A reference to ArgMatches containing the arguments passed to the program.
+///
+/// # Returns
+///
+/// Ok(()) if the passwords have been checked, WorgenXError otherwise.
+///
+fn run_check(sub_matches: &ArgMatches) -> Result<(), WorgenXError> {
+    let words: Vec<String> = match sub_matches.get_one::<String>("wordlist_check") {
+        Some(wordlist_path) => passphrase::load_words(wordlist_path)?,
+        None => Vec::new(),
+    };
+    let passwords: Vec<String> = match sub_matches.get_many::<String>("passwords_check") {
+        Some(passwords) => passwords.cloned().collect(),
+        None => io::stdin()
+            .lock()
+            .lines()
+            .collect::<Result<Vec<String>, io::Error>>()
+            .map_err(|e| WorgenXError::SystemError(SystemError::UnableToReadFile("stdin".to_string(), e.to_string())))?
+            .into_iter()
+            .filter(|password| !password.is_empty())
+            .collect(),
+    };
+
+    let offline_rate: Option<f64> = match sub_matches.get_one::<f64>("rate_check") {
+        Some(rate) => Some(*rate),
+        None if sub_matches.get_flag("benchmark_check") => Some(benchmark::measure_guess_rate(num_cpus::get())?),
+        None => None,
+    };
+
+    let checker: StrengthChecker = StrengthChecker::new(&words);
+    let reports: Vec<StrengthReport> = passwords.iter().map(|password| checker.check(password)).collect();
+    if sub_matches.get_flag("json_check") {
+        println!("{}", json::strength_reports_to_json(&reports, offline_rate));
+    } else {
+        let reports: Vec<String> = reports.iter().map(|report| format_strength_report(report, offline_rate)).collect();
+        print!("{}", reports.join("\n"));
+    }
+
+    Ok(())
 }
 
-/// This function is responsible for scheduling the execution of the different features of the program according to the user's choices.
+/// This function is responsible for formatting the strength report of a password for the plain text output.
+///
+/// # Arguments
+///
+/// * `report` - The strength report of the password.
+/// * `offline_rate` - The number of guesses per second of an offline attack, None to use the reference rates.
 ///
 /// # Returns
 ///
-/// Ok(()) if the program has been executed, WorgenXError otherwise.
+/// The report, on several lines.
 ///
-pub fn run() -> Result<(), WorgenXError> {
-    let mut command_context: Command = build_command_context();
-    if let Ok(matches) = command_context.clone().try_get_matches() {
-        // Call the `display_help()` function instead of clap help with the -h or --help arguments (better control of the help message).
-        if matches.get_flag("help") {
-            display_help();
-            return Ok(());
-        }
-        // Call the `println!()` macro instead of clap version with the -v or --version arguments (better control of the version message).
-        if matches.get_flag("version") {
-            println!("WorgenX v{}", env!("CARGO_PKG_VERSION"));
-            return Ok(());
-        }
+fn format_strength_report(report: &StrengthReport, offline_rate: Option<f64>) -> String {
+    let mut output: String = format!(
+        "Password: {}\n  Entropy: {:.2} bits ({})\n  Estimated guesses: {:.1e}\n  Patterns:\n",
+        report.password,
+        report.entropy,
+        password::get_strength_label(report.entropy),
+        report.guesses
+    );
+    for pattern in &report.patterns {
+        let word: String = match &pattern.word {
+            Some(word) => format!(" \"{}\"{}", word, if pattern.l33t { " (l33t)" } else { "" }),
+            None => String::new(),
+        };
+        output.push_str(&format!("    {}{}: {}\n", pattern.kind.get_name(), word, pattern.token));
+    }
+    output.push_str("  Time to crack:\n");
+    for (scenario, _, seconds) in strength::get_crack_times(report.guesses, offline_rate) {
+        output.push_str(&format!("    {}: {}\n", scenario, strength::format_crack_time(seconds)));
     }
+    output
+}
 
-    command_context.build();
-    match command_context.get_matches().subcommand() {
-        Some(("wordlist", sub_matches)) => run_wordlist(sub_matches),
-        Some(("password", sub_matches)) => run_passwd(sub_matches),
-        Some(("benchmark", sub_matches)) => run_benchmark(sub_matches),
-        _ => Err(WorgenXError::ArgError(ArgError::NoArgument))
+/// This function is responsible for warning the user that the output is reproducible if a seed is given.
+///
+/// # Arguments
+///
+/// * `seed` - The seed given by the user, if any.
+///
+fn display_seed_warning(seed: Option<u64>) {
+    if let Some(seed) = seed {
+        eprintln!("Warning: INSECURE output, generated from the seed {}. Anyone knowing the seed can generate the same output, don't use it as real credentials", seed);
     }
 }
 
-/// This function is responsible for scheduling the execution of the random password generation functions of the program.
+/// This function is responsible for creating the output file of the password or passphrase generation.
+/// The file is created or truncated.
 ///
 /// # Arguments
 ///
-/// * `sub_matches` - A reference to ArgMatches containing the arguments passed to the program.
+/// * `output_file` - The path of the file.
 ///
 /// # Returns
 ///
-/// Ok(()) if the password has been generated, WorgenXError otherwise.
+/// The file, WorgenXError if it can't be created.
 ///
-fn run_passwd(sub_matches: &ArgMatches) -> Result<(), WorgenXError> {
-    let password_generation_parameters: PasswordGenerationOptions = allocate_passwd_config_cli(sub_matches)?;
-    let passwords: Vec<String> = password::generate_random_passwords(
-        &password_generation_parameters.password_config,
-    );
-    let all_passwords: String = if password_generation_parameters.json {
-        json::password_config_to_json(
-            &password_generation_parameters.password_config,
-            &passwords,
-        )
-    } else {
-        passwords.join("\n")
-    };
-
-    if !password_generation_parameters.no_display {
-        println!("{}", all_passwords);
-    }
-
-    if !password_generation_parameters.output_file.is_empty() {
-        let file: File = match OpenOptions::new()
-            .write(true)
-            .create(true)
-            .truncate(true)
-            .open(password_generation_parameters.output_file.clone())
-        {
-            Ok(file) => file,
-            Err(_) => {
-                return Err(WorgenXError::SystemError(SystemError::UnableToCreateFile(
-                    password_generation_parameters.output_file,
-                    "Please check the path and try again".to_string(),
-                )))
-            }
-        };
-        let shared_file: Arc<Mutex<File>> = Arc::new(Mutex::new(file));
-        system::save_passwd_to_file(&shared_file, &all_passwords)?;
+fn create_output_file(output_file: String) -> Result<File, WorgenXError> {
+    match OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(output_file.clone())
+    {
+        Ok(file) => Ok(file),
+        Err(_) => Err(WorgenXError::SystemError(SystemError::UnableToCreateFile(
+            output_file,
+            "Please check the path and try again".to_string(),
+        ))),
     }
+}
 
-    Ok(())
+/// This function is responsible for saving the output of the passphrase generation in a file.
+///
+/// # Arguments
+///
+/// * `output_file` - The path of the file.
+/// * `content` - The content to save.
+///
+/// # Returns
+///
+/// Ok(()) if the content has been saved, WorgenXError otherwise.
+///
+fn save_output_to_file(output_file: String, content: &str) -> Result<(), WorgenXError> {
+    let shared_file: Arc<Mutex<File>> = Arc::new(Mutex::new(create_output_file(output_file)?));
+    system::save_passwd_to_file(&shared_file, content)
 }
 
 /// This function is responsible for checking the values of the arguments passed to the program for the random password generation feature.
@@ -311,13 +1122,55 @@ fn run_passwd(sub_matches: &ArgMatches) -> Result<(), WorgenXError> {
 /// PasswordGenerationOptions containing the password configuration and optional arguments, WorgenXError otherwise.
 ///
 fn allocate_passwd_config_cli(sub_matches: &ArgMatches) -> Result<PasswordGenerationOptions, WorgenXError> {
-    // Clap framework ensures that the arguments are present.
-    let length: u32 = *sub_matches.get_one::<u32>("size").unwrap();
+    // Clap framework ensures that the arguments are present, the size is only missing with a template or a policy.
     let size: u64 = *sub_matches.get_one::<u64>("count").unwrap();
-    
+
     let mut output_file: String = String::new();
     let mut json: bool = false;
     let mut no_display: bool = false;
+    let mut password_config: PasswordConfig = match sub_matches.get_one::<String>("policy_password") {
+        Some(policy_file) => policy::policy_to_password_config(policy::load_policy(policy_file)?, sub_matches.get_one::<(u32, u32)>("size").copied(), size)?,
+        None => allocate_passwd_characters_cli(sub_matches, size)?,
+    };
+
+    update_config(&mut password_config.unique, sub_matches, "unique_password");
+    update_config(&mut json, sub_matches, "json");
+    update_config(&mut output_file, sub_matches, "output");
+    update_config(&mut output_file, sub_matches, "output_only");
+
+    if !output_file.is_empty(){
+        output_file = check_output_arg(&output_file)?;
+    }
+
+    if sub_matches.get_one::<String>("output_only").is_some() {
+        no_display = true;
+    }
+    password::check_password_config(&password_config)?;
+
+    Ok(PasswordGenerationOptions {
+        password_config,
+        seed: sub_matches.get_one::<u64>("seed_password").copied(),
+        json,
+        output_file,
+        no_display,
+        // Clap framework ensures that the argument has a default value.
+        threads: *sub_matches.get_one::<usize>("threads_password").unwrap(),
+    })
+}
+
+/// This function is responsible for building the password configuration from the types of characters passed to the program, without any policy file.
+///
+/// # Arguments
+///
+/// * `sub_matches` - A reference to ArgMatches containing the arguments passed to the program.
+/// * `size` - The number of passwords to generate.
+///
+/// # Returns
+///
+/// The password configuration, WorgenXError if no type of characters is specified.
+///
+fn allocate_passwd_characters_cli(sub_matches: &ArgMatches, size: u64) -> Result<PasswordConfig, WorgenXError> {
+    let (length, max_length): (u32, u32) = sub_matches.get_one::<(u32, u32)>("size").copied().unwrap_or_default();
     let mut password_config: PasswordConfig =
         PasswordConfig {
             numbers: false,
@@ -325,39 +1178,39 @@ fn allocate_passwd_config_cli(sub_matches: &ArgMatches) -> Result<PasswordGenera
             uppercase: false,
             lowercase: false,
             length,
+            max_length,
             number_of_passwords: size,
+            ..Default::default()
         };
 
     update_config(&mut password_config.lowercase, sub_matches, "lowercase_password");
     update_config(&mut password_config.uppercase, sub_matches, "uppercase_password");
     update_config(&mut password_config.numbers, sub_matches, "numbers_password");
     update_config(&mut password_config.special_characters, sub_matches, "special_characters_password");
-    update_config(&mut json, sub_matches, "json");
-    update_config(&mut output_file, sub_matches, "output");
-    update_config(&mut output_file, sub_matches, "output_only");
-
-    if !output_file.is_empty(){
-        output_file = check_output_arg(&output_file)?;
-    }
-
-    if sub_matches.get_one::<String>("output_only").is_some() {
-        no_display = true;
-    }
+    update_config(&mut password_config.template, sub_matches, "template_password");
+    update_config(&mut password_config.pronounceable, sub_matches, "pronounceable_password");
+    update_config(&mut password_config.charset, sub_matches, "charset_password");
+    password_config.charset.push_str(&get_builtin_charsets(sub_matches, "builtin_password"));
+    update_config(&mut password_config.exclude, sub_matches, "exclude_password");
+    update_config(&mut password_config.no_ambiguous, sub_matches, "no_ambiguous_password");
+    update_config(&mut password_config.min_lowercase, sub_matches, "min_lowercase");
+    update_config(&mut password_config.min_uppercase, sub_matches, "min_uppercase");
+    update_config(&mut password_config.min_numbers, sub_matches, "min_numbers");
+    update_config(&mut password_config.min_special_characters, sub_matches, "min_special_characters");
+    password_config.class_coverage = !sub_matches.get_flag("no_class_coverage");
 
     if !password_config.lowercase
         && !password_config.uppercase
         && !password_config.numbers
         && !password_config.special_characters
+        && password_config.charset.is_empty()
+        && !password_config.pronounceable
+        && password_config.template.is_empty()
     {
         return Err(WorgenXError::ArgError(ArgError::MissingConfiguration));
     }
 
-    Ok(PasswordGenerationOptions {
-        password_config,
-        json,
-        output_file,
-        no_display,
-    })
+    Ok(password_config)
 }
 
 /// This function is responsible for scheduling the execution of the wordlist generation functions.
@@ -373,18 +1226,104 @@ fn allocate_passwd_config_cli(sub_matches: &ArgMatches) -> Result<PasswordGenera
 fn run_wordlist(sub_matches: &ArgMatches) -> Result<(), WorgenXError> {
     let wordlist_generation_parameters: WordlistGenerationOptions = allocate_wordlist_config_cli(sub_matches)?;
 
-    let wordlist_config: WordlistConfig = wordlist::build_wordlist_config(&wordlist_generation_parameters.wordlist_values);
-    let nb_of_passwords: u64 = wordlist_config.dict.len().pow(wordlist_config.mask_indexes.len() as u32) as u64;
-    println!("Estimated size of the wordlist: {}", system::get_estimated_size(nb_of_passwords, wordlist_config.formated_mask.len() as u64));
+    match &wordlist_generation_parameters.words_file {
+        Some(words_file) if !wordlist_generation_parameters.wordlist_values.mask.is_empty() => {
+            let hybrid_config: HybridConfig = HybridConfig {
+                words: wordlist::load_words(words_file, wordlist_generation_parameters.unique)?,
+                mask: wordlist::build_wordlist_config(&wordlist_generation_parameters.wordlist_values),
+                position: if wordlist_generation_parameters.prepend { HybridPosition::Prepend } else { HybridPosition::Append },
+            };
+            apply_rules_and_generate(hybrid_config, &wordlist_generation_parameters)
+        }
+        Some(words_file) if wordlist_generation_parameters.leet || wordlist_generation_parameters.case_permutations => {
+            let mutation_config: MutationConfig = MutationConfig {
+                words: wordlist::load_words(words_file, wordlist_generation_parameters.unique)?,
+                substitutions: match (&wordlist_generation_parameters.leet_table_file, wordlist_generation_parameters.leet) {
+                    (Some(leet_table_file), _) => mutation::load_substitution_table(leet_table_file)?,
+                    (None, true) => mutation::get_default_substitutions(),
+                    (None, false) => Vec::new(),
+                },
+                case_permutations: wordlist_generation_parameters.case_permutations,
+                max_changes: wordlist_generation_parameters.max_changes,
+                hash: wordlist_generation_parameters.wordlist_values.hash.clone(),
+                raw_separator: wordlist_generation_parameters.wordlist_values.raw_separator.clone(),
+            };
+            println!(
+                "Expansion factor: x{:.2} ({} passwords from {} words)",
+                mutation_config.get_expansion_factor(),
+                mutation_config.get_nb_of_passwords(),
+                mutation_config.words.len()
+            );
+            apply_rules_and_generate(mutation_config, &wordlist_generation_parameters)
+        }
+        Some(words_file) => {
+            let words_config: WordsConfig = WordsConfig {
+                words: wordlist::load_words(words_file, wordlist_generation_parameters.unique)?,
+                hash: wordlist_generation_parameters.wordlist_values.hash.clone(),
+                raw_separator: wordlist_generation_parameters.wordlist_values.raw_separator.clone(),
+            };
+            apply_rules_and_generate(words_config, &wordlist_generation_parameters)
+        }
+        None => {
+            let wordlist_config: WordlistConfig = wordlist::build_wordlist_config(&wordlist_generation_parameters.wordlist_values);
+            apply_rules_and_generate(wordlist_config, &wordlist_generation_parameters)
+        }
+    }
+}
+
+/// This function is responsible for wrapping the source of passwords with the rules of the rule file, if any, before generating the wordlist.
+///
+/// # Arguments
+///
+/// * `source` - The source of the passwords (a mask or a list of words).
+/// * `wordlist_generation_parameters` - The options of the wordlist generation.
+///
+/// # Returns
+///
+/// Ok(()) if the wordlist has been generated, WorgenXError otherwise.
+///
+fn apply_rules_and_generate<S: PasswordSource>(source: S, wordlist_generation_parameters: &WordlistGenerationOptions) -> Result<(), WorgenXError> {
+    match &wordlist_generation_parameters.rules_file {
+        Some(rules_file) => {
+            let rules_config: RulesConfig<S> = RulesConfig {
+                source,
+                rules: rules::load_rules(rules_file)?,
+            };
+            generate_wordlist(
+                &rules_config,
+                wordlist_generation_parameters.threads,
+                &wordlist_generation_parameters.output_file,
+                wordlist_generation_parameters.no_loading_bar,
+            )
+        }
+        None => generate_wordlist(
+            &source,
+            wordlist_generation_parameters.threads,
+            &wordlist_generation_parameters.output_file,
+            wordlist_generation_parameters.no_loading_bar,
+        ),
+    }
+}
+
+/// This function is responsible for generating the wordlist from a source of passwords (a mask or a list of words).
+///
+/// # Arguments
+///
+/// * `source` - The source of the passwords.
+/// * `nb_of_threads` - The number of threads to use.
+/// * `output_file` - The path of the file where the wordlist will be saved.
+/// * `no_loading_bar` - A boolean to specify if the loading bar should be displayed or not.
+///
+/// # Returns
+///
+/// Ok(()) if the wordlist has been generated, WorgenXError otherwise.
+///
+fn generate_wordlist<S: PasswordSource>(source: &S, nb_of_threads: usize, output_file: &str, no_loading_bar: bool) -> Result<(), WorgenXError> {
+    let nb_of_passwords: u64 = source.get_nb_of_passwords();
+    println!("Estimated size of the wordlist: {}", system::get_estimated_size(nb_of_passwords, source.get_password_size()));
     println!("Wordlist generation in progress...");
 
-    wordlist::wordlist_generation_scheduler(
-        &wordlist_config,
-        nb_of_passwords,
-        wordlist_generation_parameters.threads,
-        &wordlist_generation_parameters.output_file,
-        wordlist_generation_parameters.no_loading_bar,
-    )
+    wordlist::wordlist_generation_scheduler(source, nb_of_passwords, nb_of_threads, output_file, no_loading_bar)
 }
 
 /// This function is responsible for checking the values of the arguments passed to the program.
@@ -402,41 +1341,132 @@ fn allocate_wordlist_config_cli(sub_matches: &ArgMatches) -> Result<WordlistGene
     let mut output_file: String = String::new();
     let mut no_loading_bar: bool = false;
     let mut threads: usize = 0;
+    let mut unique: bool = false;
+    let mut prepend: bool = false;
+    let mut case_permutations: bool = false;
+    let mut leet: bool = false;
     let mut wordlist_values: WordlistValues = WordlistValues {
         numbers: false,
         special_characters: false,
         uppercase: false,
         lowercase: false,
+        charset: String::new(),
         mask: String::new(),
         hash: String::new(),
+        raw_separator: None,
+        byte_placeholder: false,
     };
 
     update_config(&mut wordlist_values.lowercase, sub_matches, "lowercase_wordlist");
     update_config(&mut wordlist_values.uppercase, sub_matches, "uppercase_wordlist");
     update_config(&mut wordlist_values.numbers, sub_matches, "numbers_wordlist");
     update_config(&mut wordlist_values.special_characters, sub_matches, "special_characters_wordlist");
+    update_config(&mut wordlist_values.charset, sub_matches, "charset_wordlist");
+    wordlist_values.charset.push_str(&get_builtin_charsets(sub_matches, "builtin_wordlist"));
     update_config(&mut wordlist_values.mask, sub_matches, "mask");
+    update_config(&mut wordlist_values.byte_placeholder, sub_matches, "bytes_wordlist");
     update_config(&mut wordlist_values.hash, sub_matches, "hash");
     update_config(&mut output_file, sub_matches, "output");
     update_config(&mut no_loading_bar, sub_matches, "disable_loading_bar");
     update_config(&mut threads, sub_matches, "threads_wordlist");
+    wordlist_values.raw_separator = sub_matches.get_one::<Vec<u8>>("raw_separator_wordlist").cloned();
+    update_config(&mut unique, sub_matches, "unique_wordlist");
+    update_config(&mut prepend, sub_matches, "prepend_wordlist");
+    update_config(&mut leet, sub_matches, "leet_wordlist");
+    update_config(&mut case_permutations, sub_matches, "case_wordlist");
+    let words_file: Option<String> = sub_matches.get_one::<String>("words_wordlist").cloned();
+    let leet_table_file: Option<String> = sub_matches.get_one::<String>("leet_table_wordlist").cloned();
+    let max_changes: Option<usize> = sub_matches.get_one::<usize>("max_changes_wordlist").copied();
+    let rules_file: Option<String> = sub_matches.get_one::<String>("rules_wordlist").cloned();
 
-    if !wordlist_values.lowercase
+    // A mask made only of bytes (?b with --bytes) doesn't need any type of characters.
+    let (_, mask_indexes, byte_indexes): (Vec<char>, Vec<usize>, Vec<usize>) =
+        wordlist::format_mask_to_indexes(&wordlist_values.mask, wordlist_values.byte_placeholder);
+    if mask_indexes.len() > byte_indexes.len()
+        && !wordlist_values.lowercase
         && !wordlist_values.uppercase
         && !wordlist_values.numbers
         && !wordlist_values.special_characters
+        && wordlist_values.charset.is_empty()
     {
         return Err(WorgenXError::ArgError(ArgError::MissingConfiguration));
     }
+    if let Some(c) = wordlist_values.charset.chars().find(|c| c.is_control()) {
+        return Err(WorgenXError::ArgError(ArgError::InvalidCharacter(c)));
+    }
 
     Ok(WordlistGenerationOptions {
         wordlist_values,
+        words_file,
+        unique,
+        prepend,
+        leet: leet || leet_table_file.is_some(),
+        leet_table_file,
+        case_permutations,
+        max_changes,
+        rules_file,
         output_file,
         no_loading_bar,
         threads,
     })
 }
 
+/// This function is responsible for scheduling the execution of the combinator mode.
+///
+/// # Arguments
+///
+/// * `sub_matches` - A reference to ArgMatches containing the arguments passed to the program.
+///
+/// # Returns
+///
+/// Ok(()) if the wordlist has been generated, WorgenXError otherwise.
+///
+fn run_combine(sub_matches: &ArgMatches) -> Result<(), WorgenXError> {
+    let combine_parameters: CombineOptions = allocate_combine_config_cli(sub_matches);
+    let combine_config: CombineConfig = CombineConfig {
+        lists: combine_parameters
+            .lists
+            .iter()
+            .map(|path| wordlist::load_words(path, false))
+            .collect::<Result<Vec<Vec<Vec<u8>>>, WorgenXError>>()?,
+        separator: combine_parameters.separator.into_bytes(),
+        hash: combine_parameters.hash,
+        raw_separator: combine_parameters.raw_separator,
+    };
+
+    generate_wordlist(&combine_config, combine_parameters.threads, &combine_parameters.output_file, combine_parameters.no_loading_bar)
+}
+
+/// This function is responsible for checking the values of the arguments passed to the program.
+/// This function is called only if the user specifies the combine command.
+///
+/// # Arguments
+///
+/// * `sub_matches` - A reference to ArgMatches containing the arguments passed to the program.
+///
+/// # Returns
+///
+/// CombineOptions struct containing the paths of the lists of words and the optional arguments.
+///
+fn allocate_combine_config_cli(sub_matches: &ArgMatches) -> CombineOptions {
+    let mut combine_options: CombineOptions = CombineOptions {
+        lists: sub_matches.get_many::<String>("lists_combine").map(|lists| lists.cloned().collect()).unwrap_or_default(),
+        separator: String::new(),
+        hash: String::new(),
+        raw_separator: sub_matches.get_one::<Vec<u8>>("raw_separator_combine").cloned(),
+        output_file: String::new(),
+        no_loading_bar: false,
+        threads: 0,
+    };
+
+    update_config(&mut combine_options.separator, sub_matches, "separator_combine");
+    update_config(&mut combine_options.hash, sub_matches, "hash_combine");
+    update_config(&mut combine_options.output_file, sub_matches, "output_combine");
+    update_config(&mut combine_options.no_loading_bar, sub_matches, "disable_loading_bar_combine");
+    update_config(&mut combine_options.threads, sub_matches, "threads_combine");
+    combine_options
+}
+
 /// This function is responsible for scheduling the execution of the benchmark functions of WorgenX.
 /// It will display the number of passwords generated in 1 minute.
 /// The benchmark is based on the generation of random passwords.
@@ -514,45 +1544,130 @@ fn update_config<T: Clone + Send + Sync + 'static>(
     }
 }
 
+/// This function is responsible for returning the characters of the built-in charsets selected by the user.
+///
+/// # Arguments
+///
+/// * `sub_matches` - A reference to ArgMatches containing the arguments sent to the program.
+/// * `key` - The name of the argument of the built-in charsets.
+///
+/// # Returns
+///
+/// The characters of the selected built-in charsets, an empty string if there isn't any.
+///
+fn get_builtin_charsets(sub_matches: &ArgMatches, key: &str) -> String {
+    sub_matches
+        .get_many::<String>(key)
+        .unwrap_or_default()
+        .filter_map(|name| dict::BUILTIN_CHARSETS.iter().find(|(builtin_name, _)| builtin_name == name))
+        .flat_map(|(_, characters)| characters.iter().map(|c| char::from(*c)))
+        .collect()
+}
+
 /// This function is responsible for displaying the help menu with all the features of WorgenX and their options.
 ///
 fn display_help() {
     println!("Usage: worgenX <command> [options]");
     println!("Commands:");
     println!("  wordlist\t\tGenerate a wordlist");
+    println!("  combine\t\tCombine the words of two or three wordlists");
     println!("  password\t\tGenerate random password(s)");
+    println!("  passphrase\t\tGenerate random passphrase(s) from a list of words");
+    println!("  check\t\t\tCheck the strength of password(s)");
     println!("  benchmark\t\tCPU Benchmark");
     println!("  -v, --version\t\tDisplay the version of WorgenX");
     println!("  -h, --help\t\tDisplay this help message\n\n");
     println!("You can find below the options for the main features of WorgenX:\n");
 
     println!("  --- Wordlist generation ---");
-    println!("  You must specify at least one of the following options: -l, -u, -n, -x");
+    println!("  You must specify at least one of the following options: -l, -u, -n, -x, --charset, --builtin (unless the mask only contains ?b with --bytes or only --words is used)");
     println!("    -l, --lowercase\t\t\tAdd lowercase characters to the words");
     println!("    -u, --uppercase\t\t\tAdd uppercase characters to the words");
     println!("    -n, --numbers\t\t\tAdd numbers to the words");
     println!("    -x, --special-characters\t\tAdd special characters to the words");
+    println!("    --charset <chars>\t\t\tAdd the given characters to the words (any Unicode characters, e.g. éàü or жπ)");
+    println!("    --builtin <charset>\t\t\tAdd a built-in charset to the words, can be repeated: hex, hex-upper, base64, base58, vowels, consonants,\n\t\t\t\t\tkeyboard-numbers, keyboard-top, keyboard-home, keyboard-bottom (QWERTY rows)");
     println!("\n  These parameters are mandatory:");
-    println!("    -m <mask>, --mask <mask>\t\tMask used to generate the words");
+    println!("    -m <mask>, --mask <mask>\t\tMask used to generate the words (optional with --words)\n\t\t\t\t\t? is a variable character, use \\ to escape a character");
     println!("    -o <path>, --output <path>\t\tSave the wordlist in a text file");
     println!("\n  The following options are optional:");
     println!("    -d, --disable-loading-bar\t\tDisable the loading bar when generating the wordlist");
+    println!("    --bytes\t\t\t\tMake ?b in the mask any byte (0x00-0xFF), the non-printable words are written in hashcat's $HEX[...] notation\n\t\t\t\t\tWithout --bytes, ?b is a variable character followed by b as in the previous versions, write ?\\b for it with --bytes");
+    println!("    --words <path>\t\t\tGenerate the wordlist from the words of a file (one word per line)\n\t\t\t\t\tThe lines in the $HEX[...] notation are decoded\n\t\t\t\t\tWith -m, each word is combined with every expansion of the mask (hybrid mode, e.g. word??)");
+    println!("    --prepend\t\t\t\tPut the mask before the words in the hybrid mode (e.g. ??word)");
+    println!("    --unique\t\t\t\tRemove the duplicate words of the file given with --words");
+    println!("    --leet\t\t\t\tExpand each word of the file given with --words into its l33t substitutions\n\t\t\t\t\tBy default: a -> 4 @, b -> 8, e -> 3, g -> 9 6, i -> 1 ! |, l -> 1 |, o -> 0, s -> 5 $, t -> 7 +, z -> 2");
+    println!("    --leet-table <path>\t\t\tUse the l33t substitutions of a file instead of the default ones, implies --leet\n\t\t\t\t\tEach line is a character followed by its substitutions, separated by spaces (e.g. a 4 @)");
+    println!("    --case\t\t\t\tExpand each word of the file given with --words into all its case permutations");
+    println!("    --max-changes <number>\t\tMaximum number of characters changed in each word by --leet and --case (all by default)\n\t\t\t\t\tThe expansion factor (number of passwords per word) is displayed before the generation");
+    println!("    -r <path>, --rules <path>\t\tApply the rules of a file (hashcat syntax, one rule per line) to each word\n\t\t\t\t\tThe common functions are supported (e.g. : l u c C t TN r d pN f {{ }} $X ^X [ ] DN xNM ONM iNX oNX 'N sXY @X zN ZN q k K *NM E eX)\n\t\t\t\t\tEach word is written once per rule, the number of words is multiplied by the number of rules");
+    println!("    --raw-separator <separator>\t\tWrite the words as raw bytes followed by the separator instead of one word per line\n\t\t\t\t\tThe escape sequences \\n, \\r, \\t, \\0, \\\\ and \\xHH are supported (e.g. \\0)");
     println!("    -h, --hash <hash>\t\t\tHash algorithm to use for the wordlist.\n\t\t\t\t\tYou can choose between: md5, sha1, sha224, sha256, sha384, sha512,\n\t\t\t\t\tsha3-224, sha3-256, sha3-384, sha3-512, blake2b-512, blake2s-256 and whirlpool");
     println!("    -t <threads>, --threads <threads>\tNumber of threads to generate the passwords\n\t\t\t\t\tBy default, the number of threads is based on the number of logical cores of the CPU");
 
+    println!("\n  --- Combinator mode ---");
+    println!("  Usage: worgenX combine <left> <right> [<third>] [options]");
+    println!("  Each word of the first file is combined with each word of the second file (and of the third file, if any)");
+    println!("\n  This parameter is mandatory:");
+    println!("    -o <path>, --output <path>\t\tSave the wordlist in a text file");
+    println!("\n  The following options are optional:");
+    println!("    -S <sep>, --sep <sep>\t\tSeparator between the words (none by default)");
+    println!("    -d, --disable-loading-bar\t\tDisable the loading bar when generating the wordlist");
+    println!("    --raw-separator <separator>\t\tWrite the words as raw bytes followed by the separator instead of one word per line");
+    println!("    -h, --hash <hash>\t\t\tHash algorithm to use for the wordlist (same algorithms as the wordlist generation)");
+    println!("    -t <threads>, --threads <threads>\tNumber of threads to generate the passwords\n\t\t\t\t\tBy default, the number of threads is based on the number of logical cores of the CPU");
     println!("\n  --- Password generation ---");
-    println!("  You must specify at least one of the following options: -l, -u, -n, -x");
+    println!("  You must specify at least one of the following options: -l, -u, -n, -x, --charset, --builtin, -p, -T, --policy");
     println!("    -l, --lowercase\t\t\tAdd lowercase characters to the words");
     println!("    -u, --uppercase\t\t\tAdd uppercase characters to the words");
     println!("    -n, --numbers\t\t\tAdd numbers to the words");
     println!("    -x, --special-characters\t\tAdd special characters to the words");
     println!("\n  These parameters are mandatory:");
-    println!("    -s <size>, --size <size>\t\tSize of the passwords in characters");
+    println!("    -s <size>, --size <size>\t\tSize of the passwords in characters or a range like 12-20 (not used with -T, optional with --policy)");
     println!("    -c <count>, --count <count>\t\tNumber of passwords to generate");
     println!("\n  The following options are optional:");
     println!("    -o <path>, --output <path>\t\tSave the passwords in a file");
     println!("    -O <path>, --output-only <path>\tSave the passwords only in a file, not in stdout");
-    println!("    -j, --json\t\t\t\tOutput in JSON format\n\t\t\t\t\tCombine with -o to save the json output in a file");
+    println!("    -t <threads>, --threads <threads>\tNumber of threads to generate the passwords\n\t\t\t\t\tBy default, the number of threads is based on the number of logical cores of the CPU\n\t\t\t\t\tThe passwords are streamed in batches, so a large number of passwords doesn't fill the memory");
+    println!("    --seed <seed>\t\t\tSeed of the random generator, for reproducible output in tests and demos\n\t\t\t\t\tINSECURE: the output is marked as such and must not be used as real credentials\n\t\t\t\t\tBy default, the CSPRNG of the operating system is used\n\t\t\t\t\tThe passwords are generated by a single thread with a seed");
+    println!("    --policy <path>\t\t\tGenerate the passwords from a policy file (TOML, or JSON with the .json extension)\n\t\t\t\t\tCan't be combined with the types of characters, -s must be in the length range of the policy\n\t\t\t\t\tFields: length, min_length, max_length, lowercase, uppercase, numbers, special_characters,\n\t\t\t\t\tmin_lowercase, min_uppercase, min_numbers, min_special_characters, class_coverage,\n\t\t\t\t\tallowed_characters, forbidden_characters, no_ambiguous, max_repeated, forbidden_sequences");
+    println!("    --unique\t\t\t\tGuarantee that all the generated passwords are different\n\t\t\t\t\tAn error is raised if the count exceeds the number of possible passwords");
+    println!("    -j, --json\t\t\t\tOutput in JSON format\n\t\t\t\t\tCombine with -o to save the json output in a file\n\t\t\t\t\tThe entropy (in bits) and the strength of the passwords are included in the json output\n\t\t\t\t\tOtherwise, they are displayed on stderr");
+    println!("    -T <template>, --template <template>\tGenerate the passwords from a template instead of -s and the types of characters\n\t\t\t\t\t?l, ?u, ?d, ?s: a lowercase letter, an uppercase letter, a number, a special character\n\t\t\t\t\t?a or ?: any of these characters\n\t\t\t\t\tc, v, C, V: a lowercase/uppercase consonant or vowel\n\t\t\t\t\t9, !: a number or a special character\n\t\t\t\t\tThe other characters are fixed, use \\ to escape a character (e.g. Cvccvc-99-!!)\n\t\t\t\t\tEvery c, v, C, V, 9 and ! of a fixed text must be escaped (e.g. offi\\ce-\\9\\9 for office-99)");
+    println!("    -p, --pronounceable\t\t\tGenerate pronounceable passwords made of consonant-vowel syllables\n\t\t\t\t\tCan't be combined with the other types of characters, the entropy is based on the syllables");
+    println!("    --charset <chars>\t\t\tAdd the given characters to the alphabet of the passwords");
+    println!("    --builtin <charset>\t\t\tAdd a built-in charset to the alphabet of the passwords, can be repeated: hex, hex-upper, base64, base58, vowels, consonants,\n\t\t\t\t\tkeyboard-numbers, keyboard-top, keyboard-home, keyboard-bottom (QWERTY rows)");
+    println!("    --exclude <chars>\t\t\tRemove the given characters from the alphabet of the passwords");
+    println!("    --no-ambiguous\t\t\tRemove the look-alike characters (0O1lI|) from the alphabet of the passwords");
+    println!("    --no-class-coverage\t\t\tDon't require at least one character of each selected type\n\t\t\t\t\tBy default, each password contains at least one character of each selected type");
+    println!("    --min-lower <count>\t\t\tMinimum number of lowercase characters in each password");
+    println!("    --min-upper <count>\t\t\tMinimum number of uppercase characters in each password");
+    println!("    --min-digits <count>\t\tMinimum number of numbers in each password");
+    println!("    --min-special <count>\t\tMinimum number of special characters in each password");
+
+    println!("\n  --- Passphrase generation ---");
+    println!("  The following options are optional:");
+    println!("    -f <path>, --wordlist <path>\tFile containing the words of the passphrases (one word per line)\n\t\t\t\t\tThe diceware format (e.g. 11111<tab>abacus) is supported, the duplicates are removed\n\t\t\t\t\tBy default, the embedded EFF large wordlist is used");
+    println!("    -w <words>, --words <words>\t\tNumber of words in each passphrase (6 by default)");
+    println!("    -c <count>, --count <count>\t\tNumber of passphrases to generate (1 by default)");
+    println!("    -S <sep>, --separator <sep>\t\tSeparator between the words (- by default)");
+    println!("    -C, --capitalize\t\t\tCapitalize the first letter of each word\n\t\t\t\t\tThe words that are the same once capitalized (e.g. Word and word) are counted once");
+    println!("    -n, --append-number\t\t\tAppend a random number to the passphrases");
+    println!("    -x, --append-special-character\tAppend a random special character to the passphrases");
+    println!("    -o <path>, --output <path>\t\tSave the passphrases in a file");
+    println!("    -O <path>, --output-only <path>\tSave the passphrases only in a file, not in stdout");
+    println!("    --seed <seed>\t\t\tSeed of the random generator, for reproducible output in tests and demos\n\t\t\t\t\tINSECURE: the output is marked as such and must not be used as real credentials\n\t\t\t\t\tBy default, the CSPRNG of the operating system is used");
+    println!("    -j, --json\t\t\t\tOutput in JSON format, including the entropy (in bits) and the strength of the passphrases\n\t\t\t\t\tOtherwise, they are displayed on stderr");
+    println!("\n  --- Password strength check ---");
+    println!("  Usage: worgenX check [options] [password...]");
+    println!("  The passwords are read from stdin (one password per line) if none is given, to keep them out of the shell history");
+    println!("  The entropy, the patterns found (dictionary words, keyboard walks, dates, repeats, l33t substitutions),");
+    println!("  the estimated guesses and the time to crack in several attack scenarios are reported for each password");
+    println!("\n  The following options are optional:");
+    println!("    -f <path>, --wordlist <path>\tFile containing additional words to detect (one word per line)");
+    println!("    -r <guesses>, --rate <guesses>\tNumber of guesses per second of an offline attack, to estimate the time to crack");
+    println!("    -b, --benchmark\t\t\tMeasure the number of guesses per second of this CPU (5 seconds, without any hash) to estimate the time to crack offline\n\t\t\t\t\tBy default, the reference rates of a bcrypt and an MD5 cracking rig are used");
+    println!("    -j, --json\t\t\t\tOutput in JSON format");
 
     println!("\n  --- CPU Benchmark ---");
     println!("  The following option is optional:");
@@ -598,6 +1713,139 @@ mod tests {
         assert!(result.no_loading_bar);       
     }
 
+    #[test]
+    fn test_builtin_charsets_cli() {
+        let command_context: Command = build_command_context();
+        let matches: ArgMatches = command_context.get_matches_from(vec!["worgenX", "password", "--builtin", "hex", "--builtin", "vowels", "-s", "32", "-c", "5"]);
+        let (_, sub_matches) = matches.subcommand().unwrap();
+        let result: PasswordGenerationOptions = allocate_passwd_config_cli(sub_matches).unwrap();
+        assert_eq!(result.password_config.charset, "0123456789abcdefaeiou");
+
+        let command_context: Command = build_command_context();
+        let matches: ArgMatches = command_context.get_matches_from(vec!["worgenX", "wordlist", "--builtin", "base58", "-m", "SN-??", "-o", "test.txt"]);
+        let (_, sub_matches) = matches.subcommand().unwrap();
+        let result: WordlistGenerationOptions = allocate_wordlist_config_cli(sub_matches).unwrap();
+        let wordlist_config: WordlistConfig = wordlist::build_wordlist_config(&result.wordlist_values);
+        assert_eq!(wordlist::get_nb_of_passwords(&wordlist_config), 58 * 58);
+
+        let command_context: Command = build_command_context();
+        assert!(command_context.try_get_matches_from(vec!["worgenX", "password", "--builtin", "base32", "-s", "8"]).is_err());
+    }
+
+    #[test]
+    fn test_allocate_passphrase_config_cli() {
+        let command_context: Command = build_command_context();
+        let matches: ArgMatches = command_context.get_matches_from(vec!["worgenX", "passphrase", "-C", "-w", "4"]);
+        let (_, sub_matches) = matches.subcommand().unwrap();
+        let result: PassphraseGenerationOptions = allocate_passphrase_config_cli(sub_matches).unwrap();
+        assert_eq!(result.passphrase_config.number_of_words, 4);
+        assert!(result.passphrase_config.capitalize);
+        assert_eq!(result.words, passphrase::get_default_words());
+    }
+
+    #[test]
+    fn test_words_wordlist_cli() {
+        let command_context: Command = build_command_context();
+        let matches: ArgMatches = command_context.get_matches_from(vec!["worgenX", "wordlist", "--words", "base.txt", "--unique", "-o", "test.txt"]);
+        let (_, sub_matches) = matches.subcommand().unwrap();
+        let result: WordlistGenerationOptions = allocate_wordlist_config_cli(sub_matches).unwrap();
+        assert_eq!(result.words_file, Some(String::from("base.txt")));
+        assert!(result.unique);
+        assert!(result.wordlist_values.mask.is_empty());
+
+        let command_context: Command = build_command_context();
+        assert!(command_context.try_get_matches_from(vec!["worgenX", "wordlist", "--unique", "-l", "-m", "??", "-o", "test.txt"]).is_err());
+    }
+
+    #[test]
+    fn test_hybrid_wordlist_cli() {
+        let command_context: Command = build_command_context();
+        let matches: ArgMatches = command_context.get_matches_from(vec!["worgenX", "wordlist", "--words", "base.txt", "-n", "-m", "??", "--prepend", "-o", "test.txt"]);
+        let (_, sub_matches) = matches.subcommand().unwrap();
+        let result: WordlistGenerationOptions = allocate_wordlist_config_cli(sub_matches).unwrap();
+        assert_eq!(result.words_file, Some(String::from("base.txt")));
+        assert_eq!(result.wordlist_values.mask, "??");
+        assert!(result.prepend);
+
+        let command_context: Command = build_command_context();
+        let matches: ArgMatches = command_context.get_matches_from(vec!["worgenX", "wordlist", "--words", "base.txt", "-m", "??", "-o", "test.txt"]);
+        let (_, sub_matches) = matches.subcommand().unwrap();
+        assert!(allocate_wordlist_config_cli(sub_matches).is_err());
+
+        let command_context: Command = build_command_context();
+        assert!(command_context.try_get_matches_from(vec!["worgenX", "wordlist", "--words", "base.txt", "--prepend", "-o", "test.txt"]).is_err());
+    }
+
+    #[test]
+    fn test_mutation_wordlist_cli() {
+        let command_context: Command = build_command_context();
+        let matches: ArgMatches =
+            command_context.get_matches_from(vec!["worgenX", "wordlist", "--words", "base.txt", "--leet-table", "leet.txt", "--case", "--max-changes", "2", "-o", "test.txt"]);
+        let (_, sub_matches) = matches.subcommand().unwrap();
+        let result: WordlistGenerationOptions = allocate_wordlist_config_cli(sub_matches).unwrap();
+        assert!(result.leet);
+        assert_eq!(result.leet_table_file, Some(String::from("leet.txt")));
+        assert!(result.case_permutations);
+        assert_eq!(result.max_changes, Some(2));
+
+        let command_context: Command = build_command_context();
+        let matches: ArgMatches = command_context.get_matches_from(vec!["worgenX", "wordlist", "--words", "base.txt", "--leet", "-o", "test.txt"]);
+        let (_, sub_matches) = matches.subcommand().unwrap();
+        let result: WordlistGenerationOptions = allocate_wordlist_config_cli(sub_matches).unwrap();
+        assert!(result.leet && !result.case_permutations);
+        assert!(result.leet_table_file.is_none() && result.max_changes.is_none());
+
+        for args in [
+            vec!["worgenX", "wordlist", "--leet", "-l", "-m", "??", "-o", "test.txt"],
+            vec!["worgenX", "wordlist", "--words", "base.txt", "--case", "-l", "-m", "??", "-o", "test.txt"],
+            vec!["worgenX", "wordlist", "--words", "base.txt", "--max-changes", "2", "-o", "test.txt"],
+        ] {
+            let command_context: Command = build_command_context();
+            assert!(command_context.try_get_matches_from(args).is_err());
+        }
+    }
+
+    #[test]
+    fn test_rules_wordlist_cli() {
+        let command_context: Command = build_command_context();
+        let matches: ArgMatches = command_context.get_matches_from(vec!["worgenX", "wordlist", "--words", "base.txt", "-r", "best64.rule", "-o", "test.txt"]);
+        let (_, sub_matches) = matches.subcommand().unwrap();
+        let result: WordlistGenerationOptions = allocate_wordlist_config_cli(sub_matches).unwrap();
+        assert_eq!(result.rules_file, Some(String::from("best64.rule")));
+
+        let command_context: Command = build_command_context();
+        let matches: ArgMatches = command_context.get_matches_from(vec!["worgenX", "wordlist", "-n", "-m", "??", "-o", "test.txt"]);
+        let (_, sub_matches) = matches.subcommand().unwrap();
+        let result: WordlistGenerationOptions = allocate_wordlist_config_cli(sub_matches).unwrap();
+        assert!(result.rules_file.is_none());
+    }
+
+    #[test]
+    fn test_allocate_combine_config_cli() {
+        let command_context: Command = build_command_context();
+        let matches: ArgMatches = command_context.get_matches_from(vec!["worgenX", "combine", "left.txt", "right.txt", "--sep", "-", "-h", "md5", "-o", "test.txt", "-t", "2"]);
+        let (_, sub_matches) = matches.subcommand().unwrap();
+        let result: CombineOptions = allocate_combine_config_cli(sub_matches);
+        assert_eq!(result.lists, vec!["left.txt", "right.txt"]);
+        assert_eq!(result.separator, "-");
+        assert_eq!(result.hash, "md5");
+        assert_eq!(result.output_file, "test.txt");
+        assert_eq!(result.threads, 2);
+        assert!(!result.no_loading_bar);
+
+        let command_context: Command = build_command_context();
+        let matches: ArgMatches = command_context.get_matches_from(vec!["worgenX", "combine", "a.txt", "b.txt", "c.txt", "-o", "test.txt"]);
+        let (_, sub_matches) = matches.subcommand().unwrap();
+        let result: CombineOptions = allocate_combine_config_cli(sub_matches);
+        assert_eq!(result.lists.len(), 3);
+        assert!(result.separator.is_empty());
+
+        for args in [vec!["worgenX", "combine", "a.txt", "-o", "test.txt"], vec!["worgenX", "combine", "a.txt", "b.txt", "c.txt", "d.txt", "-o", "test.txt"]] {
+            let command_context: Command = build_command_context();
+            assert!(command_context.try_get_matches_from(args).is_err());
+        }
+    }
+
     #[test]
     fn test_allocate_benchmark_config_cli() {
         let command_context: Command = build_command_context();
@@ -1,32 +1,61 @@
 // Internal crates.
-use crate::{
+use worgen_x::{
     benchmark,
-    error::SystemError,
+    dict,
+    error::{ArgError, SystemError, WorgenXError},
     password::{self, PasswordConfig},
+    rng::WorgenXRng,
     system,
     wordlist::{self, WordlistConfig, WordlistValues},
 };
 
 #[cfg(target_family = "unix")]
-use system::unix as target;
+use unix as target;
 
 #[cfg(target_family = "windows")]
-use system::windows as target;
+use windows as target;
 
 // External crates.
 use std::{
+    cmp::PartialOrd,
+    default::Default,
     env,
+    fmt::Display,
     fs::{File, OpenOptions},
+    io::stdin,
+    marker::Copy,
+    path::Path,
+    str::FromStr,
     sync::{Arc, Mutex},
     thread,
 };
 
+
+/// OS specific constants for GUI mode.
+///
+/// * `HOME_ENV_VAR` - The environment variable that holds the user's home directory.
+/// * `PASSWORDS_FOLDER` - The folder where the passwords will be saved.
+/// * `WORDLISTS_FOLDER` - The folder where the wordlists will be saved.
+///
+#[cfg(target_family = "unix")]
+pub mod unix {
+    pub const HOME_ENV_VAR: &str = "HOME";
+    pub const PASSWORDS_FOLDER: &str = "/worgenx/passwords/";
+    pub const WORDLISTS_FOLDER: &str = "/worgenx/wordlists/";
+}
+#[cfg(target_family = "windows")]
+pub mod windows {
+    pub const HOME_ENV_VAR: &str = "USERPROFILE";
+    pub const PASSWORDS_FOLDER: &str = "\\worgenx\\passwords\\";
+    pub const WORDLISTS_FOLDER: &str = "\\worgenx\\wordlists\\";
+}
+
 /// This function is responsible for scheduling in GUI mode the execution of the different features of the program according to the user's choices.
 ///
 pub fn run() {
     loop {
         print_menu();
-        let choice: String = system::get_user_choice();
+        let choice: String = get_user_choice();
         match &*choice {
             "0" => break,
             "1" => main_wordlist_generation(),
@@ -80,12 +109,14 @@ fn main_passwd_generation() {
 
     while again.eq("y") {
         let password_config: PasswordConfig = allocate_passwd_config_gui();
-        let passwords: Vec<String> = password::generate_random_passwords(&password_config);
+        let passwords: Vec<String> = password::generate_random_passwords(&password_config, &mut WorgenXRng::new(None));
 
         println!("\nYou can find your password(s) below :\n\n{}", passwords.join("\n"));
+        let entropy: f64 = password::get_entropy(&password_config);
+        println!("\nEntropy of each password : {:.2} bits ({})", entropy, password::get_strength_label(entropy));
         println!("\nDo you want to save the passwords in a file ? (y/n)");
 
-        let choice: String = system::get_user_choice_yn();
+        let choice: String = get_user_choice_yn();
         if choice.eq("y") {
             let mut file_result: Result<(File, String), SystemError> =
                 saving_procedure(target::PASSWORDS_FOLDER);
@@ -93,7 +124,7 @@ fn main_passwd_generation() {
             while file_result.is_err() {
                 println!("{}", file_result.unwrap_err());
                 println!("Do you want to try again ? (y/n)");
-                if system::get_user_choice_yn().eq("n") {
+                if get_user_choice_yn().eq("n") {
                     return;
                 }
                 file_result = saving_procedure(target::PASSWORDS_FOLDER);
@@ -106,7 +137,7 @@ fn main_passwd_generation() {
             {
                 println!("\n{}", e);
                 println!("Do you want to try again ? (y/n)");
-                let choice: String = system::get_user_choice_yn();
+                let choice: String = get_user_choice_yn();
                 if choice.eq("n") {
                     println!("The passwords have not been saved");
                     return;
@@ -116,7 +147,7 @@ fn main_passwd_generation() {
         }
 
         println!("\nDo you want to generate another password(s) ? (y/n)");
-        again = system::get_user_choice_yn();
+        again = get_user_choice_yn();
     }
 
     println!("\n");
@@ -129,52 +160,91 @@ fn main_passwd_generation() {
 /// The password config structure named PasswordConfig.
 ///
 fn allocate_passwd_config_gui() -> PasswordConfig {
-    let mut password_config: PasswordConfig = PasswordConfig {
-        numbers: false,
-        special_characters: false,
-        uppercase: false,
-        lowercase: false,
-        length: 0,
-        number_of_passwords: 0,
-    };
-    let mut is_option_chosen: bool = false;
+    let mut password_config: PasswordConfig = PasswordConfig::default();
+    println!("\nDo you want to generate the password from a template (e.g. Cvccvc-99-!!) ? (y/n)");
+    if get_user_choice_yn().eq("y") {
+        println!("Enter the template (?l, ?u, ?d, ?s, ?a, ?, c, v, C, V, 9 and ! are random characters, use \\ to escape a character) :");
+        println!("Every c, v, C, V, 9 and ! of a fixed text must be escaped (e.g. offi\\ce-\\9\\9 for office-99).");
+        password_config.template = get_user_choice();
+        while password_config.template.is_empty() || password::check_password_config(&password_config).is_err() {
+            println!("The template is invalid, please enter a valid template :");
+            password_config.template = get_user_choice();
+        }
+
+        println!("How many passwords do you want to generate ?");
+        password_config.number_of_passwords = get_user_choice_int();
+        return password_config;
+    }
+
+    println!("Do you want a pronounceable password, easy to read over the phone ? (y/n)");
+    password_config.pronounceable = get_user_choice_yn().eq("y");
+    let mut is_option_chosen: bool = password_config.pronounceable;
 
     while !is_option_chosen {
         println!("\nChoose what your password is composed of :");
         println!("Uppercase letters (A-Z) ? (y/n)");
-        if system::get_user_choice_yn().eq("y") {
+        if get_user_choice_yn().eq("y") {
             password_config.uppercase = true;
             is_option_chosen = true;
         }
 
         println!("Lowercase letters (a-z) ? (y/n)");
-        if system::get_user_choice_yn().eq("y") {
+        if get_user_choice_yn().eq("y") {
             password_config.lowercase = true;
             is_option_chosen = true;
         }
 
         println!("Numbers (0-9) ? (y/n)");
-        if system::get_user_choice_yn().eq("y") {
+        if get_user_choice_yn().eq("y") {
             password_config.numbers = true;
             is_option_chosen = true;
         }
 
         println!("Special characters ? (y/n)");
-        if system::get_user_choice_yn().eq("y") {
+        if get_user_choice_yn().eq("y") {
             password_config.special_characters = true;
             is_option_chosen = true;
         }
 
+        println!("Custom characters ? (y/n)");
+        if get_user_choice_yn().eq("y") {
+            println!("Enter the characters (any printable Unicode characters) :");
+            password_config.charset = get_user_choice();
+            is_option_chosen = is_option_chosen || !password_config.charset.is_empty();
+        }
+
+        println!("Built-in charsets (hex, base64, keyboard rows...) ? (y/n)");
+        if get_user_choice_yn().eq("y") {
+            let builtin_charsets: String = get_builtin_charsets_choice();
+            password_config.charset.push_str(&builtin_charsets);
+            is_option_chosen = is_option_chosen || !builtin_charsets.is_empty();
+        }
+
         if !is_option_chosen {
             println!("You must choose at least one option !");
         }
     }
 
+    if !password_config.pronounceable {
+        println!("Remove the look-alike characters (0O1lI|) ? (y/n)");
+        password_config.no_ambiguous = get_user_choice_yn().eq("y");
+
+        println!("Must the password contain at least one character of each chosen type ? (y/n)");
+        password_config.class_coverage = get_user_choice_yn().eq("y");
+    }
+
     println!("How long do you want your password to be ?");
-    password_config.length = system::get_user_choice_int();
+    password_config.length = get_user_choice_int();
+    while let Err(e) = password::check_password_config(&password_config) {
+        println!("{}", e);
+        if !matches!(e, WorgenXError::ArgError(ArgError::UnsatisfiableMinimums(_, _))) {
+            return allocate_passwd_config_gui();
+        }
+        password_config.length = get_user_choice_int();
+    }
 
     println!("How many passwords do you want to generate ?");
-    password_config.number_of_passwords = system::get_user_choice_int();
+    password_config.number_of_passwords = get_user_choice_int();
 
     password_config
 }
@@ -187,17 +257,13 @@ fn main_wordlist_generation() {
     while again.eq("y") {
         let wordlist_values: WordlistValues = allocate_wordlist_config_gui();
         let wordlist_config: WordlistConfig = wordlist::build_wordlist_config(&wordlist_values);
-        let nb_of_passwords: u64 = wordlist_config
-            .dict
-            .len()
-            .pow(wordlist_config.mask_indexes.len() as u32)
-            as u64;
+        let nb_of_passwords: u64 = wordlist::get_nb_of_passwords(&wordlist_config);
         println!(
             "Estimated size of the wordlist: {}",
-            system::get_estimated_size(nb_of_passwords, wordlist_config.formated_mask.len() as u64)
+            system::get_estimated_size(nb_of_passwords, wordlist::get_password_size(&wordlist_config))
         );
         println!("Do you want to continue ? (y/n)");
-        if system::get_user_choice_yn().eq("n") {
+        if get_user_choice_yn().eq("n") {
             return;
         }
 
@@ -205,7 +271,7 @@ fn main_wordlist_generation() {
         while file_result.is_err() {
             println!("{}", file_result.unwrap_err());
             println!("Do you want to try again ? (y/n)");
-            if system::get_user_choice_yn().eq("n") {
+            if get_user_choice_yn().eq("n") {
                 return;
             }
             file_result = saving_procedure(target::WORDLISTS_FOLDER);
@@ -226,7 +292,7 @@ fn main_wordlist_generation() {
 
         println!("The wordlist has been saved in the file : {}", filename);
         println!("\nDo you want to generate another wordlist ? (y/n)");
-        again = system::get_user_choice_yn();
+        again = get_user_choice_yn();
     }
     println!("\n");
 }
@@ -243,44 +309,61 @@ fn allocate_wordlist_config_gui() -> WordlistValues {
         special_characters: false,
         uppercase: false,
         lowercase: false,
+        charset: String::new(),
         mask: String::new(),
         hash: String::new(),
+        raw_separator: None,
+        byte_placeholder: false,
     };
     let mut is_option_chosen: bool = false;
 
     while !is_option_chosen {
         println!("\nChoose what your wordlist is composed of :");
         println!("Uppercase letters (A-Z) ? (y/n)");
-        if system::get_user_choice_yn().eq("y") {
+        if get_user_choice_yn().eq("y") {
             wordlist_config.uppercase = true;
             is_option_chosen = true;
         }
 
         println!("Lowercase letters (a-z) ? (y/n)");
-        if system::get_user_choice_yn().eq("y") {
+        if get_user_choice_yn().eq("y") {
             wordlist_config.lowercase = true;
             is_option_chosen = true;
         }
 
         println!("Numbers (0-9) ? (y/n)");
-        if system::get_user_choice_yn().eq("y") {
+        if get_user_choice_yn().eq("y") {
             wordlist_config.numbers = true;
             is_option_chosen = true;
         }
 
         println!("Special characters ? (y/n)");
-        if system::get_user_choice_yn().eq("y") {
+        if get_user_choice_yn().eq("y") {
             wordlist_config.special_characters = true;
             is_option_chosen = true;
         }
 
+        println!("Custom characters ? (y/n)");
+        if get_user_choice_yn().eq("y") {
+            println!("Enter the characters (any printable Unicode characters) :");
+            wordlist_config.charset = get_user_choice().chars().filter(|c| !c.is_control()).collect();
+            is_option_chosen = is_option_chosen || !wordlist_config.charset.is_empty();
+        }
+
+        println!("Built-in charsets (hex, base64, keyboard rows...) ? (y/n)");
+        if get_user_choice_yn().eq("y") {
+            let builtin_charsets: String = get_builtin_charsets_choice();
+            wordlist_config.charset.push_str(&builtin_charsets);
+            is_option_chosen = is_option_chosen || !builtin_charsets.is_empty();
+        }
+
         if !is_option_chosen {
             println!("You must choose at least one option !");
         }
     }
 
     println!("Do you want to hash the passwords of the wordlist ? (y/n)");
-    if system::get_user_choice_yn().eq("y") {
+    if get_user_choice_yn().eq("y") {
         wordlist_config.hash = get_hash_choice();
     }
 
@@ -288,10 +371,15 @@ fn allocate_wordlist_config_gui() -> WordlistValues {
     println!("For every character you want to be fixed, enter the character itself.");
     println!("For every character you want to be variable, enter a ?.");
     println!("If you want to specify the character '?' in the mask as a fixed character, enter '\\?'");
+    println!("Do you want ?b to be any byte (0x00-0xFF) ? The non-printable words are written as $HEX[...]. (y/n)");
+    if get_user_choice_yn().eq("y") {
+        wordlist_config.byte_placeholder = true;
+        println!("For every byte you want to be variable, enter a ?b, enter '?\\b' for a variable character followed by a 'b'.");
+    }
 
     let mut is_valid_mask: bool = false;
     while !is_valid_mask {
-        wordlist_config.mask = system::get_user_choice();
+        wordlist_config.mask = get_user_choice();
         if wordlist_config.mask.is_empty() {
             println!("The mask cannot be empty !");
             continue;
@@ -301,7 +389,7 @@ fn allocate_wordlist_config_gui() -> WordlistValues {
         }
 
         println!("Do you want to validate the following mask : '{}' ? (y/n)", wordlist_config.mask);
-        if system::get_user_choice_yn().eq("y") {
+        if get_user_choice_yn().eq("y") {
             is_valid_mask = true;
         }
     }
@@ -324,7 +412,7 @@ fn main_benchmark() {
         }
         
         println!("\nDo you want to run a new benchmark ? (y/n)");
-        again = system::get_user_choice_yn();
+        again = get_user_choice_yn();
     }
     println!("\n");
 }
@@ -339,12 +427,12 @@ fn main_benchmark() {
 ///
 pub fn saving_procedure(target: &str) -> Result<(File, String), SystemError> {
     println!("Please enter the file name to backup the wordlist :");
-    let mut filename: String = system::get_user_choice();
+    let mut filename: String = get_user_choice();
     let mut result: Result<String, SystemError> = system::is_valid_path(&filename);
     while result.is_err() {
         println!("{}", result.unwrap_err());
         println!("Please enter a new file name:");
-        filename = system::get_user_choice();
+        filename = get_user_choice();
         result = system::is_valid_path(&filename);
     }
 
@@ -353,7 +441,7 @@ pub fn saving_procedure(target: &str) -> Result<(File, String), SystemError> {
         format!("./{}", filename)
     }, |home_path| {
         let parent_folder: String = format!("{}{}", home_path, target);
-        let parent_folder_created: String = match system::create_folder_if_not_exists(
+        let parent_folder_created: String = match create_folder_if_not_exists(
             &parent_folder,
         ) {
             Ok(()) => format!("{}{}", home_path, target),
@@ -412,9 +500,140 @@ fn get_hash_choice() -> String {
             println!("{} : {}", i + 1, if hash.is_empty() { "None" } else { hash });
         }
 
-        match system::get_user_choice().trim().parse::<usize>() {
+        match get_user_choice().trim().parse::<usize>() {
             Ok(n) if n >= 1 && n <= hash_choices.len() => return hash_choices[n - 1].to_string(),
             _ => println!("Error: please specify a valid option"),
         }
     }
 }
+
+/// This function is responsible for getting the built-in charsets chosen by the user, like the --builtin option of the CLI.
+///
+/// # Returns
+///
+/// The characters of the chosen built-in charsets, an empty String if there isn't any.
+///
+fn get_builtin_charsets_choice() -> String {
+    loop {
+        println!("Choose the built-in charsets you want to use, separated by spaces (e.g. 1 3) :");
+        for (i, (name, characters)) in dict::BUILTIN_CHARSETS.iter().enumerate() {
+            println!("{} : {} ({})", i + 1, name, String::from_utf8_lossy(characters));
+        }
+
+        let choices: Result<Vec<usize>, _> = get_user_choice().split_whitespace().map(str::parse::<usize>).collect();
+        match choices {
+            Ok(choices) if choices.iter().all(|n| *n >= 1 && *n <= dict::BUILTIN_CHARSETS.len()) => {
+                return choices
+                    .iter()
+                    .flat_map(|n| dict::BUILTIN_CHARSETS[n - 1].1.iter().map(|c| char::from(*c)))
+                    .collect();
+            }
+            _ => println!("Error: please specify valid options"),
+        }
+    }
+}
+
+/// This function is responsible for getting user String input y/n.
+///
+/// # Returns
+///
+/// The value entered by the user. If an error occurs, the function returns an empty String.
+///
+fn get_user_choice_yn() -> String {
+    let mut choice: String = get_user_choice();
+    while !choice.eq("y") && !choice.eq("n") {
+        println!("Please enter a valid answer (y/n)");
+        choice = get_user_choice();
+    }
+
+    choice
+}
+
+/// This function is responsible for geting user String input.
+///
+/// # Returns
+///
+/// The value entered by the user. If an error occurs, the function returns an empty String.
+///
+fn get_user_choice() -> String {
+    let mut buffer: String = String::new();
+    if stdin().read_line(&mut buffer).is_ok() {
+        buffer.trim().to_string()
+    } else {
+        String::new()
+    }
+}
+
+/// This function is responsible for getting user int input.
+/// The function will keep asking the user to enter a valid number greater than 0 until the user does so.
+/// This is a generic function, so it can be used for any basic integer type.
+///
+/// # Returns
+///
+/// The value entered by the user. If an error occurs, the function returns 0.
+///
+fn get_user_choice_int<T>() -> T
+where
+    T: FromStr + Display + PartialOrd + Copy + Default,
+    <T as FromStr>::Err: Display,
+{
+    let mut is_good_number: bool = false;
+    let mut number: T = T::default();
+
+    while !is_good_number {
+        let choice: String = get_user_choice();
+        if choice.is_empty() {
+            println!("Please enter a valid number greater than 0");
+            continue;
+        }
+        match choice.trim().parse::<T>() {
+            Ok(n) => {
+                if n > T::default() {
+                    is_good_number = true;
+                    number = n;
+                } else {
+                    println!("Please enter a valid number greater than 0");
+                }
+            }
+            Err(e) => println!("Please enter a valid number greater than 0, {}", e),
+        }
+    }
+
+    number
+}
+
+/// This function is responsible for creating the passwords or wordlists folder if it doesn't exist.
+///
+/// # Arguments
+///
+/// * `folder` - A string slice that holds the folder to create.
+///
+/// # Returns
+///
+/// Ok(()) if the folder has been created, SystemError otherwise.
+///
+fn create_folder_if_not_exists(folder: &str) -> Result<(), SystemError> {
+    let mut folder: String = String::from(folder);
+    if folder.pop().is_none() {
+        return Err(SystemError::InvalidPath(folder));
+    }
+    if !Path::new(&folder).exists() {
+        std::fs::create_dir_all(&folder)
+            .map_err(|e| SystemError::UnableToCreateFolder(folder, e.to_string()))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_folder_if_not_exists() {
+        let valid_folder: &str = "./test_folder/";
+
+        assert!(create_folder_if_not_exists(valid_folder).is_ok());
+        std::fs::remove_dir(valid_folder).unwrap();
+    }
+}
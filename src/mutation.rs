@@ -0,0 +1,470 @@
+// Internal crates.
+use crate::{
+    dict,
+    error::{ArgError, SystemError, WorgenXError},
+    wordlist::{self, PasswordSource, PasswordStream},
+};
+
+// External crates.
+use std::fs;
+
+/// This struct represents a line of the substitution table: a character and the strings it can be replaced with (e.g. `a` with `4` or `@`).
+/// The ASCII letters are matched in any case.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Substitution {
+    pub character: char,
+    pub replacements: Vec<Vec<u8>>,
+}
+
+/// This struct is the source of the mutation mode: each word is expanded into all its l33t substitutions and case permutations.
+/// The l33t substitutions are disabled if `substitutions` is empty, the case permutations toggle the case of each ASCII letter.
+/// If `max_changes` is set, the mutated passwords differ from their word on this number of characters at most.
+///
+/// The mutated passwords of a word are ordered like an odometer (the last character is the least significant one), the word itself comes first.
+///
+#[derive(Debug, Clone)]
+pub struct MutationConfig {
+    pub words: Vec<Vec<u8>>,
+    pub substitutions: Vec<Substitution>,
+    pub case_permutations: bool,
+    pub max_changes: Option<usize>,
+    pub hash: String,
+    pub raw_separator: Option<Vec<u8>>,
+}
+
+/// This struct is the stream of the mutated passwords of a part of a MutationConfig.
+/// The word is split in units (a character of the substitution table or a byte), each unit is a digit of the odometer whose values are its options.
+///
+#[derive(Debug)]
+pub struct MutationStream<'a> {
+    mutation_config: &'a MutationConfig,
+    words: std::slice::Iter<'a, Vec<u8>>,
+    options: Vec<Vec<Vec<u8>>>,
+    digits: Vec<usize>,
+    password: Vec<u8>,
+    pending_advance: bool,
+    remaining: u64,
+}
+
+impl MutationStream<'_> {
+    /// This function is responsible for moving the odometer to the next mutation of the word allowed by `max_changes`.
+    /// The mutations with too many changes are skipped without being enumerated.
+    ///
+    /// # Returns
+    ///
+    /// true if there is another mutation of the word, false otherwise.
+    ///
+    fn advance(&mut self) -> bool {
+        let max_changes: usize = self.mutation_config.max_changes.unwrap_or(usize::MAX);
+        for idx in (0..self.digits.len()).rev() {
+            self.digits[idx] += 1;
+            // The following digits are 0, so only the previous ones and this one are changes.
+            if self.digits[idx] < self.options[idx].len() && self.digits[..=idx].iter().filter(|digit| **digit > 0).count() <= max_changes {
+                return true;
+            }
+            self.digits[idx] = 0;
+        }
+
+        false
+    }
+
+    /// This function is responsible for starting the mutations of a word.
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - The word to mutate.
+    ///
+    fn start_word(&mut self, word: &[u8]) {
+        self.options = self.mutation_config.get_unit_options(word);
+        self.digits = vec![0; self.options.len()];
+    }
+
+    /// This function is responsible for moving the odometer directly to the mutation of the word at the given index, without enumerating the previous ones.
+    /// The index is unranked digit by digit: each value of a digit is followed by a block of mutations of the next units,
+    /// whose size is the number of mutations of these units with the changes left.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the mutation among the mutations of the word, it must be lower than their number.
+    ///
+    fn seek(&mut self, index: u64) {
+        let max_changes: usize = self.mutation_config.max_changes.unwrap_or(usize::MAX).min(self.options.len());
+        // nb_of_suffix_mutations[i][k] is the number of mutations of the units from i onwards with k changes at most.
+        let mut nb_of_suffix_mutations: Vec<Vec<u64>> = vec![vec![1; max_changes + 1]; self.options.len() + 1];
+        for idx in (0..self.options.len()).rev() {
+            let nb_of_changes: u64 = self.options[idx].len() as u64 - 1;
+            for changes in 1..=max_changes {
+                let new_mutations: u64 = nb_of_suffix_mutations[idx + 1][changes - 1].saturating_mul(nb_of_changes);
+                nb_of_suffix_mutations[idx][changes] = nb_of_suffix_mutations[idx + 1][changes].saturating_add(new_mutations);
+            }
+        }
+
+        let mut index: u64 = index;
+        let mut changes_left: usize = max_changes;
+        for idx in 0..self.options.len() {
+            let unchanged_block: u64 = nb_of_suffix_mutations[idx + 1][changes_left];
+            if index < unchanged_block {
+                self.digits[idx] = 0;
+                continue;
+            }
+            index -= unchanged_block;
+            let changed_block: u64 = nb_of_suffix_mutations[idx + 1][changes_left - 1].max(1);
+            self.digits[idx] = 1 + (index / changed_block) as usize;
+            index %= changed_block;
+            changes_left -= 1;
+        }
+    }
+}
+
+impl PasswordStream for MutationStream<'_> {
+    fn next_password(&mut self) -> Option<&[u8]> {
+        if self.remaining == 0 {
+            return None;
+        }
+        if self.pending_advance && !self.advance() {
+            let word: &[u8] = self.words.next()?;
+            self.start_word(word);
+        }
+
+        self.pending_advance = true;
+        self.password.clear();
+        for (options, digit) in self.options.iter().zip(&self.digits) {
+            self.password.extend_from_slice(&options[*digit]);
+        }
+        self.remaining -= 1;
+        Some(&self.password)
+    }
+}
+
+impl MutationConfig {
+    /// This function is responsible for splitting a word in units and listing the options of each unit.
+    /// The first option of a unit is the unit itself, then come its other case and its l33t substitutions, without duplicates.
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - The word to mutate.
+    ///
+    /// # Returns
+    ///
+    /// The options of each unit of the word, in order.
+    ///
+    fn get_unit_options(&self, word: &[u8]) -> Vec<Vec<Vec<u8>>> {
+        let mut utf8_buffer: [u8; 4] = [0; 4];
+        let mut units: Vec<Vec<Vec<u8>>> = Vec::new();
+        let mut idx: usize = 0;
+
+        while idx < word.len() {
+            let substitution: Option<&Substitution> = self.substitutions.iter().find(|substitution| {
+                let character: &[u8] = substitution.character.encode_utf8(&mut utf8_buffer).as_bytes();
+                word[idx..].get(..character.len()).is_some_and(|unit| unit.eq_ignore_ascii_case(character))
+            });
+            let unit: &[u8] = &word[idx..idx + substitution.map_or(1, |substitution| substitution.character.len_utf8())];
+            let mut options: Vec<Vec<u8>> = vec![unit.to_vec()];
+            if self.case_permutations && unit.len() == 1 && unit[0].is_ascii_alphabetic() {
+                options.push(vec![unit[0] ^ 0x20]);
+            }
+            for replacement in substitution.iter().flat_map(|substitution| &substitution.replacements) {
+                if !options.contains(replacement) {
+                    options.push(replacement.clone());
+                }
+            }
+
+            idx += unit.len();
+            units.push(options);
+        }
+
+        units
+    }
+
+    /// This function is responsible for counting the mutated passwords of a word, the word itself included.
+    /// The number of passwords with exactly k changes is the elementary symmetric polynomial of degree k of the number of changes of each unit.
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - The word to mutate.
+    ///
+    /// # Returns
+    ///
+    /// The number of mutated passwords of the word.
+    ///
+    pub fn get_nb_of_mutations(&self, word: &[u8]) -> u64 {
+        let units: Vec<Vec<Vec<u8>>> = self.get_unit_options(word);
+        let max_changes: usize = self.max_changes.unwrap_or(usize::MAX).min(units.len());
+        let mut nb_of_passwords_by_changes: Vec<u64> = vec![0; max_changes + 1];
+        nb_of_passwords_by_changes[0] = 1;
+
+        for (unit_idx, options) in units.iter().enumerate() {
+            let nb_of_changes: u64 = options.len() as u64 - 1;
+            if nb_of_changes == 0 {
+                continue;
+            }
+            for changes in (1..=max_changes.min(unit_idx + 1)).rev() {
+                let new_passwords: u64 = nb_of_passwords_by_changes[changes - 1].saturating_mul(nb_of_changes);
+                nb_of_passwords_by_changes[changes] = nb_of_passwords_by_changes[changes].saturating_add(new_passwords);
+            }
+        }
+
+        nb_of_passwords_by_changes.into_iter().fold(0u64, u64::saturating_add)
+    }
+
+    /// This function is responsible for computing the expansion factor of the mutation, i.e. the average number of passwords generated from a word.
+    ///
+    /// # Returns
+    ///
+    /// The number of mutated passwords divided by the number of words, 0 if there isn't any word.
+    ///
+    pub fn get_expansion_factor(&self) -> f64 {
+        if self.words.is_empty() {
+            return 0.0;
+        }
+        self.get_nb_of_passwords() as f64 / self.words.len() as f64
+    }
+}
+
+impl PasswordSource for MutationConfig {
+    type Stream<'a> = MutationStream<'a>;
+
+    fn get_nb_of_passwords(&self) -> u64 {
+        self.words.iter().map(|word| self.get_nb_of_mutations(word)).fold(0u64, u64::saturating_add)
+    }
+
+    fn get_stream(&self, offset: u64, nb_of_passwords: u64) -> MutationStream<'_> {
+        let mut stream: MutationStream<'_> = MutationStream {
+            mutation_config: self,
+            words: self.words.iter(),
+            options: Vec::new(),
+            digits: Vec::new(),
+            password: Vec::new(),
+            pending_advance: false,
+            remaining: 0,
+        };
+
+        // Find the word of the first password, then seek its first mutation.
+        // The stream ends with the last word, so the total number of passwords isn't needed.
+        let mut nb_of_previous_passwords: u64 = 0;
+        while let Some(word) = stream.words.next() {
+            let nb_of_mutations: u64 = self.get_nb_of_mutations(word);
+            if offset < nb_of_previous_passwords + nb_of_mutations {
+                stream.start_word(word);
+                stream.seek(offset - nb_of_previous_passwords);
+                stream.remaining = nb_of_passwords;
+                break;
+            }
+            nb_of_previous_passwords += nb_of_mutations;
+        }
+
+        stream
+    }
+
+    fn get_password_size(&self) -> f64 {
+        // The substitutions are mostly made of a single character, so each mutated password is counted with the size of its word.
+        let (total_size, nb_of_passwords): (f64, f64) = self.words.iter().fold((0.0, 0.0), |(total_size, nb_of_passwords), word| {
+            let nb_of_mutations: f64 = self.get_nb_of_mutations(word) as f64;
+            let word_size: f64 = wordlist::get_written_size(word, self.raw_separator.as_deref()) as f64;
+            (total_size + nb_of_mutations * word_size, nb_of_passwords + nb_of_mutations)
+        });
+
+        if nb_of_passwords == 0.0 {
+            0.0
+        } else {
+            total_size / nb_of_passwords
+        }
+    }
+
+    fn get_hash(&self) -> &str {
+        &self.hash
+    }
+
+    fn get_raw_separator(&self) -> Option<&[u8]> {
+        self.raw_separator.as_deref()
+    }
+}
+
+/// This function is responsible for returning the default l33t substitutions (see `dict::L33T_SUBSTITUTIONS`).
+///
+/// # Returns
+///
+/// The vector of the default substitutions.
+///
+pub fn get_default_substitutions() -> Vec<Substitution> {
+    dict::L33T_SUBSTITUTIONS
+        .iter()
+        .map(|(character, replacements)| Substitution {
+            character: *character,
+            replacements: replacements.iter().map(|replacement| replacement.as_bytes().to_vec()).collect(),
+        })
+        .collect()
+}
+
+/// This function is responsible for parsing a substitution table.
+/// Each line is a character followed by its substitutions, separated by spaces (e.g. `a 4 @` or `h |-|`).
+/// The empty lines and the comments (lines starting with `#`) are skipped.
+///
+/// # Arguments
+///
+/// * `content` - The content of the substitution table.
+///
+/// # Returns
+///
+/// The vector of the substitutions, WorgenXError if a line is invalid.
+///
+pub fn parse_substitution_table(content: &str) -> Result<Vec<Substitution>, WorgenXError> {
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let mut fields: std::str::SplitWhitespace = line.split_whitespace();
+            let mut characters: std::str::Chars = fields.next().unwrap_or_default().chars();
+            let replacements: Vec<Vec<u8>> = fields.map(|replacement| replacement.as_bytes().to_vec()).collect();
+            match (characters.next(), characters.next()) {
+                (Some(character), None) if !replacements.is_empty() => Ok(Substitution { character, replacements }),
+                _ => Err(WorgenXError::ArgError(ArgError::InvalidSubstitution(line.to_string()))),
+            }
+        })
+        .collect()
+}
+
+/// This function is responsible for loading a substitution table from a file.
+///
+/// # Arguments
+///
+/// * `path` - The path of the substitution table.
+///
+/// # Returns
+///
+/// The vector of the substitutions, WorgenXError if the file can't be read or if a line is invalid.
+///
+pub fn load_substitution_table(path: &str) -> Result<Vec<Substitution>, WorgenXError> {
+    let content: String = fs::read_to_string(path).map_err(|e| WorgenXError::SystemError(SystemError::UnableToReadFile(path.to_string(), e.to_string())))?;
+    parse_substitution_table(&content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collect(mutation_config: &MutationConfig, offset: u64, nb_of_passwords: u64) -> Vec<String> {
+        let mut stream: MutationStream<'_> = mutation_config.get_stream(offset, nb_of_passwords);
+        let mut passwords: Vec<String> = Vec::new();
+        while let Some(password) = stream.next_password() {
+            passwords.push(String::from_utf8(password.to_vec()).unwrap());
+        }
+        passwords
+    }
+
+    #[test]
+    fn test_l33t_substitutions() {
+        let mut mutation_config: MutationConfig = MutationConfig {
+            words: vec![b"pass".to_vec(), b"42".to_vec()],
+            substitutions: get_default_substitutions(),
+            case_permutations: false,
+            max_changes: None,
+            hash: String::new(),
+            raw_separator: None,
+        };
+        assert_eq!(mutation_config.get_nb_of_mutations(b"pass"), 3 * 3 * 3);
+        assert_eq!(mutation_config.get_nb_of_passwords(), 28);
+        assert_eq!(mutation_config.get_expansion_factor(), 14.0);
+
+        let passwords: Vec<String> = collect(&mutation_config, 0, 28);
+        assert_eq!(passwords[..4], ["pass", "pas5", "pas$", "pa5s"]);
+        assert_eq!(passwords[26..], ["p@$$", "42"]);
+        assert_eq!(collect(&mutation_config, 25, 10), ["p@$5", "p@$$", "42"]);
+        assert!(collect(&mutation_config, 28, 10).is_empty());
+
+        // Limited depth: at most one character is changed.
+        mutation_config.max_changes = Some(1);
+        assert_eq!(mutation_config.get_nb_of_mutations(b"pass"), 1 + 2 + 2 + 2);
+        assert_eq!(collect(&mutation_config, 0, 8), ["pass", "pas5", "pas$", "pa5s", "pa$s", "p4ss", "p@ss", "42"]);
+        assert_eq!(collect(&mutation_config, 3, 2), ["pa5s", "pa$s"]);
+    }
+
+    #[test]
+    fn test_case_permutations() {
+        let mut mutation_config: MutationConfig = MutationConfig {
+            words: vec![b"aB1".to_vec()],
+            substitutions: Vec::new(),
+            case_permutations: true,
+            max_changes: None,
+            hash: String::new(),
+            raw_separator: None,
+        };
+        assert_eq!(collect(&mutation_config, 0, 10), ["aB1", "ab1", "AB1", "Ab1"]);
+
+        // The case permutations and the l33t substitutions are combined, without duplicates.
+        mutation_config.substitutions = parse_substitution_table("a 4 A\n# comment\n\nb |3").unwrap();
+        assert_eq!(mutation_config.get_nb_of_passwords(), 3 * 3);
+        assert_eq!(collect(&mutation_config, 0, 10), ["aB1", "ab1", "a|31", "AB1", "Ab1", "A|31", "4B1", "4b1", "4|31"]);
+
+        mutation_config.max_changes = Some(0);
+        assert_eq!(collect(&mutation_config, 0, 10), ["aB1"]);
+    }
+
+    #[test]
+    fn test_seek() {
+        let mut mutation_config: MutationConfig = MutationConfig {
+            words: vec![b"Passw0rd".to_vec(), b"ab".to_vec(), b"1".to_vec()],
+            substitutions: get_default_substitutions(),
+            case_permutations: true,
+            max_changes: None,
+            hash: String::new(),
+            raw_separator: None,
+        };
+        for max_changes in [None, Some(0), Some(1), Some(3)] {
+            mutation_config.max_changes = max_changes;
+            let nb_of_passwords: u64 = mutation_config.get_nb_of_passwords();
+            let passwords: Vec<String> = collect(&mutation_config, 0, nb_of_passwords);
+            assert_eq!(passwords.len() as u64, nb_of_passwords);
+            for offset in (0..nb_of_passwords).step_by(7) {
+                assert_eq!(collect(&mutation_config, offset, 3), passwords[offset as usize..(offset as usize + 3).min(passwords.len())]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_substitution_table() {
+        let substitutions: Vec<Substitution> = parse_substitution_table("é e 3\nh |-| #").unwrap();
+        assert_eq!(substitutions[0], Substitution { character: 'é', replacements: vec![b"e".to_vec(), b"3".to_vec()] });
+        assert_eq!(substitutions[1].replacements, vec![b"|-|".to_vec(), b"#".to_vec()]);
+
+        assert!(parse_substitution_table("ab 4").is_err());
+        assert!(parse_substitution_table("a").is_err());
+        assert!(load_substitution_table("test_missing_table.txt").is_err());
+
+        let mutation_config: MutationConfig = MutationConfig {
+            words: vec!["café".as_bytes().to_vec()],
+            substitutions,
+            case_permutations: false,
+            max_changes: None,
+            hash: String::new(),
+            raw_separator: None,
+        };
+        assert_eq!(collect(&mutation_config, 0, 10), ["café", "cafe", "caf3"]);
+    }
+
+    #[test]
+    fn test_generate_wordlist_with_mutations() {
+        let mutation_config: MutationConfig = MutationConfig {
+            words: (0..20).map(|idx| format!("test{}", idx).into_bytes()).collect(),
+            substitutions: get_default_substitutions(),
+            case_permutations: true,
+            max_changes: Some(2),
+            hash: String::from("sha256"),
+            raw_separator: None,
+        };
+        let nb_of_passwords: u64 = mutation_config.get_nb_of_passwords();
+        wordlist::wordlist_generation_scheduler(&mutation_config, nb_of_passwords, 3, "test26.txt", true).unwrap();
+
+        let mut lines: Vec<String> = std::fs::read_to_string("test26.txt").unwrap().lines().map(String::from).collect();
+        std::fs::remove_file("test26.txt").unwrap();
+        let mut expected_lines: Vec<String> = collect(&mutation_config, 0, nb_of_passwords)
+            .iter()
+            .map(|password| crate::system::manage_hash(password.as_bytes(), "sha256").unwrap())
+            .collect();
+        lines.sort();
+        expected_lines.sort();
+        assert_eq!(lines.len() as u64, nb_of_passwords);
+        assert_eq!(lines, expected_lines);
+    }
+}
@@ -0,0 +1,290 @@
+// Internal crates.
+use crate::{
+    dict,
+    error::{ArgError, SystemError, WorgenXError},
+};
+
+// External crates.
+use rand::Rng;
+use std::{collections::HashSet, fs};
+
+/// This struct built from the user's choices will be used to generate the passphrases.
+/// A passphrase is made of `number_of_words` words drawn uniformly from a list of words, joined with the separator.
+/// If `append_number` or `append_special_character` is enabled, a random number or special character is added at the end of the passphrase.
+///
+#[derive(Debug)]
+pub struct PassphraseConfig {
+    pub number_of_words: u32,
+    pub separator: String,
+    pub capitalize: bool,
+    pub append_number: bool,
+    pub append_special_character: bool,
+    pub number_of_passphrases: u64,
+}
+
+/// This function is responsible for loading the list of words used to generate the passphrases from a file.
+///
+/// # Arguments
+///
+/// * `path` - The path of the file containing the words.
+///
+/// # Returns
+///
+/// The vector of the words, WorgenXError if the file can't be read or doesn't contain at least two different words.
+///
+pub fn load_words(path: &str) -> Result<Vec<String>, WorgenXError> {
+    let content: String = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) => {
+            return Err(WorgenXError::SystemError(SystemError::UnableToReadFile(
+                path.to_string(),
+                e.to_string(),
+            )))
+        }
+    };
+
+    let words: Vec<String> = parse_words(&content);
+    if words.len() < 2 {
+        return Err(WorgenXError::ArgError(ArgError::NotEnoughWords(path.to_string())));
+    }
+
+    Ok(words)
+}
+
+/// This function is responsible for extracting the words from the content of a list of words.
+/// Each line contains a single word, optionally preceded by its dice roll like in the diceware lists (e.g. `11111\tabacus`).
+/// The empty lines are skipped and the duplicates are removed, so each word has the same probability to be drawn.
+///
+/// # Arguments
+///
+/// * `content` - The content of the list of words.
+///
+/// # Returns
+///
+/// The vector of the unique words, in the order of the list.
+///
+pub fn parse_words(content: &str) -> Vec<String> {
+    let mut seen_words: HashSet<&str> = HashSet::new();
+    content
+        .lines()
+        .filter_map(|line| line.split_whitespace().last())
+        .filter(|word| seen_words.insert(word))
+        .map(str::to_string)
+        .collect()
+}
+
+/// This function is responsible for returning the default list of words of the passphrases, the embedded EFF large wordlist.
+///
+/// # Returns
+///
+/// The vector of the words of the EFF large wordlist.
+///
+pub fn get_default_words() -> Vec<String> {
+    parse_words(dict::EFF_LARGE_WORDLIST)
+}
+
+/// This function is responsible for removing the words which are the same once capitalized (e.g. `Word` and `word`).
+/// With the capitalize option, they would produce the same word of the passphrase and inflate the entropy.
+///
+/// # Arguments
+///
+/// * `words` - The list of words to draw from.
+///
+/// # Returns
+///
+/// The vector of the words that are unique once capitalized, in the order of the list.
+///
+pub fn remove_capitalized_duplicates(words: Vec<String>) -> Vec<String> {
+    let mut seen_words: HashSet<String> = HashSet::new();
+    words.into_iter().filter(|word| seen_words.insert(capitalize_word(word))).collect()
+}
+
+/// This function is responsible for generating an array of random passphrases.
+///
+/// # Arguments
+///
+/// * `passphrase_config` - The passphrase config structure.
+/// * `words` - The list of words to draw from.
+/// * `rng` - The random number generator, see `rng::WorgenXRng`.
+///
+/// # Returns
+///
+/// A vector of String containing the random passphrases, empty if the list of words is empty.
+///
+pub fn generate_random_passphrases<R: Rng>(passphrase_config: &PassphraseConfig, words: &[String], rng: &mut R) -> Vec<String> {
+    let mut passphrases: Vec<String> = Vec::new();
+    if words.is_empty() {
+        return passphrases;
+    }
+
+    for _ in 0..passphrase_config.number_of_passphrases {
+        let mut passphrase_words: Vec<String> = Vec::with_capacity(passphrase_config.number_of_words as usize);
+        for _ in 0..passphrase_config.number_of_words {
+            let word: &str = &words[rng.random_range(0..words.len())];
+            passphrase_words.push(if passphrase_config.capitalize {
+                capitalize_word(word)
+            } else {
+                word.to_string()
+            });
+        }
+
+        let mut passphrase: String = passphrase_words.join(&passphrase_config.separator);
+        if passphrase_config.append_number {
+            passphrase.push(dict::NUMBERS[rng.random_range(0..dict::NUMBERS.len())] as char);
+        }
+        if passphrase_config.append_special_character {
+            passphrase.push(dict::SPECIAL_CHARACTERS[rng.random_range(0..dict::SPECIAL_CHARACTERS.len())] as char);
+        }
+        passphrases.push(passphrase);
+    }
+
+    passphrases
+}
+
+/// This function is responsible for computing the entropy of the passphrases generated from a passphrase config.
+/// The capitalisation and the separator are known by the attacker, so they don't add any entropy.
+///
+/// # Arguments
+///
+/// * `passphrase_config` - The passphrase config structure.
+/// * `nb_of_words` - The number of words in the list.
+///
+/// # Returns
+///
+/// The entropy in bits.
+///
+pub fn get_entropy(passphrase_config: &PassphraseConfig, nb_of_words: usize) -> f64 {
+    let mut entropy: f64 = f64::from(passphrase_config.number_of_words) * (nb_of_words as f64).log2();
+    if passphrase_config.append_number {
+        entropy += (dict::NUMBERS.len() as f64).log2();
+    }
+    if passphrase_config.append_special_character {
+        entropy += (dict::SPECIAL_CHARACTERS.len() as f64).log2();
+    }
+    entropy
+}
+
+/// This function is responsible for converting the first character of a word to uppercase.
+///
+/// # Arguments
+///
+/// * `word` - The word to capitalize.
+///
+/// # Returns
+///
+/// The capitalized word.
+///
+fn capitalize_word(word: &str) -> String {
+    let mut chars: std::str::Chars = word.chars();
+    match chars.next() {
+        Some(first_char) => first_char.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::WorgenXRng;
+
+    #[test]
+    fn test_parse_words() {
+        let content: &str = "11111\tabacus\n11112\tabdomen\n\nabacus\nzebra  \n";
+        let words: Vec<String> = parse_words(content);
+
+        assert_eq!(words, vec!["abacus", "abdomen", "zebra"]);
+    }
+
+    #[test]
+    fn test_load_words() {
+        assert!(load_words("tests_missing_words.txt").is_err());
+
+        fs::write("test18.txt", "alpha\nalpha\n").unwrap();
+        assert!(load_words("test18.txt").is_err());
+
+        fs::write("test18.txt", "alpha\nbeta\n").unwrap();
+        let words: Vec<String> = load_words("test18.txt").unwrap();
+        fs::remove_file("test18.txt").unwrap();
+        assert_eq!(words, vec!["alpha", "beta"]);
+    }
+
+    #[test]
+    fn test_get_default_words() {
+        let words: Vec<String> = get_default_words();
+        assert!(words.len() > 7700);
+        assert_eq!(words.first().map(String::as_str), Some("abacus"));
+        assert_eq!(words.last().map(String::as_str), Some("zoom"));
+        assert_eq!(remove_capitalized_duplicates(words.clone()).len(), words.len());
+    }
+
+    #[test]
+    fn test_remove_capitalized_duplicates() {
+        let words: Vec<String> = ["word", "Word", "WORD", "apple", "Éclair", "éclair"].iter().map(|word| word.to_string()).collect();
+        assert_eq!(remove_capitalized_duplicates(words), vec!["word", "WORD", "apple", "Éclair"]);
+    }
+
+    #[test]
+    fn test_generate_random_passphrases() {
+        let words: Vec<String> = vec!["alpha".to_string(), "beta".to_string(), "gamma".to_string()];
+        let passphrase_config: PassphraseConfig = PassphraseConfig {
+            number_of_words: 4,
+            separator: String::from("-"),
+            capitalize: true,
+            append_number: true,
+            append_special_character: true,
+            number_of_passphrases: 20,
+        };
+        let passphrases: Vec<String> = generate_random_passphrases(&passphrase_config, &words, &mut WorgenXRng::new(None));
+
+        assert_eq!(passphrases.len(), 20);
+        for passphrase in passphrases {
+            let (body, suffix) = passphrase.split_at(passphrase.len() - 2);
+            let suffix: &[u8] = suffix.as_bytes();
+            assert!(dict::NUMBERS.contains(&suffix[0]));
+            assert!(dict::SPECIAL_CHARACTERS.contains(&suffix[1]));
+
+            let passphrase_words: Vec<&str> = body.split('-').collect();
+            assert_eq!(passphrase_words.len(), 4);
+            assert!(passphrase_words.iter().all(|word| ["Alpha", "Beta", "Gamma"].contains(word)));
+        }
+    }
+
+    #[test]
+    fn test_generate_random_passphrases_with_seed() {
+        let words: Vec<String> = ["alpha", "beta", "gamma", "delta"].iter().map(|word| word.to_string()).collect();
+        let passphrase_config: PassphraseConfig = PassphraseConfig {
+            number_of_words: 4,
+            separator: String::from("-"),
+            capitalize: false,
+            append_number: true,
+            append_special_character: false,
+            number_of_passphrases: 1,
+        };
+        let passphrases: Vec<String> = generate_random_passphrases(&passphrase_config, &words, &mut WorgenXRng::new(Some(42)));
+
+        assert_eq!(passphrases, vec!["delta-gamma-gamma-beta0"]);
+    }
+
+    #[test]
+    fn test_get_entropy() {
+        let mut passphrase_config: PassphraseConfig = PassphraseConfig {
+            number_of_words: 6,
+            separator: String::from(" "),
+            capitalize: false,
+            append_number: false,
+            append_special_character: false,
+            number_of_passphrases: 1,
+        };
+        assert!((get_entropy(&passphrase_config, 7776) - 6.0 * 7776f64.log2()).abs() < 1e-9);
+
+        passphrase_config.append_number = true;
+        assert!((get_entropy(&passphrase_config, 7776) - (6.0 * 7776f64.log2() + 10f64.log2())).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_capitalize_word() {
+        assert_eq!(capitalize_word("horse"), "Horse");
+        assert_eq!(capitalize_word("éclair"), "Éclair");
+        assert_eq!(capitalize_word(""), "");
+    }
+}
@@ -1,10 +1,81 @@
 // Internal crates.
-use crate::dict;
+use crate::{
+    dict,
+    error::{ArgError, SystemError, WorgenXError},
+    progress::{ProgressGoal, RunContext},
+    rng::WorgenXRng,
+};
 
 // External crates.
-use rand::{rngs::ThreadRng, seq::SliceRandom, Rng};
+use rand::{seq::SliceRandom, Rng};
+use std::{
+    collections::{HashMap, HashSet},
+    hash::{BuildHasher, RandomState},
+    ops::RangeInclusive,
+    sync::Mutex,
+    thread::{self, ScopedJoinHandle},
+};
+
+/// This constant is used to set the number of passwords drawn uniformly from the whole pool of characters before using the exact sampling of the character classes.
+/// Both methods give a uniform distribution over the valid passwords, the second one is only slower to set up.
+///
+const REJECTION_ATTEMPTS: u32 = 32;
+
+/// This constant is used to set the number of passwords generated by a thread before handing them to the consumer.
+///
+const PASSWORD_BATCH_SIZE: u64 = 10_000;
+
+/// This constant is used to set the maximum size of a keyspace enumerated to draw unique passwords from a random permutation.
+///
+const PERMUTATION_MAX_KEYSPACE: u64 = 1 << 24;
+
+/// This constant is used to draw unique passwords from a random permutation of the keyspace
+/// when the number of passwords is at least 1/PERMUTATION_MIN_RATIO of the keyspace, the duplicates are rejected otherwise.
+///
+const PERMUTATION_MIN_RATIO: u64 = 4;
+
+/// This constant is used to set the maximum number of consecutive passwords rejected by the policy rules before giving up.
+/// It stops the generation if the policy rules can't be met in practice, instead of drawing passwords forever.
+///
+const POLICY_MAX_ATTEMPTS: u32 = 100_000;
+
+/// This constant is used to set the maximum size of a keyspace enumerated to count the passwords meeting the policy rules.
+/// The share of the passwords meeting the rules is estimated from POLICY_SAMPLE_SIZE passwords drawn with a fixed seed above this size.
+///
+const POLICY_MAX_KEYSPACE: u64 = 1 << 16;
+const POLICY_SAMPLE_SIZE: u32 = 10_000;
+
+/// This constant is used to set the maximum number of consecutive duplicates drawn before giving up on the unique passwords.
+/// It stops the generation if fewer unique passwords than requested can be drawn, e.g. with a length range and policy rules.
+///
+const UNIQUE_MAX_ATTEMPTS: u32 = 100_000;
+
+/// This constant is used to set the length above which the entropy of passwords requiring at most one character of each class
+/// is computed with the inclusion-exclusion principle instead of the table of the number of valid passwords.
+///
+const INCLUSION_EXCLUSION_MIN_LENGTH: u32 = 64;
+
+/// This constant defines the strength labels of the passwords with the minimum entropy (in bits) to get them.
+///
+const STRENGTH_LABELS: [(f64, &str); 5] = [
+    (128.0, "very strong"),
+    (60.0, "strong"),
+    (36.0, "reasonable"),
+    (28.0, "weak"),
+    (0.0, "very weak"),
+];
 
 /// This struct built from the user's choices will be used to generate the random password.
+/// If `class_coverage` is enabled, each password contains at least one character of each selected type of characters.
+/// If `max_length` is greater than `length`, each password has a uniformly random length between `length` and `max_length` (0 for a fixed length).
+/// The `min_*` fields define the minimum number of characters of a type in each password (0 if there isn't any minimum).
+/// The `charset` field adds an explicit alphabet (any printable Unicode characters, e.g. accented letters, Cyrillic or emoji) to the selected types of characters.
+/// The characters of `exclude`, and the ambiguous characters if `no_ambiguous` is enabled, are removed from the pool.
+/// If `pronounceable` is enabled, the passwords alternate consonants and vowels and the types of characters are ignored.
+/// If `template` is not empty, the passwords are generated from the template (see `parse_template()`) and the length and the types of characters are ignored.
+/// If `unique` is enabled, all the generated passwords are different.
+/// The policy rules reject the passwords with more than `max_repeated` identical consecutive characters (0 if there isn't any limit)
+/// or containing one of the `forbidden_sequences` (case insensitive). These passwords are drawn again and aren't counted in the entropy.
 ///
 #[derive(Debug)]
 pub struct PasswordConfig {
@@ -13,130 +84,2034 @@ pub struct PasswordConfig {
     pub uppercase: bool,
     pub lowercase: bool,
     pub length: u32,
+    pub max_length: u32,
     pub number_of_passwords: u64,
+    pub class_coverage: bool,
+    pub min_numbers: u32,
+    pub min_special_characters: u32,
+    pub min_uppercase: u32,
+    pub min_lowercase: u32,
+    pub charset: String,
+    pub exclude: String,
+    pub no_ambiguous: bool,
+    pub pronounceable: bool,
+    pub template: String,
+    pub unique: bool,
+    pub max_repeated: u32,
+    pub forbidden_sequences: Vec<String>,
+}
+
+impl Default for PasswordConfig {
+    fn default() -> Self {
+        PasswordConfig {
+            numbers: false,
+            special_characters: false,
+            uppercase: false,
+            lowercase: false,
+            length: 0,
+            max_length: 0,
+            number_of_passwords: 0,
+            class_coverage: true,
+            min_numbers: 0,
+            min_special_characters: 0,
+            min_uppercase: 0,
+            min_lowercase: 0,
+            charset: String::new(),
+            exclude: String::new(),
+            no_ambiguous: false,
+            pronounceable: false,
+            template: String::new(),
+            unique: false,
+            max_repeated: 0,
+            forbidden_sequences: Vec::new(),
+        }
+    }
+}
+
+/// This enum represents a character of a password template.
+///
+/// * `Fixed` - A character copied as is in the passwords.
+/// * `Random` - A character drawn uniformly from a set of characters.
+///
+#[derive(Debug, Clone, PartialEq)]
+enum TemplateElement {
+    Fixed(char),
+    Random(&'static [u8]),
+}
+
+/// This enum holds the data prepared once to generate the passwords of a config.
+///
+/// * `Template` - The parsed template.
+/// * `Pronounceable` - The pronounceable passwords.
+/// * `Pool` - The pool of characters, without any minimum.
+/// * `Classes` - The sampler meeting the minimums of the character classes and the pool of characters.
+///
+#[derive(Debug)]
+enum PasswordModel {
+    Template(Vec<TemplateElement>),
+    Pronounceable,
+    Pool(Vec<char>),
+    Classes(Box<ClassSampler>, Vec<char>),
+}
+
+/// This struct is responsible for generating the passwords of a config one by one.
+/// The config is checked and prepared once, so it can be used to stream a large number of passwords.
+/// The forbidden sequences are stored in lowercase.
+///
+#[derive(Debug)]
+pub struct PasswordGenerator {
+    model: PasswordModel,
+    lengths: RangeInclusive<u32>,
+    max_repeated: u32,
+    forbidden_sequences: Vec<String>,
+    nb_of_valid_passwords: Option<u64>,
+}
+
+/// This struct is responsible for rejecting the passwords already generated, shared between the threads.
+/// Only a 64-bit hash of each password is kept to save memory, a collision of hashes only causes another draw.
+/// `nb_of_passwords` is the number of unique passwords requested from all the threads, reported if they can't be drawn.
+///
+#[derive(Debug, Default)]
+struct UniqueFilter {
+    hasher: RandomState,
+    hashes: Mutex<HashSet<u64>>,
+    nb_of_passwords: u64,
+}
+
+/// This struct holds a random permutation of the keyspace, used to draw unique passwords from a small keyspace without any rejection.
+/// Each password of the keyspace is identified by its index, like a number whose digits are the characters of each position.
+///
+#[derive(Debug)]
+struct KeyspacePermutation {
+    positions: Vec<Vec<char>>,
+    indexes: Vec<u32>,
+}
+
+/// This struct represents a type of characters of the password and the minimum number of characters of this type.
+///
+#[derive(Debug, Clone)]
+struct CharacterClass {
+    name: &'static str,
+    characters: Vec<char>,
+    min: u32,
+}
+
+/// This struct is responsible for generating passwords meeting the minimums of the character classes, without any bias.
+/// The passwords are first drawn uniformly from the whole pool and rejected if a minimum is not met.
+/// If it fails too many times (strict minimums), the number of characters of each class is drawn from the exact number of valid passwords,
+/// computed once in a table, then the positions and the characters are drawn uniformly.
+///
+#[derive(Debug)]
+struct ClassSampler {
+    classes: Vec<CharacterClass>,
+    class_indexes: HashMap<char, usize>,
+    length: usize,
+    log2_factorials: Vec<f64>,
+    table: Option<Vec<Vec<f64>>>,
 }
 
 /// This function is responsible for creating the content of the password.
-/// It returns a vector of u8 containing the characters that will be used to generate the password.
+/// It returns a vector of char containing the characters that will be used to generate the password.
 ///
 /// # Arguments
 ///
 /// * `password_config` - The struct containing the user's choices.
+/// * `rng` - The random number generator.
 ///
 /// # Returns
 ///
-/// The vector of u8 containing the characters that will be used to generate the password.
+/// The vector of char containing the characters that will be used to generate the password, without any duplicate.
 /// All the characters are shuffled in a random order.
 ///
-fn create_passwd_content(password_config: &PasswordConfig) -> Vec<u8> {
-    let mut password_content: Vec<u8> = Vec::new();
+fn create_passwd_content<R: Rng>(password_config: &PasswordConfig, rng: &mut R) -> Vec<char> {
+    let mut password_content: Vec<char> = Vec::new();
+    for class in get_character_classes(password_config) {
+        password_content.extend(shuffle_dict(&class.characters, rng));
+    }
+
+    password_content.shuffle(rng);
+    password_content
+}
+
+/// This function is responsible for shuffling the characters of a character class.
+///
+/// # Arguments
+///
+/// * `dict` - The characters to shuffle.
+/// * `rng` - The random number generator.
+///
+/// # Returns
+///
+/// The shuffled vector of char sent in parameter.
+///
+fn shuffle_dict<R: Rng>(dict: &[char], rng: &mut R) -> Vec<char> {
+    let mut shuffled_dict: Vec<char> = dict.to_vec();
+    shuffled_dict.shuffle(rng);
+    shuffled_dict
+}
+
+/// This function is responsible for building the character classes from the user's choices.
+/// The order of the classes is the same as in the `create_passwd_content()` function, the custom charset comes last.
+/// The excluded characters are removed and each character belongs to a single class, so the pool doesn't contain any duplicate.
+/// The classes without any remaining character are dropped.
+///
+/// # Arguments
+///
+/// * `password_config` - The struct containing the user's choices.
+///
+/// # Returns
+///
+/// The vector of the selected character classes with their minimum number of characters.
+///
+fn get_character_classes(password_config: &PasswordConfig) -> Vec<CharacterClass> {
+    let coverage: u32 = u32::from(password_config.class_coverage);
+    let mut used_chars: HashSet<char> = password_config.exclude.chars().collect();
+    if password_config.no_ambiguous {
+        used_chars.extend(dict::AMBIGUOUS_CHARACTERS.iter().map(|c| char::from(*c)));
+    }
+
+    let to_chars = |characters: &[u8]| -> Vec<char> { characters.iter().map(|c| char::from(*c)).collect() };
+    let mut classes: Vec<CharacterClass> = Vec::new();
+    for (selected, characters, min, name) in [
+        (password_config.uppercase, to_chars(dict::UPPERCASE), password_config.min_uppercase, "uppercase"),
+        (password_config.lowercase, to_chars(dict::LOWERCASE), password_config.min_lowercase, "lowercase"),
+        (password_config.numbers, to_chars(dict::NUMBERS), password_config.min_numbers, "numbers"),
+        (password_config.special_characters, to_chars(dict::SPECIAL_CHARACTERS), password_config.min_special_characters, "special characters"),
+        (!password_config.charset.is_empty(), password_config.charset.chars().collect(), 0, "custom characters"),
+    ] {
+        if !selected {
+            continue;
+        }
+        let mut class_characters: Vec<char> = Vec::new();
+        for c in characters {
+            if used_chars.insert(c) {
+                class_characters.push(c);
+            }
+        }
+        if !class_characters.is_empty() {
+            classes.push(CharacterClass {
+                name,
+                characters: class_characters,
+                min: min.max(coverage),
+            });
+        }
+    }
+
+    classes
+}
+
+/// This function is responsible for checking that the requirements of the password config can be met.
+///
+/// # Arguments
+///
+/// * `password_config` - The password config structure.
+///
+/// # Returns
+///
+/// Ok(()) if passwords can be generated from the config, WorgenXError otherwise.
+///
+pub fn check_password_config(password_config: &PasswordConfig) -> Result<(), WorgenXError> {
+    check_character_requirements(password_config)?;
 
-    if password_config.uppercase {
-        password_content.extend(shuffle_dict(dict::UPPERCASE));
+    if password_config.unique {
+        let (min_length, max_length): (u32, u32) = get_password_length_range(password_config);
+        let keyspace: f64 = get_log2_nb_of_passwords(password_config, min_length..=max_length).exp2().round();
+        if password_config.number_of_passwords as f64 > keyspace {
+            return Err(WorgenXError::ArgError(ArgError::NotEnoughUniquePasswords(
+                password_config.number_of_passwords,
+                format!("{:.0}", keyspace),
+            )));
+        }
     }
 
-    if password_config.lowercase {
-        password_content.extend(shuffle_dict(dict::LOWERCASE));
+    Ok(())
+}
+
+/// This function is responsible for checking the policy rules of the config (maximum of repeated characters and forbidden sequences).
+/// The rules only apply to the passwords drawn from the types of characters, and a single character forbidden sequence must be excluded from the alphabet instead.
+///
+/// # Arguments
+///
+/// * `password_config` - The password config structure.
+///
+/// # Returns
+///
+/// Ok(()) if the rules are valid, WorgenXError otherwise.
+///
+fn check_policy_rules(password_config: &PasswordConfig) -> Result<(), WorgenXError> {
+    if password_config.max_repeated == 0 && password_config.forbidden_sequences.is_empty() {
+        return Ok(());
     }
 
-    if password_config.numbers {
-        password_content.extend(shuffle_dict(dict::NUMBERS));
+    if !password_config.template.is_empty() || password_config.pronounceable {
+        return Err(WorgenXError::ArgError(ArgError::InvalidPolicy(
+            "max_repeated and forbidden_sequences can't be used with a template or pronounceable passwords".to_string(),
+        )));
+    }
+    if let Some(sequence) = password_config.forbidden_sequences.iter().find(|sequence| sequence.chars().count() < 2) {
+        return Err(WorgenXError::ArgError(ArgError::InvalidPolicy(format!(
+            "the forbidden sequence \"{}\" must contain at least 2 characters, forbid a single character from the alphabet instead",
+            sequence
+        ))));
     }
 
-    if password_config.special_characters {
-        password_content.extend(shuffle_dict(dict::SPECIAL_CHARACTERS));
+    let pool: Vec<char> = get_character_classes(password_config).into_iter().flat_map(|class| class.characters).collect();
+    let (_, max_length): (u32, u32) = get_password_length_range(password_config);
+    if password_config.max_repeated > 0 && pool.len() == 1 && max_length > password_config.max_repeated {
+        return Err(WorgenXError::ArgError(ArgError::InvalidPolicy(format!(
+            "a single character can't be repeated at most {} times in passwords of {} characters",
+            password_config.max_repeated, max_length
+        ))));
     }
 
-    let mut rng: ThreadRng = rand::rng();
-    password_content.shuffle(&mut rng);
-    password_content
+    Ok(())
 }
 
-/// This function is responsible for shuffling a vector of u8 from the dict module.
+/// This function is responsible for checking that the characters of the passwords can be generated from the config (template, classes and minimums).
 ///
 /// # Arguments
 ///
-/// * `dict` - The dictionary of u8 to shuffle.
+/// * `password_config` - The password config structure.
 ///
 /// # Returns
 ///
-/// The shuffled vector of u8 sent in parameter.
+/// Ok(()) if the characters can be generated, WorgenXError otherwise.
 ///
-fn shuffle_dict(dict: &[u8]) -> Vec<u8> {
-    let mut shuffled_dict: Vec<u8> = dict.to_vec();
-    let mut rng: ThreadRng = rand::rng();
-    shuffled_dict.shuffle(&mut rng);
-    shuffled_dict
+fn check_character_requirements(password_config: &PasswordConfig) -> Result<(), WorgenXError> {
+    if password_config.max_length != 0 && password_config.max_length < password_config.length {
+        return Err(WorgenXError::ArgError(ArgError::InvalidLengthRange(format!(
+            "{}-{}",
+            password_config.length, password_config.max_length
+        ))));
+    }
+    check_policy_rules(password_config)?;
+    if !password_config.template.is_empty() {
+        return parse_template(&password_config.template).map(|_| ());
+    }
+    if password_config.pronounceable {
+        return Ok(());
+    }
+
+    if let Some(c) = password_config.charset.chars().chain(password_config.exclude.chars()).find(|c| c.is_control()) {
+        return Err(WorgenXError::ArgError(ArgError::InvalidCharacter(c)));
+    }
+
+    let classes: Vec<CharacterClass> = get_character_classes(password_config);
+    if classes.is_empty() {
+        return Err(WorgenXError::ArgError(ArgError::EmptyCharacterPool));
+    }
+
+    let minimums: [(u32, &str); 4] = [
+        (password_config.min_uppercase, "uppercase"),
+        (password_config.min_lowercase, "lowercase"),
+        (password_config.min_numbers, "numbers"),
+        (password_config.min_special_characters, "special characters"),
+    ];
+    if let Some((_, name)) = minimums.iter().find(|(min, name)| *min > 0 && !classes.iter().any(|class| class.name == *name)) {
+        return Err(WorgenXError::ArgError(ArgError::MinimumForUnselectedClass(name.to_string())));
+    }
+
+    let nb_of_required_chars: u64 = classes.iter().map(|class| u64::from(class.min)).sum();
+    if nb_of_required_chars > u64::from(password_config.length) {
+        return Err(WorgenXError::ArgError(ArgError::UnsatisfiableMinimums(nb_of_required_chars, password_config.length)));
+    }
+
+    Ok(())
 }
 
 /// This function is responsible for generating an array of random passwords.
+/// If the config requires some types of characters, every password meets these requirements
+/// and all the valid passwords have the same probability to be generated.
+/// If the passwords must be unique, they are drawn from a random permutation of a small keyspace or the duplicates are drawn again.
 ///
 /// # Arguments
 ///
 /// * `password_config` - The password config structure.
-/// * `number_of_passwords` - The number of passwords to generate.
+/// * `rng` - The random number generator, see `rng::WorgenXRng`.
 ///
 /// # Returns
 ///
 /// A vector of String containing the random passwords.
+/// The vector is empty if the requirements or the policy rules can't be met (see `check_password_config()`).
 ///
-pub fn generate_random_passwords(password_config: &PasswordConfig) -> Vec<String> {
+pub fn generate_random_passwords<R: Rng>(password_config: &PasswordConfig, rng: &mut R) -> Vec<String> {
     let mut passwords: Vec<String> = Vec::new();
-    let password_content: Vec<u8> = create_passwd_content(password_config);
-
-    for _ in 0..password_config.number_of_passwords {
-        let mut rng: ThreadRng = rand::rng();
-        let mut password: String = String::new();
-        for _ in 0..password_config.length {
-            let idx: usize = rng.random_range(0..password_content.len());
-            password.push(password_content[idx] as char);
+    let nb_of_passwords: u64 = password_config.number_of_passwords;
+    if let Ok(mut generator) = PasswordGenerator::new(password_config, rng) {
+        if !password_config.unique {
+            passwords = (0..nb_of_passwords).map(|_| generator.generate(rng)).collect::<Result<Vec<String>, WorgenXError>>().unwrap_or_default();
+        } else {
+            passwords = match KeyspacePermutation::new(&generator, nb_of_passwords, rng) {
+                Ok(Some(permutation)) => (0..nb_of_passwords as usize).map(|idx| permutation.get_password(idx)).collect(),
+                Ok(None) => UniqueFilter::new(nb_of_passwords).draw_batch(&mut generator, rng, nb_of_passwords).unwrap_or_default(),
+                Err(_) => Vec::new(),
+            };
         }
-        passwords.push(password);
     }
 
     passwords
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// This function is responsible for generating the passwords of a config in batches, without keeping them in memory.
+/// Each thread generates its part of the passwords and hands each batch to the `consume` function, in any order.
+/// With a seed, a single thread is used so the output is reproducible and the same as `generate_random_passwords()`.
+///
+/// # Arguments
+///
+/// * `password_config` - The password config structure.
+/// * `nb_of_threads` - The number of threads to use.
+/// * `seed` - The seed of the random generator, None to use the CSPRNG of the operating system.
+/// * `consume` - The function called with each batch of passwords (e.g. to write them in a file).
+///
+/// # Returns
+///
+/// Ok(()) if all the passwords have been generated and consumed, WorgenXError otherwise.
+///
+pub fn generate_passwords_in_batches<F>(
+    password_config: &PasswordConfig,
+    nb_of_threads: usize,
+    seed: Option<u64>,
+    consume: F,
+) -> Result<(), WorgenXError>
+where
+    F: Fn(Vec<String>) -> Result<(), WorgenXError> + Sync,
+{
+    check_password_config(password_config)?;
+    let nb_of_passwords: u64 = password_config.number_of_passwords;
+    if password_config.unique {
+        let mut rng: WorgenXRng = WorgenXRng::new(seed);
+        let generator: PasswordGenerator = PasswordGenerator::new(password_config, &mut rng)?;
+        if let Some(permutation) = KeyspacePermutation::new(&generator, nb_of_passwords, &mut rng)? {
+            // The keyspace is small, a single thread reads the permutation.
+            for first_idx in (0..nb_of_passwords as usize).step_by(PASSWORD_BATCH_SIZE as usize) {
+                let last_idx: usize = (first_idx + PASSWORD_BATCH_SIZE as usize).min(nb_of_passwords as usize);
+                consume((first_idx..last_idx).map(|idx| permutation.get_password(idx)).collect())?;
+            }
+            return Ok(());
+        }
+    }
+    let unique_filter: Option<UniqueFilter> = password_config.unique.then(|| UniqueFilter::new(nb_of_passwords));
+    let nb_of_batches: u64 = nb_of_passwords.div_ceil(PASSWORD_BATCH_SIZE).max(1);
+    let nb_of_threads: u64 = if seed.is_some() {
+        1
+    } else {
+        (nb_of_threads as u64).clamp(1, nb_of_batches)
+    };
+    let context: RunContext = RunContext::new(ProgressGoal::Passwords(nb_of_passwords), true);
 
-    #[test]
-    fn test_create_passwd_content() {
-        let password_config: PasswordConfig = PasswordConfig {
-            numbers: true,
-            special_characters: true,
-            uppercase: true,
-            lowercase: true,
-            length: 10,
-            number_of_passwords: 1,
+    thread::scope(|scope| {
+        let threads: Vec<ScopedJoinHandle<Result<(), WorgenXError>>> = (0..nb_of_threads)
+            .map(|idx| {
+                let nb_of_passwords_in_part: u64 = nb_of_passwords / nb_of_threads + u64::from(idx < nb_of_passwords % nb_of_threads);
+                let (context, consume, unique_filter): (&RunContext, &F, Option<&UniqueFilter>) = (&context, &consume, unique_filter.as_ref());
+                scope.spawn(move || generate_passwords_part(password_config, nb_of_passwords_in_part, seed, consume, unique_filter, context))
+            })
+            .collect();
+
+        let mut result: Result<(), WorgenXError> = Ok(());
+        for thread in threads {
+            let thread_result: Result<(), WorgenXError> = thread
+                .join()
+                .unwrap_or_else(|_| Err(WorgenXError::SystemError(SystemError::ThreadError("A password generation thread panicked".to_string()))));
+            if result.is_ok() {
+                result = thread_result;
+            }
+        }
+        result
+    })
+}
+
+/// This function is responsible for generating a part of the passwords in batches, in a single thread.
+///
+/// # Arguments
+///
+/// * `password_config` - The password config structure.
+/// * `nb_of_passwords` - The number of passwords to generate in this thread.
+/// * `seed` - The seed of the random generator, None to use the CSPRNG of the operating system.
+/// * `consume` - The function called with each batch of passwords.
+/// * `unique_filter` - The filter of the passwords already generated by all the threads, if the passwords must be unique.
+/// * `context` - The context of the run, used to stop all the threads if one of them fails.
+///
+/// # Returns
+///
+/// Ok(()) if the passwords have been generated and consumed, WorgenXError otherwise.
+///
+fn generate_passwords_part<F>(
+    password_config: &PasswordConfig,
+    nb_of_passwords: u64,
+    seed: Option<u64>,
+    consume: &F,
+    unique_filter: Option<&UniqueFilter>,
+    context: &RunContext,
+) -> Result<(), WorgenXError>
+where
+    F: Fn(Vec<String>) -> Result<(), WorgenXError>,
+{
+    let mut rng: WorgenXRng = WorgenXRng::new(seed);
+    let mut generator: PasswordGenerator = PasswordGenerator::new(password_config, &mut rng)?;
+    let mut remaining: u64 = nb_of_passwords;
+
+    while remaining > 0 && !context.is_stopped() {
+        let nb_of_passwords_in_batch: u64 = remaining.min(PASSWORD_BATCH_SIZE);
+        let batch: Result<Vec<String>, WorgenXError> = match unique_filter {
+            Some(unique_filter) => unique_filter.draw_batch(&mut generator, &mut rng, nb_of_passwords_in_batch),
+            None => (0..nb_of_passwords_in_batch).map(|_| generator.generate(&mut rng)).collect(),
         };
-        let password_content: Vec<u8> = create_passwd_content(&password_config);
+        if let Err(e) = batch.and_then(consume) {
+            context.stop();
+            return Err(e);
+        }
+        context.progress.add(nb_of_passwords_in_batch, 0);
+        remaining -= nb_of_passwords_in_batch;
+    }
 
-        assert_eq!(password_content.len(), 91);
+    Ok(())
+}
+
+impl PasswordGenerator {
+    /// This function is responsible for preparing the generation of the passwords of a config.
+    ///
+    /// # Arguments
+    ///
+    /// * `password_config` - The password config structure.
+    /// * `rng` - The random number generator, used to shuffle the pool of characters.
+    ///
+    /// # Returns
+    ///
+    /// The PasswordGenerator struct, WorgenXError if the config is invalid (see `check_password_config()`).
+    ///
+    pub fn new<R: Rng>(password_config: &PasswordConfig, rng: &mut R) -> Result<Self, WorgenXError> {
+        check_password_config(password_config)?;
+        let password_content: Vec<char> = create_passwd_content(password_config, rng);
+        let classes: Vec<CharacterClass> = get_character_classes(password_config);
+
+        let (min_length, max_length): (u32, u32) = get_password_length_range(password_config);
+
+        let model: PasswordModel = if !password_config.template.is_empty() {
+            PasswordModel::Template(parse_template(&password_config.template)?)
+        } else if password_config.pronounceable {
+            PasswordModel::Pronounceable
+        } else if classes.iter().all(|class| class.min == 0) {
+            PasswordModel::Pool(password_content)
+        } else {
+            PasswordModel::Classes(Box::new(ClassSampler::new(classes, max_length)), password_content)
+        };
+
+        let mut generator: PasswordGenerator = PasswordGenerator {
+            model,
+            lengths: min_length..=max_length,
+            max_repeated: password_config.max_repeated,
+            forbidden_sequences: password_config.forbidden_sequences.iter().map(|sequence| sequence.to_lowercase()).collect(),
+            nb_of_valid_passwords: None,
+        };
+        if generator.has_policy_rules() {
+            generator.nb_of_valid_passwords = generator.count_valid_passwords();
+            match generator.nb_of_valid_passwords {
+                Some(0) => {
+                    return Err(WorgenXError::ArgError(ArgError::InvalidPolicy(
+                        "no password of this length and alphabet meets max_repeated and forbidden_sequences".to_string(),
+                    )));
+                }
+                // The keyspace checked by check_password_config() ignores the policy rules.
+                Some(nb_of_valid_passwords) if password_config.unique && nb_of_valid_passwords < password_config.number_of_passwords => {
+                    return Err(WorgenXError::ArgError(ArgError::NotEnoughUniquePasswords(
+                        password_config.number_of_passwords,
+                        nb_of_valid_passwords.to_string(),
+                    )));
+                }
+                _ => (),
+            }
+        }
+
+        Ok(generator)
     }
 
-    #[test]
-    fn test_shuffle_dict() {
-        let dict: Vec<u8> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
-        let shuffled_dict: Vec<u8> = shuffle_dict(&dict);
+    /// This function is responsible for checking if the passwords must meet some policy rules.
+    ///
+    /// # Returns
+    ///
+    /// True if a maximum of repeated characters or a forbidden sequence is set, false otherwise.
+    ///
+    fn has_policy_rules(&self) -> bool {
+        self.max_repeated > 0 || !self.forbidden_sequences.is_empty()
+    }
 
-        assert_eq!(dict.len(), shuffled_dict.len());
+    /// This function is responsible for counting the passwords that can be generated, by enumerating a small keyspace.
+    /// With a length range, the passwords of each length are enumerated and the whole keyspace must be small.
+    ///
+    /// # Returns
+    ///
+    /// The number of valid passwords, None if the keyspace is too large.
+    ///
+    fn count_valid_passwords(&self) -> Option<u64> {
+        let mut permutations: Vec<(KeyspacePermutation, u64)> = Vec::new();
+        let mut total_keyspace: u64 = 0;
+        for length in self.lengths.clone() {
+            let positions: Vec<Vec<char>> = self.get_positions_of_length(length);
+            let keyspace: u64 = positions.iter().try_fold(1u64, |keyspace, position| keyspace.checked_mul(position.len() as u64))?;
+            total_keyspace = total_keyspace.checked_add(keyspace).filter(|keyspace| *keyspace <= POLICY_MAX_KEYSPACE)?;
+            permutations.push((KeyspacePermutation { positions, indexes: Vec::new() }, keyspace));
+        }
+
+        let nb_of_valid_passwords: usize = permutations
+            .iter()
+            .map(|(permutation, keyspace)| (0..*keyspace as u32).filter(|idx| self.is_valid(&permutation.decode(*idx))).count())
+            .sum();
+        Some(nb_of_valid_passwords as u64)
     }
 
-    #[test]
-    fn test_generate_random_passwords() {
-        let password_config: PasswordConfig = PasswordConfig {
-            numbers: true,
-            special_characters: true,
-            uppercase: true,
-            lowercase: true,
-            length: 10,
-            number_of_passwords: 1,
+    /// This function is responsible for returning the characters that can be drawn at each position of the passwords.
+    ///
+    /// # Returns
+    ///
+    /// The vector of the characters of each position, None if the length of the passwords isn't fixed.
+    ///
+    fn get_positions(&self) -> Option<Vec<Vec<char>>> {
+        if self.lengths.start() != self.lengths.end() {
+            return None;
+        }
+        Some(self.get_positions_of_length(*self.lengths.start()))
+    }
+
+    /// This function is responsible for returning the characters that can be drawn at each position of the passwords of a given length.
+    ///
+    /// # Arguments
+    ///
+    /// * `length` - The length of the passwords, ignored with a template.
+    ///
+    /// # Returns
+    ///
+    /// The vector of the characters of each position.
+    ///
+    fn get_positions_of_length(&self, length: u32) -> Vec<Vec<char>> {
+        let length: usize = length as usize;
+        let to_chars = |characters: &[u8]| -> Vec<char> { characters.iter().map(|c| *c as char).collect() };
+        let positions: Vec<Vec<char>> = match &self.model {
+            PasswordModel::Template(template) => template
+                .iter()
+                .map(|element| match element {
+                    TemplateElement::Fixed(c) => vec![*c],
+                    TemplateElement::Random(characters) => to_chars(characters),
+                })
+                .collect(),
+            PasswordModel::Pronounceable => (0..length)
+                .map(|idx| to_chars(if idx % 2 == 0 { dict::CONSONANTS } else { dict::VOWELS }))
+                .collect(),
+            PasswordModel::Pool(password_content) | PasswordModel::Classes(_, password_content) => vec![password_content.clone(); length],
         };
-        let passwords: Vec<String> = generate_random_passwords(&password_config);
+        positions
+    }
 
-        assert_eq!(passwords.len(), 1);
-        assert_eq!(passwords[0].len(), 10);
+    /// This function is responsible for checking if a password built from `get_positions()` meets the minimums of the character classes and the policy rules.
+    ///
+    /// # Arguments
+    ///
+    /// * `password` - The password to check.
+    ///
+    /// # Returns
+    ///
+    /// True if the password can be generated, false otherwise.
+    ///
+    fn is_valid(&self, password: &str) -> bool {
+        let meets_minimums: bool = match &self.model {
+            PasswordModel::Classes(sampler, _) => sampler.meets_minimums(&password.chars().collect::<Vec<char>>()),
+            _ => true,
+        };
+        meets_minimums && self.meets_policy_rules(password)
+    }
+
+    /// This function is responsible for checking if a password meets the policy rules (maximum of repeated characters and forbidden sequences).
+    ///
+    /// # Arguments
+    ///
+    /// * `password` - The password to check.
+    ///
+    /// # Returns
+    ///
+    /// True if the password meets the rules, false otherwise.
+    ///
+    fn meets_policy_rules(&self, password: &str) -> bool {
+        if self.max_repeated > 0 {
+            let mut nb_of_repetitions: u32 = 0;
+            let mut previous_char: Option<char> = None;
+            for c in password.chars() {
+                nb_of_repetitions = if previous_char == Some(c) { nb_of_repetitions + 1 } else { 1 };
+                if nb_of_repetitions > self.max_repeated {
+                    return false;
+                }
+                previous_char = Some(c);
+            }
+        }
+
+        if self.forbidden_sequences.is_empty() {
+            return true;
+        }
+        let password: String = password.to_lowercase();
+        !self.forbidden_sequences.iter().any(|sequence| password.contains(sequence.as_str()))
+    }
+
+    /// This function is responsible for generating a single random password.
+    /// The passwords breaking the policy rules are drawn again, up to POLICY_MAX_ATTEMPTS times.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - The random number generator.
+    ///
+    /// # Returns
+    ///
+    /// The random password, WorgenXError if the policy rules rejected all the passwords drawn.
+    ///
+    pub fn generate<R: Rng>(&mut self, rng: &mut R) -> Result<String, WorgenXError> {
+        for _ in 0..POLICY_MAX_ATTEMPTS {
+            let password: String = self.draw(rng);
+            if self.meets_policy_rules(&password) {
+                return Ok(password);
+            }
+        }
+
+        Err(WorgenXError::ArgError(ArgError::InvalidPolicy(format!(
+            "max_repeated and forbidden_sequences rejected {} passwords in a row, they are too strict for this length and alphabet",
+            POLICY_MAX_ATTEMPTS
+        ))))
+    }
+
+    /// This function is responsible for computing the base 2 logarithm of the share of the passwords meeting the policy rules.
+    /// The share is exact for a small keyspace, estimated from POLICY_SAMPLE_SIZE passwords otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `log2_nb_of_passwords` - The base 2 logarithm of the number of passwords without the policy rules.
+    /// * `rng` - The random number generator, used to draw the sample.
+    ///
+    /// # Returns
+    ///
+    /// The base 2 logarithm of the share of the valid passwords (0 without any policy rule).
+    ///
+    fn get_log2_policy_ratio<R: Rng>(&mut self, log2_nb_of_passwords: f64, rng: &mut R) -> f64 {
+        if !self.has_policy_rules() {
+            return 0.0;
+        }
+        if let Some(nb_of_valid_passwords) = self.nb_of_valid_passwords {
+            return (nb_of_valid_passwords as f64).log2() - log2_nb_of_passwords;
+        }
+
+        let nb_of_accepted_passwords: u32 = (0..POLICY_SAMPLE_SIZE)
+            .filter(|_| {
+                let password: String = self.draw(rng);
+                self.meets_policy_rules(&password)
+            })
+            .count() as u32;
+        // With no accepted password in the sample, the share is at most 1/POLICY_SAMPLE_SIZE.
+        (f64::from(nb_of_accepted_passwords.max(1)) / f64::from(POLICY_SAMPLE_SIZE)).log2()
+    }
+
+    /// This function is responsible for drawing a single random password from the model, without checking the policy rules.
+    /// The length is drawn uniformly from the length range first, if the length isn't fixed.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - The random number generator.
+    ///
+    /// # Returns
+    ///
+    /// The random password.
+    ///
+    fn draw<R: Rng>(&mut self, rng: &mut R) -> String {
+        let length: u32 = if self.lengths.start() == self.lengths.end() {
+            *self.lengths.start()
+        } else {
+            rng.random_range(self.lengths.clone())
+        };
+
+        match &mut self.model {
+            PasswordModel::Template(template) => generate_password_from_template(rng, template),
+            PasswordModel::Pronounceable => generate_pronounceable_password(rng, length),
+            PasswordModel::Pool(password_content) => (0..length)
+                .map(|_| password_content[rng.random_range(0..password_content.len())])
+                .collect(),
+            PasswordModel::Classes(sampler, password_content) => sampler.generate_password(rng, password_content, length as usize),
+        }
+    }
+}
+
+impl UniqueFilter {
+    /// This function is responsible for creating an empty filter.
+    ///
+    /// # Arguments
+    ///
+    /// * `nb_of_passwords` - The number of unique passwords requested from all the threads.
+    ///
+    /// # Returns
+    ///
+    /// The UniqueFilter struct.
+    ///
+    fn new(nb_of_passwords: u64) -> Self {
+        UniqueFilter {
+            nb_of_passwords,
+            ..Default::default()
+        }
+    }
+
+    /// This function is responsible for drawing a batch of passwords that have never been generated before.
+    /// It gives up after UNIQUE_MAX_ATTEMPTS duplicates in a row, as fewer unique passwords than requested may be generated.
+    ///
+    /// # Arguments
+    ///
+    /// * `generator` - The password generator.
+    /// * `rng` - The random number generator.
+    /// * `nb_of_passwords` - The number of passwords of the batch.
+    ///
+    /// # Returns
+    ///
+    /// The batch of unique passwords, WorgenXError if the policy rules can't be met, if there aren't enough unique passwords
+    /// or if another thread panicked while holding the filter.
+    ///
+    fn draw_batch<R: Rng>(&self, generator: &mut PasswordGenerator, rng: &mut R, nb_of_passwords: u64) -> Result<Vec<String>, WorgenXError> {
+        let nb_of_passwords: usize = nb_of_passwords as usize;
+        let mut batch: Vec<String> = Vec::with_capacity(nb_of_passwords);
+        let mut nb_of_duplicates: u32 = 0;
+        while batch.len() < nb_of_passwords {
+            let candidates: Vec<String> = (batch.len()..nb_of_passwords).map(|_| generator.generate(rng)).collect::<Result<Vec<String>, WorgenXError>>()?;
+            let mut hashes = self
+                .hashes
+                .lock()
+                .map_err(|e| WorgenXError::SystemError(SystemError::ThreadError(e.to_string())))?;
+            for candidate in candidates {
+                if hashes.insert(self.hasher.hash_one(&candidate)) {
+                    batch.push(candidate);
+                    nb_of_duplicates = 0;
+                } else {
+                    nb_of_duplicates += 1;
+                }
+            }
+            if nb_of_duplicates >= UNIQUE_MAX_ATTEMPTS {
+                return Err(WorgenXError::ArgError(ArgError::NotEnoughUniquePasswords(
+                    self.nb_of_passwords,
+                    format!("about {}", hashes.len()),
+                )));
+            }
+        }
+
+        Ok(batch)
+    }
+}
+
+impl KeyspacePermutation {
+    /// This function is responsible for drawing a random permutation of the passwords of a small keyspace.
+    ///
+    /// # Arguments
+    ///
+    /// * `generator` - The password generator.
+    /// * `nb_of_passwords` - The number of unique passwords to draw.
+    /// * `rng` - The random number generator.
+    ///
+    /// # Returns
+    ///
+    /// The permutation, None if the keyspace is too large, the passwords can be drawn with a few rejections or their length isn't fixed.
+    /// WorgenXError if the policy rules leave less valid passwords than requested.
+    ///
+    fn new<R: Rng>(generator: &PasswordGenerator, nb_of_passwords: u64, rng: &mut R) -> Result<Option<Self>, WorgenXError> {
+        let Some(positions) = generator.get_positions() else {
+            return Ok(None);
+        };
+        let keyspace: u64 = match positions.iter().try_fold(1u64, |keyspace, position| keyspace.checked_mul(position.len() as u64)) {
+            Some(keyspace) if keyspace <= PERMUTATION_MAX_KEYSPACE && nb_of_passwords.saturating_mul(PERMUTATION_MIN_RATIO) >= keyspace => keyspace,
+            _ => return Ok(None),
+        };
+
+        let mut permutation: KeyspacePermutation = KeyspacePermutation {
+            positions,
+            indexes: Vec::new(),
+        };
+        permutation.indexes = (0..keyspace as u32)
+            .filter(|idx| generator.is_valid(&permutation.decode(*idx)))
+            .collect();
+
+        if (permutation.indexes.len() as u64) < nb_of_passwords {
+            return Err(WorgenXError::ArgError(ArgError::NotEnoughUniquePasswords(
+                nb_of_passwords,
+                permutation.indexes.len().to_string(),
+            )));
+        }
+
+        // Partial Fisher-Yates shuffle, only the first passwords are used.
+        let nb_of_passwords: usize = nb_of_passwords as usize;
+        for idx in 0..nb_of_passwords {
+            let swap_idx: usize = rng.random_range(idx..permutation.indexes.len());
+            permutation.indexes.swap(idx, swap_idx);
+        }
+        permutation.indexes.truncate(nb_of_passwords);
+
+        Ok(Some(permutation))
+    }
+
+    /// This function is responsible for returning a password of the permutation.
+    ///
+    /// # Arguments
+    ///
+    /// * `idx` - The position of the password in the permutation.
+    ///
+    /// # Returns
+    ///
+    /// The password.
+    ///
+    fn get_password(&self, idx: usize) -> String {
+        self.decode(self.indexes[idx])
+    }
+
+    /// This function is responsible for converting the index of a password in the keyspace to the password.
+    /// The last character changes first, like in the wordlist generation.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the password in the keyspace.
+    ///
+    /// # Returns
+    ///
+    /// The password.
+    ///
+    fn decode(&self, mut index: u32) -> String {
+        let mut password: Vec<char> = Vec::with_capacity(self.positions.len());
+        for position in self.positions.iter().rev() {
+            password.push(position[index as usize % position.len()]);
+            index /= position.len() as u32;
+        }
+        password.into_iter().rev().collect()
+    }
+}
+
+/// This function is responsible for generating a pronounceable password.
+/// The password is made of consonant-vowel syllables (e.g. "tobaxemu"), an odd length ends with a consonant.
+/// Each letter is drawn uniformly from its set, so each password matches a single sequence of draws.
+///
+/// # Arguments
+///
+/// * `rng` - The random number generator.
+/// * `length` - The length of the password.
+///
+/// # Returns
+///
+/// The pronounceable password.
+///
+fn generate_pronounceable_password<R: Rng>(rng: &mut R, length: u32) -> String {
+    (0..length)
+        .map(|idx| {
+            let letters: &[u8] = if idx % 2 == 0 { dict::CONSONANTS } else { dict::VOWELS };
+            letters[rng.random_range(0..letters.len())] as char
+        })
+        .collect()
+}
+
+/// This function is responsible for parsing a password template.
+/// The template uses the syntax of the wordlist masks (`?` introduces a placeholder, `\` escapes the next character) with the following placeholders:
+///
+/// * `?l`, `?u`, `?d`, `?s` - A lowercase letter, an uppercase letter, a number or a special character.
+/// * `?a` or a bare `?` - Any character of the four types above, like a variable character of a wordlist mask.
+/// * `c`, `v`, `C`, `V` - A lowercase consonant, a lowercase vowel, an uppercase consonant or an uppercase vowel.
+/// * `9`, `!` - A number or a special character.
+///
+/// All the other characters are fixed, e.g. `Cvccvc-99-!!` or `wifi-?d?d?d?d`.
+/// The letters `c`, `v`, `C`, `V` and the characters `9`, `!` of a fixed text must be escaped, e.g. `offi\ce-\9\9` for `office-99`.
+///
+/// # Arguments
+///
+/// * `template` - The template given by the user.
+///
+/// # Returns
+///
+/// The vector of the elements of the template, WorgenXError if the template is invalid.
+///
+fn parse_template(template: &str) -> Result<Vec<TemplateElement>, WorgenXError> {
+    let invalid_template = || WorgenXError::ArgError(ArgError::InvalidTemplate(template.to_string()));
+    let mut elements: Vec<TemplateElement> = Vec::new();
+    let mut chars: std::iter::Peekable<std::str::Chars> = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        elements.push(match c {
+            '\\' => TemplateElement::Fixed(chars.next().ok_or_else(invalid_template)?),
+            '?' => TemplateElement::Random(match chars.next_if(|c| matches!(c, 'l' | 'u' | 'd' | 's' | 'a')) {
+                Some('l') => dict::LOWERCASE,
+                Some('u') => dict::UPPERCASE,
+                Some('d') => dict::NUMBERS,
+                Some('s') => dict::SPECIAL_CHARACTERS,
+                _ => dict::ALL_CHARACTERS,
+            }),
+            'c' => TemplateElement::Random(dict::CONSONANTS),
+            'v' => TemplateElement::Random(dict::VOWELS),
+            'C' => TemplateElement::Random(dict::UPPERCASE_CONSONANTS),
+            'V' => TemplateElement::Random(dict::UPPERCASE_VOWELS),
+            '9' => TemplateElement::Random(dict::NUMBERS),
+            '!' => TemplateElement::Random(dict::SPECIAL_CHARACTERS),
+            _ => TemplateElement::Fixed(c),
+        });
+    }
+
+    Ok(elements)
+}
+
+/// This function is responsible for returning the range of the length of the passwords generated from a password config.
+///
+/// # Arguments
+///
+/// * `password_config` - The password config structure.
+///
+/// # Returns
+///
+/// The minimum and maximum length: the number of characters of the template if there is one, the length range of the config otherwise.
+///
+pub fn get_password_length_range(password_config: &PasswordConfig) -> (u32, u32) {
+    if password_config.template.is_empty() {
+        (password_config.length, password_config.length.max(password_config.max_length))
+    } else {
+        let length: u32 = parse_template(&password_config.template).unwrap_or_default().len() as u32;
+        (length, length)
+    }
+}
+
+/// This function is responsible for parsing the length of the passwords, a number (e.g. 16) or a range (e.g. 12-20).
+///
+/// # Arguments
+///
+/// * `value` - The length or the range of the length.
+///
+/// # Returns
+///
+/// The minimum and maximum length (the same for a single number), WorgenXError if the value isn't a valid length or range.
+///
+pub fn parse_length_range(value: &str) -> Result<(u32, u32), WorgenXError> {
+    let invalid_range = || WorgenXError::ArgError(ArgError::InvalidLengthRange(value.to_string()));
+    let (min_length, max_length): (&str, &str) = value.split_once('-').unwrap_or((value, value));
+    let min_length: u32 = min_length.trim().parse().map_err(|_| invalid_range())?;
+    let max_length: u32 = max_length.trim().parse().map_err(|_| invalid_range())?;
+    if min_length == 0 || min_length > max_length {
+        return Err(invalid_range());
+    }
+
+    Ok((min_length, max_length))
+}
+
+/// This function is responsible for generating a password from a parsed template.
+///
+/// # Arguments
+///
+/// * `rng` - The random number generator.
+/// * `template` - The elements of the template.
+///
+/// # Returns
+///
+/// The random password.
+///
+fn generate_password_from_template<R: Rng>(rng: &mut R, template: &[TemplateElement]) -> String {
+    template
+        .iter()
+        .map(|element| match element {
+            TemplateElement::Fixed(c) => *c,
+            TemplateElement::Random(characters) => characters[rng.random_range(0..characters.len())] as char,
+        })
+        .collect()
+}
+
+/// This function is responsible for computing the entropy of the passwords generated from a password config.
+/// The passwords are drawn uniformly from all the valid passwords, so the entropy is the base 2 logarithm of their number.
+/// The passwords that don't meet the minimums of the character classes are not counted.
+/// For the pronounceable passwords, only the consonant-vowel syllables can be generated, so the entropy is based on this model.
+/// For a template, the entropy is the sum of the entropies of its random characters.
+/// With a length range, the shortest passwords are the most likely ones, so the entropy is the one of the shortest passwords
+/// plus the base 2 logarithm of the number of lengths (the min-entropy of the passwords).
+/// The passwords rejected by the policy rules are not counted: their share is exact for a small keyspace and estimated from a sample otherwise.
+///
+/// # Arguments
+///
+/// * `password_config` - The password config structure.
+///
+/// # Returns
+///
+/// The entropy in bits, 0 if no password can be generated from the config.
+///
+pub fn get_entropy(password_config: &PasswordConfig) -> f64 {
+    if check_character_requirements(password_config).is_err() {
+        return 0.0;
+    }
+
+    let (min_length, max_length): (u32, u32) = get_password_length_range(password_config);
+    let log2_nb_of_passwords: f64 = get_log2_nb_of_passwords(password_config, min_length..=min_length);
+    let entropy: f64 = log2_nb_of_passwords + f64::from(max_length - min_length + 1).log2();
+    if password_config.max_repeated == 0 && password_config.forbidden_sequences.is_empty() {
+        return entropy;
+    }
+
+    // A fixed seed keeps the estimation of the share of the valid passwords reproducible.
+    let mut rng: WorgenXRng = WorgenXRng::new(Some(0));
+    match PasswordGenerator::new(password_config, &mut rng) {
+        Ok(mut generator) => (entropy + generator.get_log2_policy_ratio(log2_nb_of_passwords, &mut rng)).max(0.0),
+        Err(_) => 0.0,
+    }
+}
+
+/// This function is responsible for computing the base 2 logarithm of the number of passwords that can be generated from a valid config.
+///
+/// # Arguments
+///
+/// * `password_config` - The password config structure, already checked by `check_character_requirements()`.
+/// * `lengths` - The lengths of the passwords counted, ignored for a template.
+///
+/// # Returns
+///
+/// The base 2 logarithm of the keyspace.
+///
+fn get_log2_nb_of_passwords(password_config: &PasswordConfig, lengths: RangeInclusive<u32>) -> f64 {
+    if !password_config.template.is_empty() {
+        return parse_template(&password_config.template)
+            .unwrap_or_default()
+            .iter()
+            .map(|element| match element {
+                TemplateElement::Fixed(_) => 0.0,
+                TemplateElement::Random(characters) => (characters.len() as f64).log2(),
+            })
+            .sum();
+    }
+
+    if password_config.pronounceable {
+        return lengths
+            .map(|length| {
+                let nb_of_vowels: u32 = length / 2;
+                let nb_of_consonants: u32 = length - nb_of_vowels;
+                f64::from(nb_of_consonants) * (dict::CONSONANTS.len() as f64).log2() + f64::from(nb_of_vowels) * (dict::VOWELS.len() as f64).log2()
+            })
+            .fold(f64::NEG_INFINITY, log2_add);
+    }
+
+    let classes: Vec<CharacterClass> = get_character_classes(password_config);
+    let pool_size: usize = classes.iter().map(|class| class.characters.len()).sum();
+    let max_length: u32 = *lengths.end();
+    let mut table: Option<Vec<Vec<f64>>> = None;
+    lengths
+        .map(|length| {
+            let log2_all_passwords: f64 = f64::from(length) * (pool_size as f64).log2();
+            if classes.iter().all(|class| class.min == 0) {
+                log2_all_passwords
+            } else if classes.iter().all(|class| class.min <= 1) && length > INCLUSION_EXCLUSION_MIN_LENGTH {
+                // Ratio of the valid passwords: sum over the subsets S of the required classes of (-1)^|S| * ((pool - |S|) / pool)^length.
+                let required_sizes: Vec<usize> = classes.iter().filter(|class| class.min == 1).map(|class| class.characters.len()).collect();
+                let mut ratio: f64 = 0.0;
+                for subset in 0..1usize << required_sizes.len() {
+                    let missing_size: usize = required_sizes.iter().enumerate().filter(|(idx, _)| subset & (1 << idx) != 0).map(|(_, size)| size).sum();
+                    let term: f64 = (f64::from(length) * ((pool_size - missing_size) as f64 / pool_size as f64).log2()).exp2();
+                    ratio += if subset.count_ones() % 2 == 0 { term } else { -term };
+                }
+                log2_all_passwords + ratio.log2()
+            } else {
+                // The table of the longest passwords also counts the shorter ones.
+                let table: &Vec<Vec<f64>> = table.get_or_insert_with(|| ClassSampler::new(classes.clone(), max_length).build_table());
+                table[0][length as usize]
+            }
+        })
+        .fold(f64::NEG_INFINITY, log2_add)
+}
+
+/// This function is responsible for returning the strength label matching an entropy.
+///
+/// # Arguments
+///
+/// * `entropy` - The entropy in bits.
+///
+/// # Returns
+///
+/// The strength label, from "very weak" to "very strong".
+///
+pub fn get_strength_label(entropy: f64) -> &'static str {
+    STRENGTH_LABELS
+        .iter()
+        .find(|(min_entropy, _)| entropy >= *min_entropy)
+        .map_or("very weak", |(_, label)| label)
+}
+
+impl ClassSampler {
+    /// This function is responsible for creating a new sampler from the character classes.
+    /// The classes without minimum are merged into a single class.
+    ///
+    /// # Arguments
+    ///
+    /// * `classes` - The selected character classes.
+    /// * `length` - The maximum length of the passwords.
+    ///
+    /// # Returns
+    ///
+    /// The ClassSampler struct.
+    ///
+    fn new(classes: Vec<CharacterClass>, length: u32) -> Self {
+        let (mut constrained_classes, free_classes): (Vec<CharacterClass>, Vec<CharacterClass>) =
+            classes.into_iter().partition(|class| class.min > 0);
+        let free_characters: Vec<char> = free_classes.into_iter().flat_map(|class| class.characters).collect();
+        if !free_characters.is_empty() {
+            constrained_classes.push(CharacterClass {
+                name: "other characters",
+                characters: free_characters,
+                min: 0,
+            });
+        }
+
+        let mut class_indexes: HashMap<char, usize> = HashMap::new();
+        for (idx, class) in constrained_classes.iter().enumerate() {
+            for c in &class.characters {
+                class_indexes.insert(*c, idx);
+            }
+        }
+
+        let length: usize = length as usize;
+        let mut log2_factorials: Vec<f64> = Vec::with_capacity(length + 1);
+        log2_factorials.push(0.0);
+        for i in 1..=length {
+            log2_factorials.push(log2_factorials[i - 1] + (i as f64).log2());
+        }
+
+        ClassSampler {
+            classes: constrained_classes,
+            class_indexes,
+            length,
+            log2_factorials,
+            table: None,
+        }
+    }
+
+    /// This function is responsible for generating a single password meeting the minimums of the classes.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - The random number generator.
+    /// * `password_content` - All the characters of the selected classes.
+    /// * `length` - The length of the password, up to the maximum length of the sampler.
+    ///
+    /// # Returns
+    ///
+    /// The random password.
+    ///
+    fn generate_password<R: Rng>(&mut self, rng: &mut R, password_content: &[char], length: usize) -> String {
+        let mut password: Vec<char> = vec!['\0'; length];
+        for _ in 0..REJECTION_ATTEMPTS {
+            for c in password.iter_mut() {
+                *c = password_content[rng.random_range(0..password_content.len())];
+            }
+            if self.meets_minimums(&password) {
+                return password.into_iter().collect();
+            }
+        }
+
+        self.generate_password_from_table(rng, length)
+    }
+
+    /// This function is responsible for checking if a password contains enough characters of each class.
+    ///
+    /// # Arguments
+    ///
+    /// * `password` - The password to check.
+    ///
+    /// # Returns
+    ///
+    /// True if all the minimums are met, false otherwise.
+    ///
+    fn meets_minimums(&self, password: &[char]) -> bool {
+        let mut counts: Vec<u32> = vec![0; self.classes.len()];
+        for c in password {
+            if let Some(idx) = self.class_indexes.get(c) {
+                counts[*idx] += 1;
+            }
+        }
+        counts.iter().zip(&self.classes).all(|(count, class)| *count >= class.min)
+    }
+
+    /// This function is responsible for generating a password with the exact distribution of the number of characters of each class.
+    /// The table is built on the first call, up to the maximum length.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - The random number generator.
+    /// * `length` - The length of the password.
+    ///
+    /// # Returns
+    ///
+    /// The random password.
+    ///
+    fn generate_password_from_table<R: Rng>(&mut self, rng: &mut R, length: usize) -> String {
+        if self.table.is_none() {
+            self.table = Some(self.build_table());
+        }
+        let table: &Vec<Vec<f64>> = self.table.as_ref().unwrap_or_else(|| unreachable!());
+        let last_class: usize = self.classes.len() - 1;
+        let mut labels: Vec<usize> = Vec::with_capacity(length);
+        let mut remaining: usize = length;
+
+        for (idx, class) in self.classes.iter().enumerate().take(last_class) {
+            let threshold: f64 = rng.random::<f64>();
+            let log2_class_size: f64 = (class.characters.len() as f64).log2();
+            let mut cumulative: f64 = 0.0;
+            let mut count: usize = class.min as usize;
+            for c in class.min as usize..=remaining {
+                let log2_ways: f64 = table[idx + 1][remaining - c];
+                if log2_ways == f64::NEG_INFINITY {
+                    continue;
+                }
+                count = c;
+                cumulative += (self.log2_binomial(remaining, c) + c as f64 * log2_class_size + log2_ways - table[idx][remaining]).exp2();
+                if threshold < cumulative {
+                    break;
+                }
+            }
+            labels.extend(std::iter::repeat_n(idx, count));
+            remaining -= count;
+        }
+        labels.extend(std::iter::repeat_n(last_class, remaining));
+        labels.shuffle(rng);
+
+        labels
+            .into_iter()
+            .map(|idx| {
+                let characters: &[char] = &self.classes[idx].characters;
+                characters[rng.random_range(0..characters.len())]
+            })
+            .collect()
+    }
+
+    /// This function is responsible for building the table of the number of valid passwords.
+    /// `table[i][r]` is the base 2 logarithm of the number of ways to fill `r` characters with the classes `i..`, meeting their minimums.
+    ///
+    /// # Returns
+    ///
+    /// The table, with f64::NEG_INFINITY where there isn't any way.
+    ///
+    fn build_table(&self) -> Vec<Vec<f64>> {
+        let nb_of_classes: usize = self.classes.len();
+        let mut table: Vec<Vec<f64>> = vec![vec![f64::NEG_INFINITY; self.length + 1]; nb_of_classes];
+
+        let last_class: &CharacterClass = &self.classes[nb_of_classes - 1];
+        let log2_last_class_size: f64 = (last_class.characters.len() as f64).log2();
+        for (r, log2_ways) in table[nb_of_classes - 1].iter_mut().enumerate().skip(last_class.min as usize) {
+            *log2_ways = r as f64 * log2_last_class_size;
+        }
+
+        for idx in (0..nb_of_classes - 1).rev() {
+            let class: &CharacterClass = &self.classes[idx];
+            let log2_class_size: f64 = (class.characters.len() as f64).log2();
+            for r in 0..=self.length {
+                let mut log2_ways: f64 = f64::NEG_INFINITY;
+                for c in (class.min as usize)..=r {
+                    if table[idx + 1][r - c] == f64::NEG_INFINITY {
+                        continue;
+                    }
+                    let term: f64 = self.log2_binomial(r, c) + c as f64 * log2_class_size + table[idx + 1][r - c];
+                    log2_ways = log2_add(log2_ways, term);
+                }
+                table[idx][r] = log2_ways;
+            }
+        }
+
+        table
+    }
+
+    /// This function is responsible for computing the base 2 logarithm of the binomial coefficient C(n, k).
+    ///
+    fn log2_binomial(&self, n: usize, k: usize) -> f64 {
+        self.log2_factorials[n] - self.log2_factorials[k] - self.log2_factorials[n - k]
+    }
+}
+
+/// This function is responsible for adding two numbers given by their base 2 logarithm, without overflow.
+///
+/// # Arguments
+///
+/// * `a` - The base 2 logarithm of the first number.
+/// * `b` - The base 2 logarithm of the second number.
+///
+/// # Returns
+///
+/// The base 2 logarithm of the sum.
+///
+fn log2_add(a: f64, b: f64) -> f64 {
+    if a == f64::NEG_INFINITY {
+        return b;
+    }
+    if b == f64::NEG_INFINITY {
+        return a;
+    }
+    let (max, min): (f64, f64) = if a > b { (a, b) } else { (b, a) };
+    max + (1.0 + (min - max).exp2()).log2()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_passwd_content() {
+        let password_config: PasswordConfig = PasswordConfig {
+            numbers: true,
+            special_characters: true,
+            uppercase: true,
+            lowercase: true,
+            length: 10,
+            number_of_passwords: 1,
+            ..Default::default()
+        };
+        let password_content: Vec<char> = create_passwd_content(&password_config, &mut WorgenXRng::new(None));
+
+        assert_eq!(password_content.len(), 91);
+    }
+
+    #[test]
+    fn test_shuffle_dict() {
+        let dict: Vec<char> = "abcdefghij".chars().collect();
+        let shuffled_dict: Vec<char> = shuffle_dict(&dict, &mut WorgenXRng::new(None));
+
+        assert_eq!(dict.len(), shuffled_dict.len());
+    }
+
+    #[test]
+    fn test_generate_random_passwords() {
+        let password_config: PasswordConfig = PasswordConfig {
+            numbers: true,
+            special_characters: true,
+            uppercase: true,
+            lowercase: true,
+            length: 10,
+            number_of_passwords: 1,
+            ..Default::default()
+        };
+        let passwords: Vec<String> = generate_random_passwords(&password_config, &mut WorgenXRng::new(None));
+
+        assert_eq!(passwords.len(), 1);
+        assert_eq!(passwords[0].len(), 10);
+    }
+
+    #[test]
+    fn test_generate_random_passwords_with_class_coverage() {
+        let password_config: PasswordConfig = PasswordConfig {
+            numbers: true,
+            special_characters: true,
+            uppercase: true,
+            lowercase: true,
+            length: 4,
+            number_of_passwords: 200,
+            ..Default::default()
+        };
+        let passwords: Vec<String> = generate_random_passwords(&password_config, &mut WorgenXRng::new(None));
+
+        assert_eq!(passwords.len(), 200);
+        for password in passwords {
+            assert!(password.bytes().any(|c| dict::UPPERCASE.contains(&c)));
+            assert!(password.bytes().any(|c| dict::LOWERCASE.contains(&c)));
+            assert!(password.bytes().any(|c| dict::NUMBERS.contains(&c)));
+            assert!(password.bytes().any(|c| dict::SPECIAL_CHARACTERS.contains(&c)));
+        }
+    }
+
+    #[test]
+    fn test_generate_random_passwords_with_minimums() {
+        let password_config: PasswordConfig = PasswordConfig {
+            numbers: true,
+            lowercase: true,
+            length: 8,
+            number_of_passwords: 100,
+            min_numbers: 7,
+            ..Default::default()
+        };
+        let passwords: Vec<String> = generate_random_passwords(&password_config, &mut WorgenXRng::new(None));
+
+        assert_eq!(passwords.len(), 100);
+        for password in passwords {
+            assert_eq!(password.len(), 8);
+            assert_eq!(password.bytes().filter(|c| dict::NUMBERS.contains(c)).count(), 7);
+            assert_eq!(password.bytes().filter(|c| dict::LOWERCASE.contains(c)).count(), 1);
+        }
+    }
+
+    #[test]
+    fn test_check_password_config() {
+        let mut password_config: PasswordConfig = PasswordConfig {
+            uppercase: true,
+            numbers: true,
+            length: 2,
+            number_of_passwords: 1,
+            ..Default::default()
+        };
+        assert!(check_password_config(&password_config).is_ok());
+
+        password_config.length = 1;
+        assert!(check_password_config(&password_config).is_err());
+        assert!(generate_random_passwords(&password_config, &mut WorgenXRng::new(None)).is_empty());
+
+        password_config.class_coverage = false;
+        assert!(check_password_config(&password_config).is_ok());
+
+        password_config.min_special_characters = 1;
+        assert!(check_password_config(&password_config).is_err());
+    }
+
+    #[test]
+    fn test_class_sampler_table() {
+        let password_config: PasswordConfig = PasswordConfig {
+            numbers: true,
+            lowercase: true,
+            length: 3,
+            min_numbers: 2,
+            ..Default::default()
+        };
+        let mut sampler: ClassSampler = ClassSampler::new(get_character_classes(&password_config), password_config.length);
+        let table: Vec<Vec<f64>> = sampler.build_table();
+
+        // 3 positions for the lowercase letter, 26 lowercase letters and 10 * 10 numbers.
+        assert!((table[0][3] - 7800f64.log2()).abs() < 1e-9);
+
+        let mut rng: WorgenXRng = WorgenXRng::new(None);
+        for _ in 0..100 {
+            let password: String = sampler.generate_password_from_table(&mut rng, 3);
+            assert!(sampler.meets_minimums(&password.chars().collect::<Vec<char>>()));
+        }
+    }
+
+    #[test]
+    fn test_get_entropy() {
+        let mut password_config: PasswordConfig = PasswordConfig {
+            numbers: true,
+            lowercase: true,
+            length: 3,
+            number_of_passwords: 1,
+            class_coverage: false,
+            ..Default::default()
+        };
+        assert!((get_entropy(&password_config) - 3.0 * 36f64.log2()).abs() < 1e-9);
+
+        password_config.min_numbers = 2;
+        password_config.class_coverage = true;
+        assert!((get_entropy(&password_config) - 7800f64.log2()).abs() < 1e-9);
+
+        // Both methods must give the same entropy for a long password.
+        password_config.min_numbers = 0;
+        password_config.length = INCLUSION_EXCLUSION_MIN_LENGTH + 1;
+        let classes: Vec<CharacterClass> = get_character_classes(&password_config);
+        let expected_entropy: f64 = ClassSampler::new(classes, password_config.length).build_table()[0][password_config.length as usize];
+        assert!((get_entropy(&password_config) - expected_entropy).abs() < 1e-6);
+
+        password_config.length = 1;
+        assert_eq!(get_entropy(&password_config), 0.0);
+    }
+
+    #[test]
+    fn test_get_strength_label() {
+        assert_eq!(get_strength_label(0.0), "very weak");
+        assert_eq!(get_strength_label(30.0), "weak");
+        assert_eq!(get_strength_label(40.0), "reasonable");
+        assert_eq!(get_strength_label(65.5), "strong");
+        assert_eq!(get_strength_label(128.0), "very strong");
+    }
+
+    #[test]
+    fn test_create_passwd_content_with_charset_and_exclusions() {
+        let mut password_config: PasswordConfig = PasswordConfig {
+            numbers: true,
+            charset: String::from("abca0"),
+            exclude: String::from("9"),
+            ..Default::default()
+        };
+        let mut password_content: Vec<char> = create_passwd_content(&password_config, &mut WorgenXRng::new(None));
+        password_content.sort();
+        assert_eq!(String::from_iter(password_content), "012345678abc");
+
+        password_config.no_ambiguous = true;
+        let mut password_content: Vec<char> = create_passwd_content(&password_config, &mut WorgenXRng::new(None));
+        password_content.sort();
+        assert_eq!(String::from_iter(password_content), "2345678abc");
+
+        password_config.charset = String::from("O1");
+        password_config.numbers = false;
+        assert!(check_password_config(&password_config).is_err());
+
+        password_config.charset = String::from("é\u{7}");
+        assert!(check_password_config(&password_config).is_err());
+    }
+
+    #[test]
+    fn test_generate_random_passwords_with_unicode_charset() {
+        let mut password_config: PasswordConfig = PasswordConfig {
+            charset: String::from("éàüжπ🔑"),
+            exclude: String::from("π"),
+            length: 12,
+            number_of_passwords: 50,
+            ..Default::default()
+        };
+        let passwords: Vec<String> = generate_random_passwords(&password_config, &mut WorgenXRng::new(None));
+        assert_eq!(passwords.len(), 50);
+        for password in &passwords {
+            assert_eq!(password.chars().count(), 12);
+            assert!(password.chars().all(|c| "éàüж🔑".contains(c)));
+        }
+        assert!((get_entropy(&password_config) - 12.0 * 5f64.log2()).abs() < 1e-9);
+
+        password_config.numbers = true;
+        password_config.min_numbers = 11;
+        let passwords: Vec<String> = generate_random_passwords(&password_config, &mut WorgenXRng::new(None));
+        for password in &passwords {
+            assert_eq!(password.chars().count(), 12);
+            assert!(password.chars().filter(char::is_ascii_digit).count() >= 11);
+            assert!(password.chars().any(|c| "éàüж🔑".contains(c)));
+        }
+    }
+
+    #[test]
+    fn test_generate_random_passwords_with_charset() {
+        let password_config: PasswordConfig = PasswordConfig {
+            lowercase: true,
+            charset: String::from("ab*"),
+            exclude: String::from("abcdefghijklmnopqrstuvwxy"),
+            length: 3,
+            number_of_passwords: 50,
+            ..Default::default()
+        };
+        let passwords: Vec<String> = generate_random_passwords(&password_config, &mut WorgenXRng::new(None));
+
+        assert_eq!(passwords.len(), 50);
+        for password in passwords {
+            assert!(password.bytes().all(|c| c == b'z' || c == b'*'));
+            assert!(password.contains('z') && password.contains('*'));
+        }
+        // 3 positions for the single 'z' or '*' and 2^3 - 2 passwords mixing both.
+        assert!((get_entropy(&password_config) - 6f64.log2()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_generate_pronounceable_passwords() {
+        let password_config: PasswordConfig = PasswordConfig {
+            length: 7,
+            number_of_passwords: 20,
+            pronounceable: true,
+            ..Default::default()
+        };
+        let passwords: Vec<String> = generate_random_passwords(&password_config, &mut WorgenXRng::new(None));
+
+        assert_eq!(passwords.len(), 20);
+        for password in passwords {
+            assert_eq!(password.len(), 7);
+            for (idx, c) in password.bytes().enumerate() {
+                if idx % 2 == 0 {
+                    assert!(dict::CONSONANTS.contains(&c));
+                } else {
+                    assert!(dict::VOWELS.contains(&c));
+                }
+            }
+        }
+
+        // 4 consonants and 3 vowels.
+        let expected_entropy: f64 = 4.0 * 20f64.log2() + 3.0 * 5f64.log2();
+        assert!((get_entropy(&password_config) - expected_entropy).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_parse_template() {
+        assert_eq!(
+            parse_template("Cv-9\\9?d").unwrap(),
+            vec![
+                TemplateElement::Random(dict::UPPERCASE_CONSONANTS),
+                TemplateElement::Random(dict::VOWELS),
+                TemplateElement::Fixed('-'),
+                TemplateElement::Random(dict::NUMBERS),
+                TemplateElement::Fixed('9'),
+                TemplateElement::Random(dict::NUMBERS),
+            ]
+        );
+        assert_eq!(parse_template("\\?é").unwrap(), vec![TemplateElement::Fixed('?'), TemplateElement::Fixed('é')]);
+        assert_eq!(
+            parse_template("?x?").unwrap(),
+            vec![
+                TemplateElement::Random(dict::ALL_CHARACTERS),
+                TemplateElement::Fixed('x'),
+                TemplateElement::Random(dict::ALL_CHARACTERS),
+            ]
+        );
+        assert!(parse_template("abc\\").is_err());
+    }
+
+    #[test]
+    fn test_parse_template_with_fixed_text() {
+        // The letters c, v, C, V and the characters 9, ! of a fixed text are random characters unless they are escaped.
+        let template: Vec<TemplateElement> = parse_template("offi\\ce-\\9\\9\\!-\\C\\V").unwrap();
+        assert!(template.iter().all(|element| matches!(element, TemplateElement::Fixed(_))));
+        assert_eq!(generate_password_from_template(&mut WorgenXRng::new(None), &template), "office-99!-CV");
+
+        let template: Vec<TemplateElement> = parse_template("office-9999").unwrap();
+        assert_eq!(template[4], TemplateElement::Random(dict::CONSONANTS));
+        assert_eq!(template.iter().filter(|element| matches!(element, TemplateElement::Fixed(_))).count(), 6);
+    }
+
+    #[test]
+    fn test_generate_passwords_from_template() {
+        let password_config: PasswordConfig = PasswordConfig {
+            number_of_passwords: 20,
+            template: String::from("Cvccvc-99-!!"),
+            ..Default::default()
+        };
+        let passwords: Vec<String> = generate_random_passwords(&password_config, &mut WorgenXRng::new(None));
+
+        assert_eq!(passwords.len(), 20);
+        assert_eq!(get_password_length_range(&password_config), (12, 12));
+        for password in passwords {
+            let password: &[u8] = password.as_bytes();
+            assert_eq!(password.len(), 12);
+            assert!(dict::UPPERCASE_CONSONANTS.contains(&password[0]));
+            assert!(dict::VOWELS.contains(&password[1]));
+            assert_eq!(password[6], b'-');
+            assert!(dict::NUMBERS.contains(&password[8]));
+            assert!(dict::SPECIAL_CHARACTERS.contains(&password[11]));
+        }
+
+        let expected_entropy: f64 = 4.0 * 20f64.log2() + 2.0 * 5f64.log2() + 2.0 * 10f64.log2() + 2.0 * 29f64.log2();
+        assert!((get_entropy(&password_config) - expected_entropy).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_all_characters() {
+        let mut all_characters: Vec<u8> = [dict::UPPERCASE, dict::LOWERCASE, dict::NUMBERS, dict::SPECIAL_CHARACTERS].concat();
+        let mut expected: Vec<u8> = dict::ALL_CHARACTERS.to_vec();
+        all_characters.sort();
+        expected.sort();
+        assert_eq!(all_characters, expected);
+    }
+
+    #[test]
+    fn test_builtin_charsets() {
+        for (name, characters, expected_size) in [("hex", dict::HEX_LOWERCASE, 16), ("base64", dict::BASE64, 64), ("base58", dict::BASE58, 58)] {
+            assert_eq!(characters.iter().collect::<HashSet<&u8>>().len(), expected_size, "{}", name);
+        }
+        assert!(!dict::BASE58.iter().any(|c| b"0OIl".contains(c)));
+
+        let password_config: PasswordConfig = PasswordConfig {
+            charset: String::from_utf8(dict::HEX_UPPERCASE.to_vec()).unwrap(),
+            length: 32,
+            number_of_passwords: 20,
+            ..Default::default()
+        };
+        for password in generate_random_passwords(&password_config, &mut WorgenXRng::new(None)) {
+            assert!(password.bytes().all(|c| c.is_ascii_hexdigit() && !c.is_ascii_lowercase()));
+        }
+        assert_eq!(get_entropy(&password_config), 128.0);
+    }
+
+    #[test]
+    fn test_generate_random_passwords_with_seed() {
+        let password_config: PasswordConfig = PasswordConfig {
+            numbers: true,
+            lowercase: true,
+            length: 12,
+            number_of_passwords: 2,
+            ..Default::default()
+        };
+        let passwords: Vec<String> = generate_random_passwords(&password_config, &mut WorgenXRng::new(Some(42)));
+        assert_eq!(passwords, vec!["17k0de6dzyui", "rv08qyr163tj"]);
+
+        let password_config: PasswordConfig = PasswordConfig {
+            number_of_passwords: 1,
+            template: String::from("Cvccvc-99"),
+            ..Default::default()
+        };
+        let passwords: Vec<String> = generate_random_passwords(&password_config, &mut WorgenXRng::new(Some(42)));
+        assert_eq!(passwords, vec!["Viqlac-71"]);
+    }
+
+    #[test]
+    fn test_generate_passwords_in_batches() {
+        let password_config: PasswordConfig = PasswordConfig {
+            numbers: true,
+            lowercase: true,
+            length: 12,
+            number_of_passwords: 2 * PASSWORD_BATCH_SIZE + 3,
+            ..Default::default()
+        };
+        let batch_sizes: Mutex<Vec<usize>> = Mutex::new(Vec::new());
+        generate_passwords_in_batches(&password_config, 4, None, |batch| {
+            assert!(batch.iter().all(|password| password.len() == 12));
+            batch_sizes.lock().unwrap().push(batch.len());
+            Ok(())
+        })
+        .unwrap();
+
+        let batch_sizes: Vec<usize> = batch_sizes.into_inner().unwrap();
+        assert_eq!(batch_sizes.len(), 3);
+        assert_eq!(batch_sizes.iter().sum::<usize>(), 2 * PASSWORD_BATCH_SIZE as usize + 3);
+    }
+
+    #[test]
+    fn test_generate_passwords_in_batches_with_seed() {
+        let password_config: PasswordConfig = PasswordConfig {
+            numbers: true,
+            lowercase: true,
+            length: 12,
+            number_of_passwords: 2,
+            ..Default::default()
+        };
+        let passwords: Mutex<Vec<String>> = Mutex::new(Vec::new());
+        generate_passwords_in_batches(&password_config, 4, Some(42), |batch| {
+            passwords.lock().unwrap().extend(batch);
+            Ok(())
+        })
+        .unwrap();
+
+        assert_eq!(passwords.into_inner().unwrap(), generate_random_passwords(&password_config, &mut WorgenXRng::new(Some(42))));
+    }
+
+    #[test]
+    fn test_generate_passwords_in_batches_with_error() {
+        let password_config: PasswordConfig = PasswordConfig {
+            numbers: true,
+            length: 4,
+            number_of_passwords: 10 * PASSWORD_BATCH_SIZE,
+            ..Default::default()
+        };
+        let result: Result<(), WorgenXError> = generate_passwords_in_batches(&password_config, 2, None, |_| {
+            Err(WorgenXError::SystemError(SystemError::UnableToWriteToFile("test".to_string(), "test".to_string())))
+        });
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_generate_unique_passwords() {
+        // Small keyspace: 10^4 PINs, drawn from a random permutation.
+        let mut password_config: PasswordConfig = PasswordConfig {
+            numbers: true,
+            length: 4,
+            number_of_passwords: 10_000,
+            unique: true,
+            ..Default::default()
+        };
+        let passwords: Vec<String> = generate_random_passwords(&password_config, &mut WorgenXRng::new(None));
+        assert_eq!(passwords.len(), 10_000);
+        assert_eq!(passwords.iter().collect::<HashSet<&String>>().len(), 10_000);
+
+        password_config.number_of_passwords = 10_001;
+        assert!(check_password_config(&password_config).is_err());
+
+        // Keyspace with minimums: 3 positions for the single number, 10 numbers and 26 * 26 lowercase letters.
+        password_config.lowercase = true;
+        password_config.length = 3;
+        password_config.min_lowercase = 2;
+        password_config.number_of_passwords = 3 * 10 * 26 * 26;
+        let passwords: Vec<String> = generate_random_passwords(&password_config, &mut WorgenXRng::new(None));
+        assert_eq!(passwords.iter().collect::<HashSet<&String>>().len(), 3 * 10 * 26 * 26);
+        assert!(passwords.iter().all(|password| password.bytes().filter(u8::is_ascii_digit).count() == 1));
+    }
+
+    #[test]
+    fn test_generate_unique_passwords_in_batches() {
+        let password_config: PasswordConfig = PasswordConfig {
+            lowercase: true,
+            length: 4,
+            number_of_passwords: 2 * PASSWORD_BATCH_SIZE + 3,
+            unique: true,
+            ..Default::default()
+        };
+        let passwords: Mutex<Vec<String>> = Mutex::new(Vec::new());
+        generate_passwords_in_batches(&password_config, 4, None, |batch| {
+            passwords.lock().unwrap().extend(batch);
+            Ok(())
+        })
+        .unwrap();
+
+        let passwords: Vec<String> = passwords.into_inner().unwrap();
+        assert_eq!(passwords.len(), 2 * PASSWORD_BATCH_SIZE as usize + 3);
+        assert_eq!(passwords.iter().collect::<HashSet<&String>>().len(), passwords.len());
+    }
+
+    #[test]
+    fn test_generate_random_passwords_with_policy_rules() {
+        let mut password_config: PasswordConfig = PasswordConfig {
+            numbers: true,
+            length: 6,
+            number_of_passwords: 200,
+            max_repeated: 1,
+            forbidden_sequences: vec!["12".to_string(), "99".to_string()],
+            ..Default::default()
+        };
+        let passwords: Vec<String> = generate_random_passwords(&password_config, &mut WorgenXRng::new(None));
+        assert_eq!(passwords.len(), 200);
+        for password in passwords {
+            assert!(!password.contains("12"));
+            assert!(password.as_bytes().windows(2).all(|pair| pair[0] != pair[1]));
+        }
+
+        // Case insensitive sequences.
+        password_config.numbers = false;
+        password_config.charset = "aB".to_string();
+        password_config.max_repeated = 0;
+        password_config.forbidden_sequences = vec!["ab".to_string()];
+        let passwords: Vec<String> = generate_random_passwords(&password_config, &mut WorgenXRng::new(None));
+        assert!(passwords.iter().all(|password| !password.contains("aB")));
+
+        password_config.forbidden_sequences = vec!["a".to_string()];
+        assert!(check_password_config(&password_config).is_err());
+        password_config.charset = "a".to_string();
+        password_config.forbidden_sequences = Vec::new();
+        password_config.max_repeated = 2;
+        assert!(check_password_config(&password_config).is_err());
+        password_config.template = "?d?d".to_string();
+        assert!(check_password_config(&password_config).is_err());
+    }
+
+    #[test]
+    fn test_unsatisfiable_policy_rules() {
+        // "aba" and "bab" contain "ab" or "ba", "aab"-like passwords repeat a character.
+        let mut password_config: PasswordConfig = PasswordConfig {
+            charset: "ab".to_string(),
+            length: 3,
+            number_of_passwords: 1,
+            max_repeated: 1,
+            forbidden_sequences: vec!["ab".to_string(), "ba".to_string()],
+            ..Default::default()
+        };
+        assert!(PasswordGenerator::new(&password_config, &mut WorgenXRng::new(None)).is_err());
+        assert!(generate_random_passwords(&password_config, &mut WorgenXRng::new(None)).is_empty());
+        assert!(generate_passwords_in_batches(&password_config, 2, None, |_| Ok(())).is_err());
+        assert_eq!(get_entropy(&password_config), 0.0);
+
+        // A large keyspace isn't enumerated, the generation gives up after POLICY_MAX_ATTEMPTS rejections.
+        password_config.max_length = 17;
+        let mut generator: PasswordGenerator = PasswordGenerator::new(&password_config, &mut WorgenXRng::new(None)).unwrap();
+        assert!(generator.generate(&mut WorgenXRng::new(None)).is_err());
+    }
+
+    #[test]
+    fn test_get_entropy_with_policy_rules() {
+        // 10 * 9 PINs of 2 different numbers.
+        let mut password_config: PasswordConfig = PasswordConfig {
+            numbers: true,
+            length: 2,
+            max_repeated: 1,
+            ..Default::default()
+        };
+        assert!((get_entropy(&password_config) - 90f64.log2()).abs() < 1e-9);
+
+        // Estimated share of the passwords without two identical consecutive numbers: (9/10)^11.
+        password_config.length = 12;
+        let expected_entropy: f64 = 12.0 * 10f64.log2() + 11.0 * 0.9f64.log2();
+        assert!((get_entropy(&password_config) - expected_entropy).abs() < 0.1);
+        assert_eq!(get_entropy(&password_config), get_entropy(&password_config));
+    }
+
+    #[test]
+    fn test_generate_unique_passwords_with_policy_rules() {
+        // 10 * 9 PINs of 2 different numbers.
+        let mut password_config: PasswordConfig = PasswordConfig {
+            numbers: true,
+            length: 2,
+            number_of_passwords: 90,
+            unique: true,
+            max_repeated: 1,
+            ..Default::default()
+        };
+        let passwords: Vec<String> = generate_random_passwords(&password_config, &mut WorgenXRng::new(None));
+        assert_eq!(passwords.iter().collect::<HashSet<&String>>().len(), 90);
+
+        password_config.number_of_passwords = 91;
+        assert!(generate_passwords_in_batches(&password_config, 1, None, |_| Ok(())).is_err());
+
+        // 10 PINs of 1 number and 90 PINs of 2 different numbers, the keyspace of the range is 110.
+        password_config.length = 1;
+        password_config.max_length = 2;
+        password_config.number_of_passwords = 100;
+        let passwords: Vec<String> = generate_random_passwords(&password_config, &mut WorgenXRng::new(None));
+        assert_eq!(passwords.iter().collect::<HashSet<&String>>().len(), 100);
+
+        password_config.number_of_passwords = 101;
+        assert!(matches!(
+            PasswordGenerator::new(&password_config, &mut WorgenXRng::new(None)),
+            Err(WorgenXError::ArgError(ArgError::NotEnoughUniquePasswords(101, _)))
+        ));
+        assert!(generate_passwords_in_batches(&password_config, 2, None, |_| Ok(())).is_err());
+    }
+
+    #[test]
+    fn test_unique_filter_gives_up() {
+        let password_config: PasswordConfig = PasswordConfig {
+            numbers: true,
+            length: 1,
+            ..Default::default()
+        };
+        let mut rng: WorgenXRng = WorgenXRng::new(None);
+        let mut generator: PasswordGenerator = PasswordGenerator::new(&password_config, &mut rng).unwrap();
+        let unique_filter: UniqueFilter = UniqueFilter::new(11);
+        assert_eq!(unique_filter.draw_batch(&mut generator, &mut rng, 10).unwrap().len(), 10);
+        assert!(unique_filter.draw_batch(&mut generator, &mut rng, 1).is_err());
+    }
+
+    #[test]
+    fn test_generate_random_passwords_with_length_range() {
+        let mut password_config: PasswordConfig = PasswordConfig {
+            lowercase: true,
+            numbers: true,
+            length: 3,
+            max_length: 5,
+            number_of_passwords: 300,
+            min_numbers: 2,
+            ..Default::default()
+        };
+        let passwords: Vec<String> = generate_random_passwords(&password_config, &mut WorgenXRng::new(None));
+        assert_eq!(passwords.len(), 300);
+        for length in 3..=5 {
+            assert!(passwords.iter().any(|password| password.len() == length));
+        }
+        for password in &passwords {
+            assert!((3..=5).contains(&password.len()));
+            assert!(password.bytes().filter(u8::is_ascii_digit).count() >= 2);
+        }
+
+        // Unique passwords of 1 or 2 numbers.
+        password_config.lowercase = false;
+        password_config.min_numbers = 0;
+        password_config.length = 1;
+        password_config.max_length = 2;
+        password_config.number_of_passwords = 110;
+        password_config.unique = true;
+        let passwords: Vec<String> = generate_random_passwords(&password_config, &mut WorgenXRng::new(None));
+        assert_eq!(passwords.iter().collect::<HashSet<&String>>().len(), 110);
+        password_config.number_of_passwords = 111;
+        assert!(check_password_config(&password_config).is_err());
+
+        password_config.max_length = 0;
+        assert_eq!(get_password_length_range(&password_config), (1, 1));
+        password_config.length = 3;
+        password_config.max_length = 2;
+        assert!(check_password_config(&password_config).is_err());
+    }
+
+    #[test]
+    fn test_get_entropy_with_length_range() {
+        let mut password_config: PasswordConfig = PasswordConfig {
+            lowercase: true,
+            length: 8,
+            max_length: 10,
+            class_coverage: false,
+            ..Default::default()
+        };
+        assert!((get_entropy(&password_config) - (8.0 * 26f64.log2() + 3f64.log2())).abs() < 1e-9);
+
+        password_config.max_length = 8;
+        assert!((get_entropy(&password_config) - 8.0 * 26f64.log2()).abs() < 1e-9);
+
+        // The keyspace counts all the lengths: 10 + 100 + 1000 PINs.
+        password_config.lowercase = false;
+        password_config.numbers = true;
+        password_config.length = 1;
+        password_config.max_length = 3;
+        assert!((get_log2_nb_of_passwords(&password_config, 1..=3) - 1110f64.log2()).abs() < 1e-9);
+        password_config.min_numbers = 1;
+        assert!((get_log2_nb_of_passwords(&password_config, 1..=3) - 1110f64.log2()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_parse_length_range() {
+        assert_eq!(parse_length_range("16").unwrap(), (16, 16));
+        assert_eq!(parse_length_range("12-20").unwrap(), (12, 20));
+        assert_eq!(parse_length_range("12-12").unwrap(), (12, 12));
+        assert!(parse_length_range("20-12").is_err());
+        assert!(parse_length_range("0-12").is_err());
+        assert!(parse_length_range("12-").is_err());
+        assert!(parse_length_range("a").is_err());
     }
 }
@@ -0,0 +1,210 @@
+// Internal crates.
+use worgen_x::{
+    error::{ArgError, SystemError, WorgenXError},
+    password::{self, PasswordConfig},
+};
+
+// External crates.
+use serde::Deserialize;
+use std::{fs, path::Path};
+
+/// This struct represents a password policy read from a TOML or JSON file.
+/// The passwords are `length` characters long if it's set, between `min_length` and `max_length` characters long otherwise,
+/// and a size or a range of sizes given on the command line must be in the `min_length`-`max_length` range.
+/// `allowed_characters` and `forbidden_characters` are added to and removed from the alphabet of the selected types of characters.
+/// Alone, `allowed_characters` is the whole alphabet of the passwords.
+///
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PasswordPolicy {
+    pub length: Option<u32>,
+    pub min_length: Option<u32>,
+    pub max_length: Option<u32>,
+    pub lowercase: bool,
+    pub uppercase: bool,
+    pub numbers: bool,
+    pub special_characters: bool,
+    pub min_lowercase: u32,
+    pub min_uppercase: u32,
+    pub min_numbers: u32,
+    pub min_special_characters: u32,
+    pub class_coverage: Option<bool>,
+    pub allowed_characters: String,
+    pub forbidden_characters: String,
+    pub no_ambiguous: bool,
+    pub max_repeated: u32,
+    pub forbidden_sequences: Vec<String>,
+}
+
+/// This function is responsible for reading a password policy from a file.
+/// The file is parsed as JSON if its extension is `.json`, as TOML otherwise.
+///
+/// # Arguments
+///
+/// * `path` - The path of the policy file.
+///
+/// # Returns
+///
+/// The password policy, WorgenXError if the file can't be read or contains an invalid or unknown field.
+///
+pub fn load_policy(path: &str) -> Result<PasswordPolicy, WorgenXError> {
+    let content: String = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) => {
+            return Err(WorgenXError::SystemError(SystemError::UnableToReadFile(
+                path.to_string(),
+                e.to_string(),
+            )))
+        }
+    };
+
+    let is_json: bool = Path::new(path).extension().is_some_and(|extension| extension.eq_ignore_ascii_case("json"));
+    let policy: Result<PasswordPolicy, String> = if is_json {
+        serde_json::from_str(&content).map_err(|e| e.to_string())
+    } else {
+        toml::from_str(&content).map_err(|e| e.to_string())
+    };
+
+    policy.map_err(|e| WorgenXError::ArgError(ArgError::InvalidPolicy(format!("unable to parse {}: {}", path, e.trim_end()))))
+}
+
+/// This function is responsible for building the password config of a policy.
+/// The config is checked, so the generated passwords always meet the policy.
+///
+/// # Arguments
+///
+/// * `policy` - The password policy.
+/// * `size` - The minimum and maximum size of the passwords given on the command line, if any.
+/// * `number_of_passwords` - The number of passwords to generate.
+///
+/// # Returns
+///
+/// The password config, WorgenXError if the policy is inconsistent or the size is out of its range.
+///
+pub fn policy_to_password_config(policy: PasswordPolicy, size: Option<(u32, u32)>, number_of_passwords: u64) -> Result<PasswordConfig, WorgenXError> {
+    let invalid_policy = |message: String| WorgenXError::ArgError(ArgError::InvalidPolicy(message));
+
+    let min_length: u32 = policy.min_length.unwrap_or(1);
+    let max_length: u32 = policy.max_length.unwrap_or(u32::MAX);
+    if min_length == 0 {
+        return Err(invalid_policy("min_length must be greater than 0".to_string()));
+    }
+    if min_length > max_length {
+        return Err(invalid_policy(format!("min_length ({}) is greater than max_length ({})", min_length, max_length)));
+    }
+
+    let (length, max_password_length): (u32, u32) = match size {
+        Some(range) => range,
+        None => match (policy.length, policy.min_length) {
+            (Some(length), _) => (length, length),
+            (None, Some(min_length)) => (min_length, policy.max_length.unwrap_or(min_length)),
+            (None, None) => return Err(invalid_policy("the length of the passwords is missing, set length or min_length".to_string())),
+        },
+    };
+    if length < min_length || max_password_length > max_length {
+        return Err(invalid_policy(format!(
+            "the size of the passwords ({}) is out of the range of the policy ({}-{})",
+            if length == max_password_length { length.to_string() } else { format!("{}-{}", length, max_password_length) },
+            min_length,
+            policy.max_length.map_or(String::new(), |max_length| max_length.to_string())
+        )));
+    }
+
+    if !policy.lowercase && !policy.uppercase && !policy.numbers && !policy.special_characters && policy.allowed_characters.is_empty() {
+        return Err(invalid_policy("no type of characters is allowed".to_string()));
+    }
+
+    let password_config: PasswordConfig = PasswordConfig {
+        numbers: policy.numbers,
+        special_characters: policy.special_characters,
+        uppercase: policy.uppercase,
+        lowercase: policy.lowercase,
+        length,
+        max_length: max_password_length,
+        number_of_passwords,
+        class_coverage: policy.class_coverage.unwrap_or(true),
+        min_numbers: policy.min_numbers,
+        min_special_characters: policy.min_special_characters,
+        min_uppercase: policy.min_uppercase,
+        min_lowercase: policy.min_lowercase,
+        charset: policy.allowed_characters,
+        exclude: policy.forbidden_characters,
+        no_ambiguous: policy.no_ambiguous,
+        max_repeated: policy.max_repeated,
+        forbidden_sequences: policy.forbidden_sequences,
+        ..Default::default()
+    };
+    password::check_password_config(&password_config)?;
+
+    Ok(password_config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use worgen_x::rng::WorgenXRng;
+
+    #[test]
+    fn test_load_policy() {
+        let toml_path: String = format!("{}/worgenx_policy_test.toml", std::env::temp_dir().display());
+        fs::write(
+            &toml_path,
+            "min_length = 12\nmax_length = 64\nlowercase = true\nnumbers = true\nmin_numbers = 2\nmax_repeated = 2\nforbidden_sequences = [\"123\", \"password\"]\n",
+        )
+        .unwrap();
+        let policy: PasswordPolicy = load_policy(&toml_path).unwrap();
+        assert_eq!(policy.min_length, Some(12));
+        assert_eq!(policy.min_numbers, 2);
+        assert_eq!(policy.forbidden_sequences, vec!["123".to_string(), "password".to_string()]);
+
+        let json_path: String = format!("{}/worgenx_policy_test.json", std::env::temp_dir().display());
+        fs::write(&json_path, r#"{"length": 16, "uppercase": true, "forbidden_characters": "O"}"#).unwrap();
+        let policy: PasswordPolicy = load_policy(&json_path).unwrap();
+        assert_eq!(policy.length, Some(16));
+        assert_eq!(policy.forbidden_characters, "O");
+
+        fs::write(&toml_path, "lenght = 12\n").unwrap();
+        assert!(load_policy(&toml_path).is_err());
+        assert!(load_policy("this_file_does_not_exist.toml").is_err());
+
+        fs::remove_file(&toml_path).unwrap();
+        fs::remove_file(&json_path).unwrap();
+    }
+
+    #[test]
+    fn test_policy_to_password_config() {
+        let policy = || PasswordPolicy {
+            min_length: Some(12),
+            max_length: Some(20),
+            lowercase: true,
+            numbers: true,
+            min_numbers: 3,
+            forbidden_characters: "0".to_string(),
+            max_repeated: 1,
+            forbidden_sequences: vec!["abc".to_string()],
+            ..Default::default()
+        };
+
+        let password_config: PasswordConfig = policy_to_password_config(policy(), None, 50).unwrap();
+        assert_eq!(password::get_password_length_range(&password_config), (12, 20));
+        let passwords: Vec<String> = password::generate_random_passwords(&password_config, &mut WorgenXRng::new(None));
+        assert_eq!(passwords.len(), 50);
+        for password in passwords {
+            assert!((12..=20).contains(&password.len()));
+            assert!(password.bytes().filter(u8::is_ascii_digit).count() >= 3);
+            assert!(!password.contains('0') && !password.contains("abc"));
+            assert!(password.as_bytes().windows(2).all(|pair| pair[0] != pair[1]));
+        }
+
+        let password_config: PasswordConfig = policy_to_password_config(policy(), Some((16, 16)), 1).unwrap();
+        assert_eq!(password::get_password_length_range(&password_config), (16, 16));
+        let password_config: PasswordConfig = policy_to_password_config(PasswordPolicy { length: Some(14), ..policy() }, None, 1).unwrap();
+        assert_eq!(password::get_password_length_range(&password_config), (14, 14));
+        assert!(policy_to_password_config(policy(), Some((8, 8)), 1).is_err());
+        assert!(policy_to_password_config(policy(), Some((16, 24)), 1).is_err());
+        assert!(policy_to_password_config(PasswordPolicy { min_length: Some(30), ..policy() }, None, 1).is_err());
+        assert!(policy_to_password_config(PasswordPolicy { min_numbers: 13, ..policy() }, None, 1).is_err());
+        assert!(policy_to_password_config(PasswordPolicy { lowercase: false, numbers: false, ..policy() }, None, 1).is_err());
+        assert!(policy_to_password_config(PasswordPolicy { min_length: None, max_length: None, ..policy() }, None, 1).is_err());
+    }
+}
@@ -0,0 +1,204 @@
+// Internal crates.
+use crate::system;
+
+// External crates.
+use indicatif::{HumanBytes, HumanCount, HumanDuration, ProgressBar};
+use std::{
+    sync::atomic::{AtomicBool, AtomicU64, Ordering},
+    time::{Duration, Instant},
+};
+
+/// This enum defines what the progress is measured against.
+///
+/// * `Passwords` - The total number of passwords to generate (wordlist generation).
+/// * `Duration` - The duration of the task (CPU benchmark).
+///
+#[derive(Debug, Clone, Copy)]
+pub enum ProgressGoal {
+    Passwords(u64),
+    Duration(Duration),
+}
+
+/// This struct is responsible for tracking and displaying the progress of a task shared between several threads.
+/// The workers update the counters in batches with relaxed atomics, the progress bar is refreshed on each update.
+/// The progress bar displays the percentage, the throughput, the number of bytes written and the ETA.
+///
+#[derive(Debug)]
+pub struct ProgressReporter {
+    pb: ProgressBar,
+    goal: ProgressGoal,
+    start: Instant,
+    nb_of_passwords: AtomicU64,
+    nb_of_bytes: AtomicU64,
+}
+
+impl ProgressReporter {
+    /// This function is responsible for creating a new progress reporter.
+    ///
+    /// # Arguments
+    ///
+    /// * `goal` - What the progress is measured against.
+    /// * `hidden` - A boolean to specify if the progress bar should be hidden or not.
+    ///
+    /// # Returns
+    ///
+    /// The ProgressReporter struct.
+    ///
+    pub fn new(goal: ProgressGoal, hidden: bool) -> Self {
+        let pb: ProgressBar = if hidden {
+            ProgressBar::hidden()
+        } else {
+            system::get_progress_bar()
+        };
+
+        ProgressReporter {
+            pb,
+            goal,
+            start: Instant::now(),
+            nb_of_passwords: AtomicU64::new(0),
+            nb_of_bytes: AtomicU64::new(0),
+        }
+    }
+
+    /// This function is responsible for adding a batch of generated passwords to the counters and refreshing the progress bar.
+    ///
+    /// # Arguments
+    ///
+    /// * `nb_of_passwords` - The number of passwords generated since the last update.
+    /// * `nb_of_bytes` - The number of bytes written since the last update.
+    ///
+    pub fn add(&self, nb_of_passwords: u64, nb_of_bytes: u64) {
+        let total_passwords: u64 = self.nb_of_passwords.fetch_add(nb_of_passwords, Ordering::Relaxed) + nb_of_passwords;
+        let total_bytes: u64 = self.nb_of_bytes.fetch_add(nb_of_bytes, Ordering::Relaxed) + nb_of_bytes;
+        if !self.pb.is_hidden() {
+            self.refresh(total_passwords, total_bytes);
+        }
+    }
+
+    /// This function is responsible for returning the number of passwords generated so far.
+    ///
+    /// # Returns
+    ///
+    /// The number of passwords generated.
+    ///
+    pub fn get_nb_of_passwords(&self) -> u64 {
+        self.nb_of_passwords.load(Ordering::Relaxed)
+    }
+
+    /// This function is responsible for completing the progress bar with a final message.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - The message to display at the end of the progress bar.
+    ///
+    pub fn finish(&self, message: &str) {
+        self.pb.set_position(100);
+        self.pb.finish_with_message(message.to_string());
+    }
+
+    /// This function is responsible for updating the position and the message of the progress bar.
+    ///
+    /// # Arguments
+    ///
+    /// * `nb_of_passwords` - The total number of passwords generated.
+    /// * `nb_of_bytes` - The total number of bytes written.
+    ///
+    fn refresh(&self, nb_of_passwords: u64, nb_of_bytes: u64) {
+        let elapsed: Duration = self.start.elapsed();
+        let (ratio, eta): (f64, Duration) = match self.goal {
+            ProgressGoal::Passwords(total) => {
+                let ratio: f64 = if total == 0 { 1.0 } else { nb_of_passwords as f64 / total as f64 };
+                let eta: Duration = if nb_of_passwords == 0 {
+                    Duration::ZERO
+                } else {
+                    elapsed.mul_f64(total.saturating_sub(nb_of_passwords) as f64 / nb_of_passwords as f64)
+                };
+                (ratio, eta)
+            }
+            ProgressGoal::Duration(duration) => (
+                elapsed.as_secs_f64() / duration.as_secs_f64(),
+                duration.saturating_sub(elapsed),
+            ),
+        };
+
+        let throughput: u64 = (nb_of_passwords as f64 / elapsed.as_secs_f64().max(0.001)) as u64;
+        let mut message: String = format!("{} passwords/s", HumanCount(throughput));
+        if nb_of_bytes > 0 {
+            message.push_str(&format!(" | {} written", HumanBytes(nb_of_bytes)));
+        }
+        message.push_str(&format!(" | ETA {}", HumanDuration(eta)));
+
+        self.pb.set_position(((ratio * 100.0) as u64).clamp(1, 99));
+        self.pb.set_message(message);
+    }
+}
+
+/// This struct holds the state of a single run (wordlist generation or benchmark) shared between its threads.
+/// Each run owns its own context, so several runs can be executed one after the other or concurrently in the same process.
+///
+#[derive(Debug)]
+pub struct RunContext {
+    pub progress: ProgressReporter,
+    stop_signal: AtomicBool,
+}
+
+impl RunContext {
+    /// This function is responsible for creating the context of a new run.
+    ///
+    /// # Arguments
+    ///
+    /// * `goal` - What the progress of the run is measured against.
+    /// * `hidden` - A boolean to specify if the progress bar should be hidden or not.
+    ///
+    /// # Returns
+    ///
+    /// The RunContext struct.
+    ///
+    pub fn new(goal: ProgressGoal, hidden: bool) -> Self {
+        RunContext {
+            progress: ProgressReporter::new(goal, hidden),
+            stop_signal: AtomicBool::new(false),
+        }
+    }
+
+    /// This function is responsible for asking all the threads of the run to stop.
+    ///
+    pub fn stop(&self) {
+        self.stop_signal.store(true, Ordering::Relaxed);
+    }
+
+    /// This function is responsible for checking if the run has been asked to stop.
+    ///
+    /// # Returns
+    ///
+    /// True if the threads must stop, false otherwise.
+    ///
+    pub fn is_stopped(&self) -> bool {
+        self.stop_signal.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress_reporter_counters() {
+        let reporter: ProgressReporter = ProgressReporter::new(ProgressGoal::Passwords(100), true);
+        reporter.add(10, 50);
+        reporter.add(5, 25);
+
+        assert_eq!(reporter.get_nb_of_passwords(), 15);
+        assert_eq!(reporter.nb_of_bytes.load(Ordering::Relaxed), 75);
+    }
+
+    #[test]
+    fn test_run_context_stop() {
+        let context: RunContext = RunContext::new(ProgressGoal::Duration(Duration::from_secs(1)), true);
+        assert!(!context.is_stopped());
+
+        context.stop();
+        assert!(context.is_stopped());
+        assert_eq!(context.progress.get_nb_of_passwords(), 0);
+    }
+}
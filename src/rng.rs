@@ -0,0 +1,127 @@
+// External crates.
+use rand::{
+    rand_core::block::{BlockRng, BlockRngCore, CryptoBlockRng},
+    rngs::OsRng,
+    CryptoRng, RngCore, SeedableRng, TryRngCore,
+};
+use rand_chacha::ChaCha20Rng;
+
+/// This constant is used to set the number of u32 read from the operating system at once.
+/// Reading a block instead of a single value avoids a system call for each random character.
+///
+const OS_BLOCK_SIZE: usize = 32;
+
+/// This struct reads blocks of random values from the CSPRNG of the operating system.
+///
+#[derive(Debug)]
+pub struct OsBlockCore;
+
+impl BlockRngCore for OsBlockCore {
+    type Item = u32;
+    type Results = [u32; OS_BLOCK_SIZE];
+
+    fn generate(&mut self, results: &mut Self::Results) {
+        let mut bytes: [u8; OS_BLOCK_SIZE * 4] = [0; OS_BLOCK_SIZE * 4];
+        // Like OsRng used as an RngCore, there isn't any way to generate a password if the operating system fails.
+        if let Err(e) = OsRng.try_fill_bytes(&mut bytes) {
+            panic!("Unable to read random data from the operating system: {}", e);
+        }
+        for (result, chunk) in results.iter_mut().zip(bytes.chunks_exact(4)) {
+            *result = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        }
+    }
+}
+
+impl CryptoBlockRng for OsBlockCore {}
+
+/// This enum is the random number generator used to generate the passwords and the passphrases.
+///
+/// * `Os` - The CSPRNG of the operating system, used by default.
+/// * `Seeded` - A ChaCha20 generator seeded by the user. The output is reproducible, so it must only be used for tests and demos.
+///
+#[derive(Debug)]
+pub enum WorgenXRng {
+    Os(BlockRng<OsBlockCore>),
+    Seeded(ChaCha20Rng),
+}
+
+impl WorgenXRng {
+    /// This function is responsible for creating the random number generator.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The seed given by the user, None to use the CSPRNG of the operating system.
+    ///
+    /// # Returns
+    ///
+    /// The WorgenXRng enum.
+    ///
+    pub fn new(seed: Option<u64>) -> Self {
+        match seed {
+            Some(seed) => WorgenXRng::Seeded(ChaCha20Rng::seed_from_u64(seed)),
+            None => WorgenXRng::Os(BlockRng::new(OsBlockCore)),
+        }
+    }
+
+    /// This function is responsible for checking if the output of the generator is reproducible.
+    ///
+    /// # Returns
+    ///
+    /// True if the generator is seeded (insecure), false otherwise.
+    ///
+    pub fn is_seeded(&self) -> bool {
+        matches!(self, WorgenXRng::Seeded(_))
+    }
+}
+
+impl RngCore for WorgenXRng {
+    fn next_u32(&mut self) -> u32 {
+        match self {
+            WorgenXRng::Os(rng) => rng.next_u32(),
+            WorgenXRng::Seeded(rng) => rng.next_u32(),
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        match self {
+            WorgenXRng::Os(rng) => rng.next_u64(),
+            WorgenXRng::Seeded(rng) => rng.next_u64(),
+        }
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        match self {
+            WorgenXRng::Os(rng) => rng.fill_bytes(dst),
+            WorgenXRng::Seeded(rng) => rng.fill_bytes(dst),
+        }
+    }
+}
+
+/// The seeded generator is a CSPRNG too, only its seed is weak.
+///
+impl CryptoRng for WorgenXRng {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seeded_rng() {
+        let mut first_rng: WorgenXRng = WorgenXRng::new(Some(42));
+        let mut second_rng: WorgenXRng = WorgenXRng::new(Some(42));
+
+        assert!(first_rng.is_seeded());
+        assert_eq!(first_rng.next_u64(), second_rng.next_u64());
+        assert_ne!(first_rng.next_u64(), WorgenXRng::new(Some(43)).next_u64());
+    }
+
+    #[test]
+    fn test_os_rng() {
+        let mut rng: WorgenXRng = WorgenXRng::new(None);
+        let mut buffer: [u8; 32] = [0; 32];
+        rng.fill_bytes(&mut buffer);
+
+        assert!(!rng.is_seeded());
+        assert_ne!(buffer, [0; 32]);
+    }
+}
@@ -0,0 +1,689 @@
+// Internal crates.
+use crate::{
+    error::{ArgError, SystemError, WorgenXError},
+    wordlist::{self, PasswordSource, PasswordStream},
+};
+
+// External crates.
+use std::{fs, str::Bytes};
+
+/// This constant is the size limit of a mutated password, like the `RP_PASSWORD_SIZE` constant of hashcat.
+/// A function which would produce a password of this size or longer leaves the password unchanged.
+///
+const MAX_PASSWORD_SIZE: usize = 256;
+
+/// This constant is the number of words of the source used to estimate the size of the mutated passwords.
+///
+const SIZE_SAMPLE: u64 = 100;
+
+/// This enum represents a function of a rule, with the syntax of hashcat and John the Ripper.
+/// The positions N and M are written 0-9 then A-Z (10-35), X and Y are any byte.
+/// As in hashcat, the functions work on bytes and only change the case of the ASCII letters,
+/// a function whose position is out of the password leaves it unchanged.
+///
+/// * `Noop` - `:` Do nothing.
+/// * `Lowercase` - `l` Lowercase all the letters.
+/// * `Uppercase` - `u` Uppercase all the letters.
+/// * `Capitalize` - `c` Uppercase the first letter and lowercase the other ones.
+/// * `InvertCapitalize` - `C` Lowercase the first letter and uppercase the other ones.
+/// * `ToggleCase` - `t` Toggle the case of all the letters.
+/// * `ToggleAt` - `TN` Toggle the case of the letter at position N.
+/// * `Reverse` - `r` Reverse the password.
+/// * `Duplicate` - `d` Duplicate the password.
+/// * `DuplicateN` - `pN` Append the password N times.
+/// * `Reflect` - `f` Append the reversed password.
+/// * `RotateLeft` - `{` Move the first character to the end.
+/// * `RotateRight` - `}` Move the last character to the front.
+/// * `Append` - `$X` Append X.
+/// * `Prepend` - `^X` Prepend X.
+/// * `DeleteFirst` - `[` Delete the first character.
+/// * `DeleteLast` - `]` Delete the last character.
+/// * `DeleteAt` - `DN` Delete the character at position N.
+/// * `Extract` - `xNM` Keep M characters from position N.
+/// * `Omit` - `ONM` Delete M characters from position N.
+/// * `Insert` - `iNX` Insert X at position N.
+/// * `Overwrite` - `oNX` Overwrite the character at position N with X.
+/// * `Truncate` - `'N` Keep the N first characters.
+/// * `Replace` - `sXY` Replace all the X with Y.
+/// * `Purge` - `@X` Delete all the X.
+/// * `DuplicateFirst` - `zN` Prepend the first character N times.
+/// * `DuplicateLast` - `ZN` Append the last character N times.
+/// * `DuplicateAll` - `q` Duplicate each character.
+/// * `SwapFront` - `k` Swap the two first characters.
+/// * `SwapBack` - `K` Swap the two last characters.
+/// * `SwapAt` - `*NM` Swap the characters at positions N and M.
+/// * `Title` - `E` Lowercase the password, then uppercase the first letter and each letter after a space.
+/// * `TitleWithSeparator` - `eX` Lowercase the password, then uppercase the first letter and each letter after X.
+/// * `ShiftLeft` - `LN` Shift the bits of the character at position N to the left.
+/// * `ShiftRight` - `RN` Shift the bits of the character at position N to the right.
+/// * `Increment` - `+N` Increment the byte at position N.
+/// * `Decrement` - `-N` Decrement the byte at position N.
+/// * `ReplaceWithNext` - `.N` Replace the character at position N with the following one.
+/// * `ReplaceWithPrevious` - `,N` Replace the character at position N with the previous one.
+/// * `DuplicateBlockFront` - `yN` Prepend the N first characters.
+/// * `DuplicateBlockBack` - `YN` Append the N last characters.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum RuleFunction {
+    Noop,
+    Lowercase,
+    Uppercase,
+    Capitalize,
+    InvertCapitalize,
+    ToggleCase,
+    ToggleAt(usize),
+    Reverse,
+    Duplicate,
+    DuplicateN(usize),
+    Reflect,
+    RotateLeft,
+    RotateRight,
+    Append(u8),
+    Prepend(u8),
+    DeleteFirst,
+    DeleteLast,
+    DeleteAt(usize),
+    Extract(usize, usize),
+    Omit(usize, usize),
+    Insert(usize, u8),
+    Overwrite(usize, u8),
+    Truncate(usize),
+    Replace(u8, u8),
+    Purge(u8),
+    DuplicateFirst(usize),
+    DuplicateLast(usize),
+    DuplicateAll,
+    SwapFront,
+    SwapBack,
+    SwapAt(usize, usize),
+    Title,
+    TitleWithSeparator(u8),
+    ShiftLeft(usize),
+    ShiftRight(usize),
+    Increment(usize),
+    Decrement(usize),
+    ReplaceWithNext(usize),
+    ReplaceWithPrevious(usize),
+    DuplicateBlockFront(usize),
+    DuplicateBlockBack(usize),
+}
+
+/// This struct represents a rule, i.e. a line of a rule file: its functions are applied to the password one after the other.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rule {
+    pub functions: Vec<RuleFunction>,
+}
+
+/// This struct is a source of passwords which applies each rule to each password of another source (a mask or a list of words).
+/// The mutated passwords are ordered by password then by rule, like the `--stdout` output of hashcat.
+/// The hash algorithm and the raw separator of the wrapped source are used.
+///
+#[derive(Debug, Clone)]
+pub struct RulesConfig<S: PasswordSource> {
+    pub source: S,
+    pub rules: Vec<Rule>,
+}
+
+/// This struct is the stream of the mutated passwords of a part of a RulesConfig.
+///
+pub struct RulesStream<'a, S: PasswordSource + 'a> {
+    stream: S::Stream<'a>,
+    rules: &'a [Rule],
+    rule_idx: usize,
+    needs_word: bool,
+    word: Vec<u8>,
+    password: Vec<u8>,
+    remaining: u64,
+}
+
+impl<S: PasswordSource> PasswordStream for RulesStream<'_, S> {
+    fn next_password(&mut self) -> Option<&[u8]> {
+        if self.remaining == 0 {
+            return None;
+        }
+        if self.rule_idx == self.rules.len() {
+            self.rule_idx = 0;
+            self.needs_word = true;
+        }
+        if self.needs_word {
+            let word: &[u8] = self.stream.next_password()?;
+            self.word.clear();
+            self.word.extend_from_slice(word);
+            self.needs_word = false;
+        }
+
+        apply_rule(&self.rules[self.rule_idx], &self.word, &mut self.password);
+        self.rule_idx += 1;
+        self.remaining -= 1;
+        Some(&self.password)
+    }
+}
+
+impl<S: PasswordSource> PasswordSource for RulesConfig<S> {
+    type Stream<'a>
+        = RulesStream<'a, S>
+    where
+        S: 'a;
+
+    fn get_nb_of_passwords(&self) -> u64 {
+        self.source.get_nb_of_passwords().saturating_mul(self.rules.len() as u64)
+    }
+
+    fn get_stream(&self, offset: u64, nb_of_passwords: u64) -> RulesStream<'_, S> {
+        let nb_of_rules: u64 = (self.rules.len() as u64).max(1);
+        let rule_idx: u64 = offset % nb_of_rules;
+        RulesStream {
+            stream: self.source.get_stream(offset / nb_of_rules, (rule_idx.saturating_add(nb_of_passwords)).div_ceil(nb_of_rules)),
+            rules: &self.rules,
+            rule_idx: rule_idx as usize,
+            needs_word: true,
+            word: Vec::new(),
+            password: Vec::new(),
+            remaining: self.get_nb_of_passwords().saturating_sub(offset).min(nb_of_passwords),
+        }
+    }
+
+    fn get_password_size(&self) -> f64 {
+        let mut stream: S::Stream<'_> = self.source.get_stream(0, SIZE_SAMPLE);
+        let mut password: Vec<u8> = Vec::new();
+        let mut total_size: usize = 0;
+        let mut nb_of_passwords: usize = 0;
+        while let Some(word) = stream.next_password() {
+            for rule in &self.rules {
+                apply_rule(rule, word, &mut password);
+                total_size += wordlist::get_written_size(&password, self.source.get_raw_separator());
+                nb_of_passwords += 1;
+            }
+        }
+
+        if nb_of_passwords == 0 {
+            0.0
+        } else {
+            total_size as f64 / nb_of_passwords as f64
+        }
+    }
+
+    fn get_hash(&self) -> &str {
+        self.source.get_hash()
+    }
+
+    fn get_raw_separator(&self) -> Option<&[u8]> {
+        self.source.get_raw_separator()
+    }
+
+    fn get_part_granularity(&self, nb_of_threads: usize) -> u64 {
+        self.source.get_part_granularity(nb_of_threads).saturating_mul(self.rules.len() as u64)
+    }
+}
+
+impl RuleFunction {
+    /// This function is responsible for computing the size of a password after this function, without applying it.
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - The size of the password in bytes.
+    ///
+    /// # Returns
+    ///
+    /// The size of the password in bytes once the function is applied, the size given if the function doesn't make the password longer.
+    ///
+    fn get_grown_length(&self, len: usize) -> usize {
+        match *self {
+            RuleFunction::Duplicate | RuleFunction::Reflect | RuleFunction::DuplicateAll => len * 2,
+            RuleFunction::DuplicateN(n) => len * (n + 1),
+            RuleFunction::Append(_) | RuleFunction::Prepend(_) | RuleFunction::Insert(_, _) => len + 1,
+            RuleFunction::DuplicateFirst(n) | RuleFunction::DuplicateLast(n) | RuleFunction::DuplicateBlockFront(n) | RuleFunction::DuplicateBlockBack(n) => len + n,
+            _ => len,
+        }
+    }
+
+    /// This function is responsible for applying this function to a password.
+    ///
+    /// # Arguments
+    ///
+    /// * `password` - The password to mutate in place.
+    ///
+    fn apply(&self, password: &mut Vec<u8>) {
+        let len: usize = password.len();
+        match *self {
+            RuleFunction::Noop => (),
+            RuleFunction::Lowercase => password.make_ascii_lowercase(),
+            RuleFunction::Uppercase => password.make_ascii_uppercase(),
+            RuleFunction::Capitalize => {
+                password.make_ascii_lowercase();
+                if let Some(first) = password.first_mut() {
+                    first.make_ascii_uppercase();
+                }
+            }
+            RuleFunction::InvertCapitalize => {
+                password.make_ascii_uppercase();
+                if let Some(first) = password.first_mut() {
+                    first.make_ascii_lowercase();
+                }
+            }
+            RuleFunction::ToggleCase => password.iter_mut().for_each(toggle_case),
+            RuleFunction::ToggleAt(n) => {
+                if let Some(c) = password.get_mut(n) {
+                    toggle_case(c);
+                }
+            }
+            RuleFunction::Reverse => password.reverse(),
+            RuleFunction::Duplicate => password.extend_from_within(..),
+            RuleFunction::DuplicateN(n) => {
+                for _ in 0..n {
+                    password.extend_from_within(..len);
+                }
+            }
+            RuleFunction::Reflect => {
+                for idx in (0..len).rev() {
+                    password.push(password[idx]);
+                }
+            }
+            RuleFunction::RotateLeft if len > 0 => password.rotate_left(1),
+            RuleFunction::RotateRight if len > 0 => password.rotate_right(1),
+            RuleFunction::Append(c) => password.push(c),
+            RuleFunction::Prepend(c) => password.insert(0, c),
+            RuleFunction::DeleteFirst if len > 0 => {
+                password.remove(0);
+            }
+            RuleFunction::DeleteLast => {
+                password.pop();
+            }
+            RuleFunction::DeleteAt(n) if n < len => {
+                password.remove(n);
+            }
+            RuleFunction::Extract(n, m) if n < len && n + m <= len => {
+                password.truncate(n + m);
+                password.drain(..n);
+            }
+            RuleFunction::Omit(n, m) if n < len && n + m <= len => {
+                password.drain(n..n + m);
+            }
+            RuleFunction::Insert(n, c) if n <= len => password.insert(n, c),
+            RuleFunction::Overwrite(n, c) if n < len => password[n] = c,
+            RuleFunction::Truncate(n) => password.truncate(n),
+            RuleFunction::Replace(x, y) => password.iter_mut().filter(|c| **c == x).for_each(|c| *c = y),
+            RuleFunction::Purge(x) => password.retain(|c| *c != x),
+            RuleFunction::DuplicateFirst(n) if len > 0 => {
+                password.splice(..0, std::iter::repeat_n(password[0], n));
+            }
+            RuleFunction::DuplicateLast(n) if len > 0 => password.extend(std::iter::repeat_n(password[len - 1], n)),
+            RuleFunction::DuplicateAll => *password = password.iter().flat_map(|c| [*c, *c]).collect(),
+            RuleFunction::SwapFront if len > 1 => password.swap(0, 1),
+            RuleFunction::SwapBack if len > 1 => password.swap(len - 2, len - 1),
+            RuleFunction::SwapAt(n, m) if n < len && m < len => password.swap(n, m),
+            RuleFunction::Title => title_case(password, b' '),
+            RuleFunction::TitleWithSeparator(separator) => title_case(password, separator),
+            RuleFunction::ShiftLeft(n) if n < len => password[n] <<= 1,
+            RuleFunction::ShiftRight(n) if n < len => password[n] >>= 1,
+            RuleFunction::Increment(n) if n < len => password[n] = password[n].wrapping_add(1),
+            RuleFunction::Decrement(n) if n < len => password[n] = password[n].wrapping_sub(1),
+            RuleFunction::ReplaceWithNext(n) if n + 1 < len => password[n] = password[n + 1],
+            RuleFunction::ReplaceWithPrevious(n) if n > 0 && n < len => password[n] = password[n - 1],
+            RuleFunction::DuplicateBlockFront(n) if n <= len => {
+                password.splice(..0, password[..n].to_vec());
+            }
+            RuleFunction::DuplicateBlockBack(n) if n <= len => password.extend_from_within(len - n..),
+            _ => (), // The position is out of the password
+        }
+    }
+}
+
+/// This function is responsible for toggling the case of an ASCII letter.
+///
+/// # Arguments
+///
+/// * `c` - The byte to change in place.
+///
+fn toggle_case(c: &mut u8) {
+    if c.is_ascii_alphabetic() {
+        *c ^= 0x20;
+    }
+}
+
+/// This function is responsible for lowercasing a password, then uppercasing its first letter and each letter after the separator.
+///
+/// # Arguments
+///
+/// * `password` - The password to change in place.
+/// * `separator` - The byte after which a letter is uppercased.
+///
+fn title_case(password: &mut [u8], separator: u8) {
+    password.make_ascii_lowercase();
+    let mut uppercase_next: bool = true;
+    for c in password.iter_mut() {
+        if uppercase_next {
+            c.make_ascii_uppercase();
+        }
+        uppercase_next = *c == separator;
+    }
+}
+
+/// This function is responsible for applying a rule to a password.
+///
+/// # Arguments
+///
+/// * `rule` - The rule to apply.
+/// * `password` - The password to mutate.
+/// * `output` - The buffer where the mutated password is written, it's cleared first.
+///
+pub fn apply_rule(rule: &Rule, password: &[u8], output: &mut Vec<u8>) {
+    output.clear();
+    output.extend_from_slice(password);
+    for function in &rule.functions {
+        if function.get_grown_length(output.len()) < MAX_PASSWORD_SIZE {
+            function.apply(output);
+        }
+    }
+}
+
+/// This function is responsible for parsing a rule written with the syntax of hashcat (e.g. `c $1 $2 $3` or `so0 sa@`).
+/// The spaces between the functions are ignored, the rejection and memory functions aren't supported.
+///
+/// # Arguments
+///
+/// * `rule` - The rule to parse.
+///
+/// # Returns
+///
+/// The Rule struct, WorgenXError if a function is unknown, unsupported or incomplete.
+///
+pub fn parse_rule(rule: &str) -> Result<Rule, WorgenXError> {
+    let mut bytes: Bytes = rule.bytes();
+    let mut functions: Vec<RuleFunction> = Vec::new();
+
+    while let Some(name) = bytes.next() {
+        let function: RuleFunction = match name {
+            b' ' => continue,
+            b':' => RuleFunction::Noop,
+            b'l' => RuleFunction::Lowercase,
+            b'u' => RuleFunction::Uppercase,
+            b'c' => RuleFunction::Capitalize,
+            b'C' => RuleFunction::InvertCapitalize,
+            b't' => RuleFunction::ToggleCase,
+            b'T' => RuleFunction::ToggleAt(next_position(&mut bytes, rule, name)?),
+            b'r' => RuleFunction::Reverse,
+            b'd' => RuleFunction::Duplicate,
+            b'p' => RuleFunction::DuplicateN(next_position(&mut bytes, rule, name)?),
+            b'f' => RuleFunction::Reflect,
+            b'{' => RuleFunction::RotateLeft,
+            b'}' => RuleFunction::RotateRight,
+            b'$' => RuleFunction::Append(next_param(&mut bytes, rule, name)?),
+            b'^' => RuleFunction::Prepend(next_param(&mut bytes, rule, name)?),
+            b'[' => RuleFunction::DeleteFirst,
+            b']' => RuleFunction::DeleteLast,
+            b'D' => RuleFunction::DeleteAt(next_position(&mut bytes, rule, name)?),
+            b'x' => RuleFunction::Extract(next_position(&mut bytes, rule, name)?, next_position(&mut bytes, rule, name)?),
+            b'O' => RuleFunction::Omit(next_position(&mut bytes, rule, name)?, next_position(&mut bytes, rule, name)?),
+            b'i' => RuleFunction::Insert(next_position(&mut bytes, rule, name)?, next_param(&mut bytes, rule, name)?),
+            b'o' => RuleFunction::Overwrite(next_position(&mut bytes, rule, name)?, next_param(&mut bytes, rule, name)?),
+            b'\'' => RuleFunction::Truncate(next_position(&mut bytes, rule, name)?),
+            b's' => RuleFunction::Replace(next_param(&mut bytes, rule, name)?, next_param(&mut bytes, rule, name)?),
+            b'@' => RuleFunction::Purge(next_param(&mut bytes, rule, name)?),
+            b'z' => RuleFunction::DuplicateFirst(next_position(&mut bytes, rule, name)?),
+            b'Z' => RuleFunction::DuplicateLast(next_position(&mut bytes, rule, name)?),
+            b'q' => RuleFunction::DuplicateAll,
+            b'k' => RuleFunction::SwapFront,
+            b'K' => RuleFunction::SwapBack,
+            b'*' => RuleFunction::SwapAt(next_position(&mut bytes, rule, name)?, next_position(&mut bytes, rule, name)?),
+            b'E' => RuleFunction::Title,
+            b'e' => RuleFunction::TitleWithSeparator(next_param(&mut bytes, rule, name)?),
+            b'L' => RuleFunction::ShiftLeft(next_position(&mut bytes, rule, name)?),
+            b'R' => RuleFunction::ShiftRight(next_position(&mut bytes, rule, name)?),
+            b'+' => RuleFunction::Increment(next_position(&mut bytes, rule, name)?),
+            b'-' => RuleFunction::Decrement(next_position(&mut bytes, rule, name)?),
+            b'.' => RuleFunction::ReplaceWithNext(next_position(&mut bytes, rule, name)?),
+            b',' => RuleFunction::ReplaceWithPrevious(next_position(&mut bytes, rule, name)?),
+            b'y' => RuleFunction::DuplicateBlockFront(next_position(&mut bytes, rule, name)?),
+            b'Y' => RuleFunction::DuplicateBlockBack(next_position(&mut bytes, rule, name)?),
+            _ => {
+                return Err(WorgenXError::ArgError(ArgError::InvalidRule(
+                    rule.to_string(),
+                    format!("the function `{}` is not supported", char::from(name)),
+                )))
+            }
+        };
+        functions.push(function);
+    }
+
+    Ok(Rule { functions })
+}
+
+/// This function is responsible for reading the next parameter of a function of a rule.
+///
+/// # Arguments
+///
+/// * `bytes` - The remaining bytes of the rule.
+/// * `rule` - The whole rule, for the error message.
+/// * `name` - The name of the function, for the error message.
+///
+/// # Returns
+///
+/// The byte of the parameter, WorgenXError if the rule ends before.
+///
+fn next_param(bytes: &mut Bytes, rule: &str, name: u8) -> Result<u8, WorgenXError> {
+    bytes.next().ok_or_else(|| {
+        WorgenXError::ArgError(ArgError::InvalidRule(
+            rule.to_string(),
+            format!("the function `{}` is incomplete", char::from(name)),
+        ))
+    })
+}
+
+/// This function is responsible for reading the next position of a function of a rule, written 0-9 then A-Z (10-35).
+///
+/// # Arguments
+///
+/// * `bytes` - The remaining bytes of the rule.
+/// * `rule` - The whole rule, for the error message.
+/// * `name` - The name of the function, for the error message.
+///
+/// # Returns
+///
+/// The position, WorgenXError if the rule ends before or if the position is invalid.
+///
+fn next_position(bytes: &mut Bytes, rule: &str, name: u8) -> Result<usize, WorgenXError> {
+    match next_param(bytes, rule, name)? {
+        c @ b'0'..=b'9' => Ok(usize::from(c - b'0')),
+        c @ b'A'..=b'Z' => Ok(usize::from(c - b'A') + 10),
+        c => Err(WorgenXError::ArgError(ArgError::InvalidRule(
+            rule.to_string(),
+            format!("the position `{}` of the function `{}` must be 0-9 or A-Z", char::from(c), char::from(name)),
+        ))),
+    }
+}
+
+/// This function is responsible for parsing the rules of a rule file, one rule per line.
+/// The empty lines and the comments (lines starting with `#`) are skipped.
+///
+/// # Arguments
+///
+/// * `content` - The content of the rule file.
+///
+/// # Returns
+///
+/// The vector of the rules, WorgenXError if a rule is invalid.
+///
+pub fn parse_rules(content: &str) -> Result<Vec<Rule>, WorgenXError> {
+    content
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(parse_rule)
+        .collect()
+}
+
+/// This function is responsible for loading the rules of a rule file.
+///
+/// # Arguments
+///
+/// * `path` - The path of the rule file.
+///
+/// # Returns
+///
+/// The vector of the rules, WorgenXError if the file can't be read, if a rule is invalid or if there isn't any rule.
+///
+pub fn load_rules(path: &str) -> Result<Vec<Rule>, WorgenXError> {
+    let content: String = fs::read_to_string(path).map_err(|e| WorgenXError::SystemError(SystemError::UnableToReadFile(path.to_string(), e.to_string())))?;
+    let rules: Vec<Rule> = parse_rules(&content)?;
+    if rules.is_empty() {
+        return Err(WorgenXError::ArgError(ArgError::EmptyRuleFile(path.to_string())));
+    }
+
+    Ok(rules)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wordlist::{WordlistConfig, WordsConfig};
+
+    fn mutate(rule: &str, password: &str) -> Vec<u8> {
+        let mut output: Vec<u8> = Vec::new();
+        apply_rule(&parse_rule(rule).unwrap(), password.as_bytes(), &mut output);
+        output
+    }
+
+    #[test]
+    fn test_apply_rule_hashcat_vectors() {
+        // The examples of the rule-based attack documentation of hashcat.
+        let vectors: [(&str, &str, &[u8]); 43] = [
+            (":", "p@ssW0rd", b"p@ssW0rd"),
+            ("l", "p@ssW0rd", b"p@ssw0rd"),
+            ("u", "p@ssW0rd", b"P@SSW0RD"),
+            ("c", "p@ssW0rd", b"P@ssw0rd"),
+            ("C", "p@ssW0rd", b"p@SSW0RD"),
+            ("t", "p@ssW0rd", b"P@SSw0RD"),
+            ("T3", "p@ssW0rd", b"p@sSW0rd"),
+            ("r", "p@ssW0rd", b"dr0Wss@p"),
+            ("d", "p@ssW0rd", b"p@ssW0rdp@ssW0rd"),
+            ("p2", "p@ssW0rd", b"p@ssW0rdp@ssW0rdp@ssW0rd"),
+            ("f", "p@ssW0rd", b"p@ssW0rddr0Wss@p"),
+            ("{", "p@ssW0rd", b"@ssW0rdp"),
+            ("}", "p@ssW0rd", b"dp@ssW0r"),
+            ("$1$2", "p@ssW0rd", b"p@ssW0rd12"),
+            ("^2^1", "p@ssW0rd", b"12p@ssW0rd"),
+            ("[", "p@ssW0rd", b"@ssW0rd"),
+            ("]", "p@ssW0rd", b"p@ssW0r"),
+            ("D3", "p@ssW0rd", b"p@sW0rd"),
+            ("x04", "p@ssW0rd", b"p@ss"),
+            ("O12", "p@ssW0rd", b"psW0rd"),
+            ("i4!", "p@ssW0rd", b"p@ss!W0rd"),
+            ("o3$", "p@ssW0rd", b"p@s$W0rd"),
+            ("'6", "p@ssW0rd", b"p@ssW0"),
+            ("ss$", "p@ssW0rd", b"p@$$W0rd"),
+            ("@s", "p@ssW0rd", b"p@W0rd"),
+            ("z2", "p@ssW0rd", b"ppp@ssW0rd"),
+            ("Z2", "p@ssW0rd", b"p@ssW0rddd"),
+            ("q", "p@ssW0rd", b"pp@@ssssWW00rrdd"),
+            ("k", "p@ssW0rd", b"@pssW0rd"),
+            ("K", "p@ssW0rd", b"p@ssW0dr"),
+            ("*34", "p@ssW0rd", b"p@sWs0rd"),
+            ("L2", "p@ssW0rd", b"p@\xe6sW0rd"),
+            ("R2", "p@ssW0rd", b"p@9sW0rd"),
+            ("+2", "p@ssW0rd", b"p@tsW0rd"),
+            ("-1", "p@ssW0rd", b"p?ssW0rd"),
+            (".1", "p@ssW0rd", b"psssW0rd"),
+            (",1", "p@ssW0rd", b"ppssW0rd"),
+            ("y2", "p@ssW0rd", b"p@p@ssW0rd"),
+            ("Y2", "p@ssW0rd", b"p@ssW0rdrd"),
+            ("E", "p@ssW0rd w0rld", b"P@ssw0rd W0rld"),
+            ("e-", "hello-world", b"Hello-World"),
+            ("c $1 $2 $3", "password", b"Password123"),
+            ("so0 sa@ $!", "password", b"p@ssw0rd!"),
+        ];
+
+        for (rule, password, expected) in vectors {
+            assert_eq!(mutate(rule, password), expected, "rule `{}`", rule);
+        }
+    }
+
+    #[test]
+    fn test_apply_rule_out_of_range() {
+        // The functions whose position is out of the password leave it unchanged, like in hashcat.
+        for rule in ["T9", "D8", "x36", "O72", "i9!", "o8$", "'8", "*08", "L9", ".7", ",0", "y9", "Y9", "k", "{", "z3"] {
+            let password: &str = if ["k", "{", "z3"].contains(&rule) { "" } else { "p@ssW0rd" };
+            assert_eq!(mutate(rule, password), password.as_bytes(), "rule `{}`", rule);
+        }
+        assert_eq!(mutate("i8!", "p@ssW0rd"), b"p@ssW0rd!");
+
+        // A function which would produce a password of 256 bytes or more is skipped.
+        let password: String = "a".repeat(128);
+        assert_eq!(mutate("d", &password).len(), 128);
+        assert_eq!(mutate("$b", &password).len(), 129);
+        assert_eq!(mutate("p1 $b", &"a".repeat(100)).len(), 201);
+    }
+
+    #[test]
+    fn test_parse_rules() {
+        let rules: Vec<Rule> = parse_rules("# comment\n:\n\nsa@ ]\n$ \n").unwrap();
+        assert_eq!(rules.len(), 3);
+        assert_eq!(rules[1].functions, vec![RuleFunction::Replace(b'a', b'@'), RuleFunction::DeleteLast]);
+        assert_eq!(rules[2].functions, vec![RuleFunction::Append(b' ')]);
+        assert_eq!(parse_rule("TA TZ").unwrap().functions, vec![RuleFunction::ToggleAt(10), RuleFunction::ToggleAt(35)]);
+        assert!(parse_rule("T A").is_err());
+
+        assert!(parse_rule("$").is_err());
+        assert!(parse_rule("Ta").is_err());
+        assert!(parse_rule("<5").is_err());
+        assert!(parse_rules(":\nx1").is_err());
+        assert!(load_rules("test_missing_rules.rule").is_err());
+    }
+
+    #[test]
+    fn test_rules_config() {
+        let rules_config: RulesConfig<WordsConfig> = RulesConfig {
+            source: WordsConfig {
+                words: vec![b"abc".to_vec(), b"xyz".to_vec(), b"pass".to_vec()],
+                hash: String::new(),
+                raw_separator: None,
+            },
+            rules: parse_rules(":\nu\n$1").unwrap(),
+        };
+        let collect = |offset: u64, nb_of_passwords: u64| -> Vec<String> {
+            let mut stream: RulesStream<'_, WordsConfig> = rules_config.get_stream(offset, nb_of_passwords);
+            let mut passwords: Vec<String> = Vec::new();
+            while let Some(password) = stream.next_password() {
+                passwords.push(String::from_utf8(password.to_vec()).unwrap());
+            }
+            passwords
+        };
+
+        assert_eq!(rules_config.get_nb_of_passwords(), 9);
+        assert_eq!(collect(0, 9), ["abc", "ABC", "abc1", "xyz", "XYZ", "xyz1", "pass", "PASS", "pass1"]);
+        assert_eq!(collect(2, 3), ["abc1", "xyz", "XYZ"]);
+        assert_eq!(collect(7, 10), ["PASS", "pass1"]);
+        assert!(collect(9, 10).is_empty());
+        assert_eq!(rules_config.get_password_size(), 33.0 / 9.0);
+    }
+
+    #[test]
+    fn test_generate_wordlist_with_rules() {
+        let wordlist_config: WordlistConfig = wordlist::build_wordlist_config(&wordlist::WordlistValues {
+            numbers: true,
+            special_characters: false,
+            uppercase: false,
+            lowercase: false,
+            charset: String::new(),
+            mask: String::from("a??"),
+            hash: String::from("md5"),
+            raw_separator: None,
+            byte_placeholder: false,
+        });
+        let rules_config: RulesConfig<WordlistConfig> = RulesConfig {
+            source: wordlist_config,
+            rules: parse_rules("c\nr\n$!").unwrap(),
+        };
+        wordlist::wordlist_generation_scheduler(&rules_config, rules_config.get_nb_of_passwords(), 4, "test20.txt", true).unwrap();
+
+        let mut lines: Vec<String> = std::fs::read_to_string("test20.txt").unwrap().lines().map(String::from).collect();
+        std::fs::remove_file("test20.txt").unwrap();
+        let mut expected_lines: Vec<String> = (0..100)
+            .flat_map(|idx| [format!("A{:02}", idx), format!("a{:02}", idx).chars().rev().collect::<String>(), format!("a{:02}!", idx)])
+            .map(|password| crate::system::manage_hash(password.as_bytes(), "md5").unwrap())
+            .collect();
+        lines.sort();
+        expected_lines.sort();
+        assert_eq!(lines, expected_lines);
+    }
+}
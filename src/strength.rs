@@ -0,0 +1,803 @@
+// Internal crates.
+use crate::{benchmark, dict};
+
+// External crates.
+use std::collections::HashMap;
+
+/// This constant is used to set the minimum number of characters of a dictionary word.
+///
+const MIN_WORD_LENGTH: usize = 3;
+
+/// This constant is used to set the maximum number of characters of a dictionary word.
+///
+const MAX_WORD_LENGTH: usize = 32;
+
+/// This constant is used to set the minimum number of keys of a keyboard walk.
+///
+const MIN_WALK_LENGTH: usize = 4;
+
+/// This constant is used to set the minimum number of repetitions of a single character.
+///
+const MIN_REPEATED_CHARACTERS: usize = 3;
+
+/// These constants are used to set the number of characters of the dates (e.g. 1990 and 31/12/1990).
+///
+const MIN_DATE_LENGTH: usize = 4;
+const MAX_DATE_LENGTH: usize = 10;
+
+/// This constant is used to set the number of years (1900-2099) and days tried by an attacker guessing a date.
+///
+const NB_OF_YEARS: f64 = 200.0;
+const NB_OF_DAYS: f64 = 365.0;
+
+/// This constant defines the separators of the dates.
+///
+const DATE_SEPARATORS: &str = "/-._ ";
+
+/// This constant is used to set the number of keys of the keyboard and the average number of neighbours of a key, to estimate the guesses of a keyboard walk.
+///
+const NB_OF_KEYS: f64 = 47.0;
+const KEYBOARD_AVERAGE_DEGREE: f64 = 4.0;
+
+/// This constant is used to set the minimum number of guesses of a pattern, so an attacker still has to guess the pattern itself.
+///
+const MIN_PATTERN_GUESSES: f64 = 10.0;
+
+/// This enum represents the type of pattern found in a password.
+///
+/// * `Dictionary` - A common password or word, possibly with uppercase letters or l33t substitutions.
+/// * `KeyboardWalk` - A sequence of adjacent keys of a QWERTY keyboard (e.g. qwerty or 1qaz).
+/// * `Date` - A year or a full date, with or without separators.
+/// * `Repeat` - A character or a group of characters repeated several times.
+/// * `BruteForce` - The characters that don't match any other pattern.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum PatternKind {
+    Dictionary,
+    KeyboardWalk,
+    Date,
+    Repeat,
+    BruteForce,
+}
+
+/// This struct represents a pattern found in a password.
+/// `start` and `end` are the indexes of the first character and of the character after the pattern (in characters, not bytes).
+/// `word` is the dictionary word matched, in lowercase and without the l33t substitutions.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct PatternMatch {
+    pub kind: PatternKind,
+    pub token: String,
+    pub start: usize,
+    pub end: usize,
+    pub guesses: f64,
+    pub word: Option<String>,
+    pub l33t: bool,
+}
+
+/// This struct represents the estimated strength of a password.
+/// The patterns are the cheapest way found to guess the whole password, in order.
+///
+#[derive(Debug)]
+pub struct StrengthReport {
+    pub password: String,
+    pub entropy: f64,
+    pub guesses: f64,
+    pub patterns: Vec<PatternMatch>,
+}
+
+/// This struct is responsible for estimating the strength of passwords from the patterns an attacker would try first.
+/// The words are ranked, the most common ones being the cheapest to guess.
+///
+#[derive(Debug)]
+pub struct StrengthChecker {
+    ranked_words: HashMap<String, usize>,
+}
+
+impl PatternKind {
+    /// This function is responsible for returning the name of the pattern, displayed to the user.
+    ///
+    /// # Returns
+    ///
+    /// The name of the pattern.
+    ///
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            PatternKind::Dictionary => "dictionary word",
+            PatternKind::KeyboardWalk => "keyboard walk",
+            PatternKind::Date => "date",
+            PatternKind::Repeat => "repeat",
+            PatternKind::BruteForce => "brute force",
+        }
+    }
+}
+
+impl StrengthChecker {
+    /// This function is responsible for building the checker from the common words of the dictionary and additional words.
+    /// The words of the EFF large wordlist are ranked after the common words, then the additional words in their order.
+    ///
+    /// # Arguments
+    ///
+    /// * `words` - The additional words (e.g. the name of the company), may be empty.
+    ///
+    /// # Returns
+    ///
+    /// The StrengthChecker struct.
+    ///
+    pub fn new(words: &[String]) -> Self {
+        let mut ranked_words: HashMap<String, usize> = HashMap::new();
+        let all_words = dict::COMMON_WORDS
+            .lines()
+            .chain(dict::EFF_LARGE_WORDLIST.lines())
+            .map(|word| word.trim().to_lowercase())
+            .filter(|word| !word.is_empty())
+            .chain(words.iter().map(|word| word.to_lowercase()));
+        for word in all_words {
+            let rank: usize = ranked_words.len() + 1;
+            ranked_words.entry(word).or_insert(rank);
+        }
+
+        StrengthChecker { ranked_words }
+    }
+
+    /// This function is responsible for estimating the strength of a password.
+    /// The number of guesses is the minimum product of the guesses of the patterns covering the whole password,
+    /// the characters that don't belong to any pattern are brute forced.
+    ///
+    /// # Arguments
+    ///
+    /// * `password` - The password to check.
+    ///
+    /// # Returns
+    ///
+    /// The StrengthReport struct of the password.
+    ///
+    pub fn check(&self, password: &str) -> StrengthReport {
+        let chars: Vec<char> = password.chars().collect();
+        let matches: Vec<PatternMatch> = self.find_patterns(&chars);
+        let log2_cardinality: f64 = get_bruteforce_cardinality(&chars).log2();
+
+        // best_entropy[i] is the minimum base 2 logarithm of the guesses of the first i characters.
+        let mut best_entropy: Vec<f64> = vec![0.0; chars.len() + 1];
+        let mut best_match: Vec<Option<usize>> = vec![None; chars.len() + 1];
+        for end in 1..=chars.len() {
+            best_entropy[end] = best_entropy[end - 1] + log2_cardinality;
+            for (idx, pattern) in matches.iter().enumerate().filter(|(_, pattern)| pattern.end == end) {
+                let entropy: f64 = best_entropy[pattern.start] + pattern.guesses.log2();
+                if entropy < best_entropy[end] {
+                    best_entropy[end] = entropy;
+                    best_match[end] = Some(idx);
+                }
+            }
+        }
+
+        let mut patterns: Vec<PatternMatch> = Vec::new();
+        let mut end: usize = chars.len();
+        while end > 0 {
+            let pattern: PatternMatch = match best_match[end] {
+                Some(idx) => matches[idx].clone(),
+                None => {
+                    let mut start: usize = end - 1;
+                    while start > 0 && best_match[start].is_none() {
+                        start -= 1;
+                    }
+                    PatternMatch {
+                        kind: PatternKind::BruteForce,
+                        token: chars[start..end].iter().collect(),
+                        start,
+                        end,
+                        guesses: (best_entropy[end] - best_entropy[start]).exp2(),
+                        word: None,
+                        l33t: false,
+                    }
+                }
+            };
+            end = pattern.start;
+            patterns.push(pattern);
+        }
+        patterns.reverse();
+
+        let entropy: f64 = best_entropy[chars.len()];
+        StrengthReport {
+            password: password.to_string(),
+            entropy,
+            guesses: entropy.exp2(),
+            patterns,
+        }
+    }
+
+    /// This function is responsible for finding all the patterns of a password, they may overlap.
+    ///
+    /// # Arguments
+    ///
+    /// * `chars` - The characters of the password.
+    ///
+    /// # Returns
+    ///
+    /// The vector of the patterns found.
+    ///
+    fn find_patterns(&self, chars: &[char]) -> Vec<PatternMatch> {
+        let mut matches: Vec<PatternMatch> = self.find_dictionary_words(chars);
+        matches.extend(find_keyboard_walks(chars));
+        matches.extend(find_dates(chars));
+        let repeats: Vec<PatternMatch> = find_repeats(chars, &matches);
+        matches.extend(repeats);
+        for pattern in matches.iter_mut() {
+            pattern.guesses = pattern.guesses.max(MIN_PATTERN_GUESSES);
+        }
+        matches
+    }
+
+    /// This function is responsible for finding the dictionary words of a password, in any case and with l33t substitutions (e.g. p@ssw0rd).
+    /// The guesses of a word are its rank, multiplied by the number of ways to capitalize it and to substitute its letters.
+    ///
+    /// # Arguments
+    ///
+    /// * `chars` - The characters of the password.
+    ///
+    /// # Returns
+    ///
+    /// The vector of the dictionary words found.
+    ///
+    fn find_dictionary_words(&self, chars: &[char]) -> Vec<PatternMatch> {
+        let mut matches: Vec<PatternMatch> = Vec::new();
+        for start in 0..chars.len() {
+            for end in (start + MIN_WORD_LENGTH)..=chars.len().min(start + MAX_WORD_LENGTH) {
+                let token: &[char] = &chars[start..end];
+                let lowercase_token: String = token.iter().flat_map(|c| c.to_lowercase()).collect();
+                let mut candidates: Vec<(String, bool)> = vec![(lowercase_token.clone(), false)];
+                candidates.extend(['i', 'l'].iter().map(|one| (remove_l33t(&lowercase_token, *one), true)));
+
+                let found = candidates.into_iter().find_map(|(word, l33t)| self.ranked_words.get(&word).map(|rank| (word, l33t, *rank)));
+                if let Some((word, l33t, rank)) = found {
+                    let nb_of_substitutions: usize = lowercase_token.chars().zip(word.chars()).filter(|(c, w)| c != w).count();
+                    matches.push(PatternMatch {
+                        kind: PatternKind::Dictionary,
+                        token: token.iter().collect(),
+                        start,
+                        end,
+                        guesses: rank as f64 * get_uppercase_variations(token) * (nb_of_substitutions as f64).exp2(),
+                        word: Some(word),
+                        l33t: l33t && nb_of_substitutions > 0,
+                    });
+                }
+            }
+        }
+        matches
+    }
+}
+
+/// This function is responsible for finding the repeated characters (e.g. aaaa) and the repeated groups of characters (e.g. abcabc) of a password.
+/// The guesses of a repeat are the guesses of the repeated group multiplied by the number of repetitions.
+/// The guesses of the group are the ones of a pattern already found on exactly the same characters, or the brute force guesses otherwise.
+/// The groups are at most 32 characters long, so the search stays linear in the length of the password.
+///
+/// # Arguments
+///
+/// * `chars` - The characters of the password.
+/// * `matches` - The other patterns found in the password.
+///
+/// # Returns
+///
+/// The vector of the longest repeat starting at each character.
+///
+fn find_repeats(chars: &[char], matches: &[PatternMatch]) -> Vec<PatternMatch> {
+    // longest_repeats[i] is the group length and the number of repetitions of the longest repeat starting at the character i.
+    let mut longest_repeats: Vec<Option<(usize, usize)>> = vec![None; chars.len()];
+    for group_length in 1..=MAX_WORD_LENGTH.min(chars.len() / 2) {
+        let min_repetitions: usize = if group_length == 1 { MIN_REPEATED_CHARACTERS } else { 2 };
+        // The number of characters equal to the character group_length positions after them, from the character i onwards.
+        let mut nb_of_equal_chars: usize = 0;
+        for start in (0..chars.len() - group_length).rev() {
+            nb_of_equal_chars = if chars[start] == chars[start + group_length] { nb_of_equal_chars + 1 } else { 0 };
+            let nb_of_repetitions: usize = 1 + nb_of_equal_chars / group_length;
+            if nb_of_repetitions >= min_repetitions
+                && longest_repeats[start].is_none_or(|(length, count)| group_length * nb_of_repetitions > length * count)
+            {
+                longest_repeats[start] = Some((group_length, nb_of_repetitions));
+            }
+        }
+    }
+
+    let mut repeats: Vec<PatternMatch> = Vec::new();
+    for (start, longest_repeat) in longest_repeats.into_iter().enumerate() {
+        if let Some((group_length, nb_of_repetitions)) = longest_repeat {
+            let group: &[char] = &chars[start..start + group_length];
+            let group_guesses: f64 = matches
+                .iter()
+                .filter(|pattern| pattern.start == start && pattern.end == start + group_length)
+                .map(|pattern| pattern.guesses)
+                .fold(get_bruteforce_cardinality(group).powi(group_length as i32), f64::min);
+            let end: usize = start + group_length * nb_of_repetitions;
+            repeats.push(PatternMatch {
+                kind: PatternKind::Repeat,
+                token: chars[start..end].iter().collect(),
+                start,
+                end,
+                guesses: group_guesses * nb_of_repetitions as f64,
+                word: None,
+                l33t: false,
+            });
+        }
+    }
+    repeats
+}
+
+/// This function is responsible for replacing the l33t characters of a word by the letters they stand for.
+///
+/// # Arguments
+///
+/// * `word` - The word, in lowercase.
+/// * `one` - The letter replacing the ambiguous characters 1 and |, i or l.
+///
+/// # Returns
+///
+/// The word without the l33t substitutions.
+///
+fn remove_l33t(word: &str, one: char) -> String {
+    word.chars()
+        .map(|c| match c {
+            '4' | '@' => 'a',
+            '8' => 'b',
+            '3' => 'e',
+            '9' | '6' => 'g',
+            '1' | '!' | '|' => one,
+            '0' => 'o',
+            '5' | '$' => 's',
+            '7' | '+' => 't',
+            '2' => 'z',
+            _ => c,
+        })
+        .collect()
+}
+
+/// This function is responsible for computing the number of ways to capitalize a word like the token.
+/// A word in lowercase, in uppercase or only capitalized on its first or last letter is tried first.
+///
+/// # Arguments
+///
+/// * `token` - The characters of the word found in the password.
+///
+/// # Returns
+///
+/// The number of variations.
+///
+fn get_uppercase_variations(token: &[char]) -> f64 {
+    let nb_of_uppercase: usize = token.iter().filter(|c| c.is_uppercase()).count();
+    let nb_of_lowercase: usize = token.iter().filter(|c| c.is_lowercase()).count();
+    if nb_of_uppercase == 0 {
+        return 1.0;
+    }
+    let first_or_last_only: bool = nb_of_uppercase == 1 && (token[0].is_uppercase() || token[token.len() - 1].is_uppercase());
+    if nb_of_lowercase == 0 || first_or_last_only {
+        return 2.0;
+    }
+
+    let nb_of_letters: usize = nb_of_uppercase + nb_of_lowercase;
+    (1..=nb_of_uppercase.min(nb_of_lowercase)).map(|k| get_binomial(nb_of_letters, k)).sum()
+}
+
+/// This function is responsible for computing the binomial coefficient C(n, k).
+///
+/// # Arguments
+///
+/// * `n` - The number of elements.
+/// * `k` - The number of elements chosen.
+///
+/// # Returns
+///
+/// The binomial coefficient.
+///
+fn get_binomial(n: usize, k: usize) -> f64 {
+    (0..k).fold(1.0, |binomial, i| binomial * (n - i) as f64 / (i + 1) as f64)
+}
+
+/// This function is responsible for returning the position of a key on a QWERTY keyboard.
+///
+/// # Arguments
+///
+/// * `c` - The character of the key.
+///
+/// # Returns
+///
+/// The row, the horizontal position and true if the shift key is needed, None if the character isn't on the keyboard.
+///
+fn get_key_position(c: char) -> Option<(usize, f64, bool)> {
+    for (row, (keys, shifted_keys)) in dict::KEYBOARD_ROWS.iter().zip(dict::SHIFTED_KEYBOARD_ROWS.iter()).enumerate() {
+        for (shifted, keys) in [(false, keys), (true, shifted_keys)] {
+            if let Some(column) = keys.chars().position(|key| key == c) {
+                return Some((row, column as f64 + dict::KEYBOARD_ROW_OFFSETS[row], shifted));
+            }
+        }
+    }
+    None
+}
+
+/// This function is responsible for finding the keyboard walks of a password, like qwerty, 1qaz2wsx or zxcvbn.
+/// The guesses of a walk grow with its length and with each change of direction.
+///
+/// # Arguments
+///
+/// * `chars` - The characters of the password.
+///
+/// # Returns
+///
+/// The vector of the longest keyboard walks.
+///
+fn find_keyboard_walks(chars: &[char]) -> Vec<PatternMatch> {
+    let mut matches: Vec<PatternMatch> = Vec::new();
+    let mut start: usize = 0;
+    while start < chars.len() {
+        let mut end: usize = start + 1;
+        let mut nb_of_turns: u32 = 0;
+        let mut previous_direction: Option<(i32, i32)> = None;
+        while end < chars.len() {
+            let direction: Option<(i32, i32)> = match (get_key_position(chars[end - 1]), get_key_position(chars[end])) {
+                (Some((row, x, _)), Some((next_row, next_x, _))) => {
+                    let dx: f64 = next_x - x;
+                    let adjacent: bool = row.abs_diff(next_row) <= 1 && dx.abs() <= 1.0 && (row != next_row || dx != 0.0);
+                    adjacent.then_some((next_row as i32 - row as i32, dx.signum() as i32))
+                }
+                _ => None,
+            };
+            let Some(direction) = direction else { break };
+            if previous_direction.is_some_and(|previous_direction| previous_direction != direction) {
+                nb_of_turns += 1;
+            }
+            previous_direction = Some(direction);
+            end += 1;
+        }
+
+        if end - start >= MIN_WALK_LENGTH {
+            let is_shifted: bool = chars[start..end].iter().any(|c| get_key_position(*c).is_some_and(|(_, _, shifted)| shifted));
+            matches.push(PatternMatch {
+                kind: PatternKind::KeyboardWalk,
+                token: chars[start..end].iter().collect(),
+                start,
+                end,
+                guesses: NB_OF_KEYS * (end - start) as f64 * KEYBOARD_AVERAGE_DEGREE.powi(nb_of_turns as i32) * if is_shifted { 2.0 } else { 1.0 },
+                word: None,
+                l33t: false,
+            });
+            start = end - 1;
+        } else {
+            start += 1;
+        }
+    }
+    matches
+}
+
+/// This function is responsible for finding the dates of a password, like 1990, 311290, 19901231 or 31/12/1990.
+///
+/// # Arguments
+///
+/// * `chars` - The characters of the password.
+///
+/// # Returns
+///
+/// The vector of the dates found.
+///
+fn find_dates(chars: &[char]) -> Vec<PatternMatch> {
+    let mut matches: Vec<PatternMatch> = Vec::new();
+    for start in 0..chars.len() {
+        for end in (start + MIN_DATE_LENGTH)..=chars.len().min(start + MAX_DATE_LENGTH) {
+            let token: String = chars[start..end].iter().collect();
+            if let Some(guesses) = get_date_guesses(&token) {
+                matches.push(PatternMatch {
+                    kind: PatternKind::Date,
+                    token,
+                    start,
+                    end,
+                    guesses,
+                    word: None,
+                    l33t: false,
+                });
+            }
+        }
+    }
+    matches
+}
+
+/// This function is responsible for computing the guesses of a token if it's a date.
+/// The digits alone can be a year, a day and a month, or a full date (day, month and year in any usual order).
+/// The groups of a date with separators are split by the same separator.
+///
+/// # Arguments
+///
+/// * `token` - The token to check.
+///
+/// # Returns
+///
+/// The guesses of the date, None if the token isn't a date.
+///
+fn get_date_guesses(token: &str) -> Option<f64> {
+    let groups: Vec<&str> = token.split(|c: char| !c.is_ascii_digit()).collect();
+    let separators: Vec<char> = token.chars().filter(|c| !c.is_ascii_digit()).collect();
+    if groups.iter().any(|group| group.is_empty()) {
+        return None;
+    }
+
+    match (groups.len(), token.len()) {
+        (1, 4) => {
+            let year: u32 = token.parse().ok()?;
+            if (1900..=2099).contains(&year) {
+                return Some(NB_OF_YEARS);
+            }
+            let (first, second) = token.split_at(2);
+            (is_day_and_month(first, second) || is_day_and_month(second, first)).then_some(NB_OF_DAYS)
+        }
+        (1, 6) | (1, 8) => {
+            let (first, rest) = token.split_at(if token.len() == 8 && is_year(&token[..4]) { 4 } else { 2 });
+            let (second, third) = rest.split_at(2);
+            get_full_date_guesses(first, second, third)
+        }
+        (3, _) if separators[0] == separators[1] && DATE_SEPARATORS.contains(separators[0]) => {
+            get_full_date_guesses(groups[0], groups[1], groups[2]).map(|guesses| guesses * DATE_SEPARATORS.len() as f64)
+        }
+        _ => None,
+    }
+}
+
+/// This function is responsible for computing the guesses of a full date made of three groups of digits.
+/// The accepted orders are day-month-year, month-day-year and year-month-day.
+///
+/// # Arguments
+///
+/// * `first` - The first group of digits.
+/// * `second` - The second group of digits.
+/// * `third` - The third group of digits.
+///
+/// # Returns
+///
+/// The guesses of the date, None if the groups aren't a date.
+///
+fn get_full_date_guesses(first: &str, second: &str, third: &str) -> Option<f64> {
+    let nb_of_years: f64 = if third.len() == 4 || first.len() == 4 { NB_OF_YEARS } else { 100.0 };
+    let is_date: bool = (is_year(third) && (is_day_and_month(first, second) || is_day_and_month(second, first)))
+        || (is_year(first) && is_day_and_month(third, second));
+    is_date.then_some(NB_OF_DAYS * nb_of_years)
+}
+
+/// This function is responsible for checking if two groups of digits are a day and a month.
+///
+/// # Arguments
+///
+/// * `day` - The group of digits of the day.
+/// * `month` - The group of digits of the month.
+///
+/// # Returns
+///
+/// True if the groups are a day and a month, false otherwise.
+///
+fn is_day_and_month(day: &str, month: &str) -> bool {
+    let is_valid = |group: &str, max: u32| group.len() <= 2 && group.parse::<u32>().is_ok_and(|value| (1..=max).contains(&value));
+    is_valid(day, 31) && is_valid(month, 12)
+}
+
+/// This function is responsible for checking if a group of digits is a year, on 2 digits or between 1900 and 2099.
+///
+/// # Arguments
+///
+/// * `year` - The group of digits.
+///
+/// # Returns
+///
+/// True if the group is a year, false otherwise.
+///
+fn is_year(year: &str) -> bool {
+    match year.len() {
+        2 => true,
+        4 => year.parse::<u32>().is_ok_and(|year| (1900..=2099).contains(&year)),
+        _ => false,
+    }
+}
+
+/// This function is responsible for returning the number of characters an attacker tries for each character that doesn't match any pattern.
+/// It's the size of the pool made of the types of characters of the password, like the entropy of the random passwords.
+///
+/// # Arguments
+///
+/// * `chars` - The characters of the password.
+///
+/// # Returns
+///
+/// The number of characters of the pool.
+///
+fn get_bruteforce_cardinality(chars: &[char]) -> f64 {
+    let mut cardinality: f64 = 0.0;
+    if chars.iter().any(char::is_ascii_lowercase) {
+        cardinality += dict::LOWERCASE.len() as f64;
+    }
+    if chars.iter().any(char::is_ascii_uppercase) {
+        cardinality += dict::UPPERCASE.len() as f64;
+    }
+    if chars.iter().any(char::is_ascii_digit) {
+        cardinality += dict::NUMBERS.len() as f64;
+    }
+    if chars.iter().any(|c| c.is_ascii() && !c.is_ascii_alphanumeric()) {
+        cardinality += 33.0;
+    }
+    if chars.iter().any(|c| !c.is_ascii()) {
+        cardinality += 100.0;
+    }
+    cardinality
+}
+
+/// This function is responsible for estimating the time needed to try all the guesses in each attack scenario.
+/// The offline scenario uses the given rate (e.g. measured by the benchmark module), the reference offline rates are used otherwise.
+///
+/// # Arguments
+///
+/// * `guesses` - The number of guesses.
+/// * `offline_rate` - The number of guesses per second of an offline attack, None to use the reference rates.
+///
+/// # Returns
+///
+/// The vector of the scenarios, their rate (guesses per second) and the time to crack (in seconds).
+///
+pub fn get_crack_times(guesses: f64, offline_rate: Option<f64>) -> Vec<(String, f64, f64)> {
+    let mut rates: Vec<(String, f64)> = benchmark::ONLINE_CRACKING_RATES.iter().map(|(scenario, rate)| (scenario.to_string(), *rate)).collect();
+    match offline_rate {
+        Some(rate) => rates.push((format!("offline attack, at the given or measured rate ({:.1e} guesses per second)", rate), rate)),
+        None => rates.extend(benchmark::OFFLINE_CRACKING_RATES.iter().map(|(scenario, rate)| (scenario.to_string(), *rate))),
+    }
+    rates.into_iter().map(|(scenario, rate)| (scenario, rate, guesses / rate)).collect()
+}
+
+/// This function is responsible for displaying a duration in the largest suitable unit.
+///
+/// # Arguments
+///
+/// * `seconds` - The duration in seconds.
+///
+/// # Returns
+///
+/// The duration rounded in its unit (e.g. "3 hours"), "less than a second" or "centuries".
+///
+pub fn format_crack_time(seconds: f64) -> String {
+    const UNITS: [(f64, &str); 6] = [
+        (31_556_952.0, "year"),
+        (2_629_746.0, "month"),
+        (86_400.0, "day"),
+        (3_600.0, "hour"),
+        (60.0, "minute"),
+        (1.0, "second"),
+    ];
+
+    if seconds < 1.0 {
+        return "less than a second".to_string();
+    }
+
+    // The duration is at least one second, so a unit is always found.
+    let (value, unit): (f64, &str) = UNITS
+        .iter()
+        .map(|(unit_seconds, unit)| ((seconds / unit_seconds).round(), *unit))
+        .find(|(value, _)| *value >= 1.0)
+        .unwrap();
+    if unit == "year" && value >= 100.0 {
+        return "centuries".to_string();
+    }
+    format!("{} {}{}", value, unit, if value > 1.0 { "s" } else { "" })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dictionary_words() {
+        let checker: StrengthChecker = StrengthChecker::new(&["Worgenx".to_string()]);
+        let report: StrengthReport = checker.check("password");
+        assert_eq!(report.patterns.len(), 1);
+        assert_eq!(report.patterns[0].kind, PatternKind::Dictionary);
+        assert_eq!(report.guesses.round(), MIN_PATTERN_GUESSES);
+
+        let report: StrengthReport = checker.check("P@ssw0rd");
+        assert_eq!(report.patterns[0].word, Some("password".to_string()));
+        assert!(report.patterns[0].l33t);
+        assert_eq!(report.patterns[0].guesses, 2.0 * 2.0 * 4.0);
+
+        let report: StrengthReport = checker.check("worgenx2024");
+        assert_eq!(report.patterns.len(), 2);
+        assert_eq!(report.patterns[0].word, Some("worgenx".to_string()));
+        assert_eq!(report.patterns[1].kind, PatternKind::Date);
+        assert_eq!(report.patterns[1].token, "2024");
+    }
+
+    #[test]
+    fn test_keyboard_walks() {
+        let matches: Vec<PatternMatch> = find_keyboard_walks(&"xx1qaz2wsx".chars().collect::<Vec<char>>());
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].token, "1qaz");
+        assert_eq!(matches[0].start, 2);
+        assert_eq!(matches[1].token, "2wsx");
+
+        assert!(find_keyboard_walks(&"qaz".chars().collect::<Vec<char>>()).is_empty());
+        assert_eq!(find_keyboard_walks(&"ASDF".chars().collect::<Vec<char>>())[0].guesses, NB_OF_KEYS * 4.0 * 2.0);
+
+        let report: StrengthReport = StrengthChecker::new(&[]).check("zxcvbnm,./");
+        assert_eq!(report.patterns.len(), 1);
+        assert_eq!(report.patterns[0].kind, PatternKind::KeyboardWalk);
+    }
+
+    #[test]
+    fn test_dates() {
+        assert_eq!(get_date_guesses("1990"), Some(NB_OF_YEARS));
+        assert_eq!(get_date_guesses("1231"), Some(NB_OF_DAYS));
+        assert_eq!(get_date_guesses("311290"), Some(NB_OF_DAYS * 100.0));
+        assert_eq!(get_date_guesses("19901231"), Some(NB_OF_DAYS * NB_OF_YEARS));
+        assert_eq!(get_date_guesses("31/12/1990"), Some(NB_OF_DAYS * NB_OF_YEARS * 5.0));
+        assert_eq!(get_date_guesses("31/12-1990"), None);
+        assert_eq!(get_date_guesses("5678"), None);
+        assert_eq!(get_date_guesses("32131990"), None);
+    }
+
+    #[test]
+    fn test_repeats() {
+        let checker: StrengthChecker = StrengthChecker::new(&[]);
+        let report: StrengthReport = checker.check("zzzzzz");
+        assert_eq!(report.patterns.len(), 1);
+        assert_eq!(report.patterns[0].kind, PatternKind::Repeat);
+        assert_eq!(report.patterns[0].guesses.round(), 26.0 * 6.0);
+
+        let report: StrengthReport = checker.check("xkcdxkcdxkcd");
+        assert_eq!(report.patterns[0].kind, PatternKind::Repeat);
+        assert_eq!(report.patterns[0].token, "xkcdxkcdxkcd");
+
+        let report: StrengthReport = checker.check("passwordpassword");
+        assert_eq!(report.patterns.len(), 1);
+        assert_eq!(report.patterns[0].kind, PatternKind::Repeat);
+        assert_eq!(report.patterns[0].guesses, MIN_PATTERN_GUESSES);
+
+        let report: StrengthReport = checker.check(&"ab1".repeat(1200));
+        assert_eq!(report.patterns.len(), 1);
+        assert_eq!(report.patterns[0].kind, PatternKind::Repeat);
+    }
+
+    #[test]
+    fn test_common_words() {
+        let checker: StrengthChecker = StrengthChecker::new(&[]);
+        let report: StrengthReport = checker.check("Tr0ub4dor&3");
+        assert!(report.patterns.iter().any(|pattern| pattern.word == Some("troubador".to_string()) && pattern.l33t));
+
+        let report: StrengthReport = checker.check("correcthorsebatterystaple");
+        assert!(report.patterns.iter().all(|pattern| pattern.kind == PatternKind::Dictionary));
+        assert!(report.entropy < 60.0);
+    }
+
+    #[test]
+    fn test_check_random_password() {
+        let checker: StrengthChecker = StrengthChecker::new(&[]);
+        let report: StrengthReport = checker.check("G7#kP2v!Qz9m");
+        assert!(report.entropy > 60.0);
+        assert_eq!(report.patterns.iter().map(|pattern| pattern.end - pattern.start).sum::<usize>(), 12);
+
+        let report: StrengthReport = checker.check("");
+        assert_eq!(report.entropy, 0.0);
+        assert!(report.patterns.is_empty());
+
+        let weak: StrengthReport = checker.check("Summer2024!");
+        let strong: StrengthReport = checker.check("vT9$wq2LmZ!x");
+        assert!(weak.entropy < 36.0);
+        assert!(strong.entropy > weak.entropy);
+    }
+
+    #[test]
+    fn test_crack_times() {
+        let crack_times: Vec<(String, f64, f64)> = get_crack_times(1e10, None);
+        assert_eq!(crack_times.len(), benchmark::ONLINE_CRACKING_RATES.len() + benchmark::OFFLINE_CRACKING_RATES.len());
+        assert_eq!(crack_times[3].2, 1.0);
+
+        let crack_times: Vec<(String, f64, f64)> = get_crack_times(1e10, Some(1e5));
+        assert_eq!(crack_times.len(), benchmark::ONLINE_CRACKING_RATES.len() + 1);
+        assert_eq!(crack_times[2].1, 1e5);
+        assert_eq!(crack_times[2].2, 1e5);
+
+        assert_eq!(format_crack_time(0.5), "less than a second");
+        assert_eq!(format_crack_time(1.0), "1 second");
+        assert_eq!(format_crack_time(7200.0), "2 hours");
+        assert_eq!(format_crack_time(3599.99), "1 hour");
+        assert_eq!(format_crack_time(1e8), "3 years");
+        assert_eq!(format_crack_time(1e12), "centuries");
+    }
+}
@@ -18,100 +18,6 @@ use std::{
 };
 use whirlpool::Whirlpool;
 
-#[cfg(feature = "gui")]
-use std::{cmp::PartialOrd, default::Default, fmt::Display, io::stdin, marker::Copy, str::FromStr};
-
-/// OS specific constants for GUI mode.
-///
-/// * `HOME_ENV_VAR` - The environment variable that holds the user's home directory.
-/// * `PASSWORDS_FOLDER` - The folder where the passwords will be saved.
-/// * `WORDLISTS_FOLDER` - The folder where the wordlists will be saved.
-///
-#[cfg(all(target_family = "unix", feature = "gui"))]
-pub mod unix {
-    pub const HOME_ENV_VAR: &str = "HOME";
-    pub const PASSWORDS_FOLDER: &str = "/worgenx/passwords/";
-    pub const WORDLISTS_FOLDER: &str = "/worgenx/wordlists/";
-}
-#[cfg(all(target_family = "windows", feature = "gui"))]
-pub mod windows {
-    pub const HOME_ENV_VAR: &str = "USERPROFILE";
-    pub const PASSWORDS_FOLDER: &str = "\\worgenx\\passwords\\";
-    pub const WORDLISTS_FOLDER: &str = "\\worgenx\\wordlists\\";
-}
-
-/// This function is responsible for getting user String input y/n.
-///
-/// # Returns
-///
-/// The value entered by the user. If an error occurs, the function returns an empty String.
-///
-#[cfg(feature = "gui")]
-pub fn get_user_choice_yn() -> String {
-    let mut choice: String = get_user_choice();
-    while !choice.eq("y") && !choice.eq("n") {
-        println!("Please enter a valid answer (y/n)");
-        choice = get_user_choice();
-    }
-
-    choice
-}
-
-/// This function is responsible for geting user String input.
-///
-/// # Returns
-///
-/// The value entered by the user. If an error occurs, the function returns an empty String.
-///
-#[cfg(feature = "gui")]
-pub fn get_user_choice() -> String {
-    let mut buffer: String = String::new();
-    if stdin().read_line(&mut buffer).is_ok() {
-        buffer.trim().to_string()
-    } else {
-        String::new()
-    }
-}
-
-/// This function is responsible for getting user int input.
-/// The function will keep asking the user to enter a valid number greater than 0 until the user does so.
-/// This is a generic function, so it can be used for any basic integer type.
-///
-/// # Returns
-///
-/// The value entered by the user. If an error occurs, the function returns 0.
-///
-#[cfg(feature = "gui")]
-pub fn get_user_choice_int<T>() -> T
-where
-    T: FromStr + Display + PartialOrd + Copy + Default,
-    <T as FromStr>::Err: Display,
-{
-    let mut is_good_number: bool = false;
-    let mut number: T = T::default();
-
-    while !is_good_number {
-        let choice: String = get_user_choice();
-        if choice.is_empty() {
-            println!("Please enter a valid number greater than 0");
-            continue;
-        }
-        match choice.trim().parse::<T>() {
-            Ok(n) => {
-                if n > T::default() {
-                    is_good_number = true;
-                    number = n;
-                } else {
-                    println!("Please enter a valid number greater than 0");
-                }
-            }
-            Err(e) => println!("Please enter a valid number greater than 0, {}", e),
-        }
-    }
-
-    number
-}
-
 /// This function is responsible for checking a path/filename.
 ///
 /// # Arguments
@@ -179,30 +85,6 @@ pub fn check_if_parent_folder_exists(file_path: &str) -> bool {
     Path::new(file_path).parent().is_some_and(Path::exists)
 }
 
-/// This function is responsible for creating the passwords or wordlists folder if it doesn't exist.
-///
-/// # Arguments
-///
-/// * `folder` - A string slice that holds the folder to create.
-///
-/// # Returns
-///
-/// Ok(()) if the folder has been created, SystemError otherwise.
-///
-#[cfg(feature = "gui")]
-pub fn create_folder_if_not_exists(folder: &str) -> Result<(), SystemError> {
-    let mut folder: String = String::from(folder);
-    if folder.pop().is_none() {
-        return Err(SystemError::InvalidPath(folder));
-    }
-    if !Path::new(&folder).exists() {
-        std::fs::create_dir_all(&folder)
-            .map_err(|e| SystemError::UnableToCreateFolder(folder, e.to_string()))?;
-    }
-
-    Ok(())
-}
-
 /// This function sends the invalid chars for windows platforms.
 ///
 /// # Returns
@@ -292,6 +174,34 @@ pub fn save_passwd_to_file(file: &Arc<Mutex<File>>, passwords: &str) -> Result<(
         })
 }
 
+/// This function is responsible for writing a buffer of bytes in a file.
+/// The buffer is written as it is, the caller is responsible for the separators between the passwords.
+///
+/// # Arguments
+///
+/// * `file` - The file to write to, wrapped in an `Arc<Mutex<File>>`.
+/// * `buffer` - The bytes to write.
+///
+/// # Returns
+///
+/// Ok(()) if the buffer has been written to the file, WorgenXError otherwise.
+///
+pub fn save_buffer_to_file(file: &Arc<Mutex<File>>, buffer: &[u8]) -> Result<(), WorgenXError> {
+    let mut file = file.lock().map_err(|_| {
+        WorgenXError::SystemError(SystemError::UnableToWriteToFile(
+            "output file".to_string(),
+            "Please check the path, the permissions and try again".to_string(),
+        ))
+    })?;
+
+    file.write_all(buffer).map_err(|_| {
+        WorgenXError::SystemError(SystemError::UnableToWriteToFile(
+            "output file".to_string(),
+            "Please check the path, the permissions and try again".to_string(),
+        ))
+    })
+}
+
 /// This function is responsible for returniong the progress used by the program.
 ///
 /// # Returns
@@ -314,7 +224,7 @@ pub fn get_progress_bar() -> indicatif::ProgressBar {
 /// # Arguments
 ///
 /// * `nb_of_passwords` - The number of passwords in the wordlist.
-/// * `length` - The length of the passwords.
+/// * `password_size` - The average size of the passwords in bytes, once encoded in UTF-8 (see `wordlist::get_password_size()`).
 ///
 /// # Returns
 ///
@@ -323,12 +233,12 @@ pub fn get_progress_bar() -> indicatif::ProgressBar {
 /// If the size is less than 1KB, it will return the size in bytes.
 /// The function will return an empty string if the parameters are equal to 0.
 ///
-pub fn get_estimated_size(nb_of_passwords: u64, length: u64) -> String {
-    if nb_of_passwords == 0 || length == 0 {
+pub fn get_estimated_size(nb_of_passwords: u64, password_size: f64) -> String {
+    if nb_of_passwords == 0 || password_size <= 0.0 {
         return String::new();
     }
 
-    let size: u64 = nb_of_passwords * (length + 1); // +1 for the newline character
+    let size: u64 = (nb_of_passwords as f64 * (password_size + 1.0)).round() as u64; // +1 for the newline character
     let mut size_str: String = String::new();
     if size < 1024 {
         size_str.push_str(&size.to_string());
@@ -355,14 +265,15 @@ pub fn get_estimated_size(nb_of_passwords: u64, length: u64) -> String {
 ///
 /// # Arguments
 ///
-/// * `password` - The password to hash.
+/// * `password` - The password to hash, as a string or as raw bytes.
 /// * `hash` - The hash algorithm to use.
 ///
 /// # Returns
 ///
 /// The hashed password, SystemError otherwise.
 ///
-pub fn manage_hash(password: &str, hash: &str) -> Result<String, SystemError> {
+pub fn manage_hash<T: AsRef<[u8]>>(password: T, hash: &str) -> Result<String, SystemError> {
+    let password: &[u8] = password.as_ref();
     match hash {
         "md5" => Ok(hash_with_digest(Md5::new(), password)),
         "sha1" => Ok(hash_with_digest(Sha1::new(), password)),
@@ -393,8 +304,8 @@ pub fn manage_hash(password: &str, hash: &str) -> Result<String, SystemError> {
 ///
 /// The hashed password.
 ///
-fn hash_with_digest<D: Digest>(mut hasher: D, password: &str) -> String {
-    hasher.update(password.as_bytes());
+fn hash_with_digest<D: Digest>(mut hasher: D, password: &[u8]) -> String {
+    hasher.update(password);
     let result = hasher.finalize();
     hex::encode(result)
 }
@@ -435,15 +346,6 @@ mod tests {
         assert!(check_if_parent_folder_exists(valid_absolute_file_path));
     }
 
-    #[test]
-    #[cfg(feature = "gui")]
-    fn test_create_folder_if_not_exists() {
-        let valid_folder: &str = "./test_folder/";
-
-        assert!(create_folder_if_not_exists(valid_folder).is_ok());
-        std::fs::remove_dir(valid_folder).unwrap();
-    }
-
     #[test]
     fn test_get_elapsed_time() {
         let start_time: Instant = Instant::now();
@@ -465,6 +367,17 @@ mod tests {
         std::fs::remove_file("./test.txt").unwrap();
     }
 
+    #[test]
+    fn test_save_buffer_to_file() {
+        let file: Arc<Mutex<File>> = Arc::new(Mutex::new(File::create("./test_buffer.txt").unwrap()));
+        assert!(save_buffer_to_file(&file, b"test1\ntest2\n").is_ok());
+
+        let content: String = std::fs::read_to_string("./test_buffer.txt").unwrap();
+        assert_eq!(content, "test1\ntest2\n");
+
+        std::fs::remove_file("./test_buffer.txt").unwrap();
+    }
+
     #[test]
     fn test_get_progress_bar() {
         let pb: ProgressBar = get_progress_bar();
@@ -474,20 +387,24 @@ mod tests {
     #[test]
     fn test_get_estimated_size() {
         let nb_of_passwords: u64 = 1000;
-        let length: u64 = 10;
-        assert_eq!(get_estimated_size(nb_of_passwords, length), "10.74 KB");
+        let password_size: f64 = 10.0;
+        assert_eq!(get_estimated_size(nb_of_passwords, password_size), "10.74 KB");
 
         let nb_of_passwords: u64 = 1000000;
-        let length: u64 = 10;
-        assert_eq!(get_estimated_size(nb_of_passwords, length), "10.49 MB");
+        let password_size: f64 = 10.0;
+        assert_eq!(get_estimated_size(nb_of_passwords, password_size), "10.49 MB");
+
+        let nb_of_passwords: u64 = 100;
+        let password_size: f64 = 2.5;
+        assert_eq!(get_estimated_size(nb_of_passwords, password_size), "350 bytes");
 
         let nb_of_passwords: u64 = 0;
-        let length: u64 = 10;
-        assert_eq!(get_estimated_size(nb_of_passwords, length), "");
+        let password_size: f64 = 10.0;
+        assert_eq!(get_estimated_size(nb_of_passwords, password_size), "");
 
         let nb_of_passwords: u64 = 10;
-        let length: u64 = 0;
-        assert_eq!(get_estimated_size(nb_of_passwords, length), "");
+        let password_size: f64 = 0.0;
+        assert_eq!(get_estimated_size(nb_of_passwords, password_size), "");
     }
 
     #[test]
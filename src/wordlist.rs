@@ -1,34 +1,39 @@
 // Internal crates.
 use crate::{
     dict,
-    error::{SystemError, WorgenXError},
+    error::{ArgError, SystemError, WorgenXError},
+    progress::{ProgressGoal, RunContext},
     system,
 };
 
 // External crates.
-use indicatif::ProgressBar;
 use std::{
-    fs::{File, OpenOptions},
-    sync::{
-        atomic::{AtomicU64, Ordering},
-        Arc, Mutex,
-    },
-    thread::{self, JoinHandle},
+    collections::HashSet,
+    fs::{self, File, OpenOptions},
+    iter::FusedIterator,
+    sync::{Arc, Mutex},
+    thread::{self, ScopedJoinHandle},
     time::Instant,
 };
 
 /// This constant is used to set the size of the buffer used to write the passwords in the file.
-/// It specifies the maximum number of passwords that will be written in the file at once per thread.
+/// It specifies the number of bytes that will be written in the file at once per thread.
 ///
-const BUFFER_SIZE: usize = 100000;
+const BUFFER_SIZE: usize = 4 * 1024 * 1024;
 
-/// This static variable is used to track the number of passwords generated.
-/// It is used to update the progress bar.
-/// It is wrapped in a AtomicU64 to avoid data sharing issues between the threads.
+/// This constant is the number of values of a byte placeholder (`?b`) of the mask.
 ///
-static GLOBAL_COUNTER: AtomicU64 = AtomicU64::new(0);
+const BYTE_RADIX: usize = 256;
+
+/// This constant is the number of values of a byte placeholder written as is in the wordlist, the printable ASCII characters (0x20 to 0x7E).
+///
+const NB_OF_PRINTABLE_BYTES: f64 = 95.0;
 
 /// This struct is built from the user's choices and will be used to generate the wordlist.
+/// The `charset` field adds any Unicode characters (e.g. accented letters, Cyrillic or emoji) to the selected types of characters.
+/// If `raw_separator` is set, the passwords are written as raw bytes followed by this separator,
+/// otherwise each password is written on its own line and the non-printable ones in the `$HEX[...]` notation (see `format_password()`).
+/// The `?b` byte placeholder of the mask is opt-in with `byte_placeholder`, so the older masks like `pass?b` keep their meaning.
 ///
 #[derive(Debug)]
 pub struct WordlistValues {
@@ -36,22 +41,582 @@ pub struct WordlistValues {
     pub special_characters: bool,
     pub uppercase: bool,
     pub lowercase: bool,
+    pub charset: String,
     pub mask: String,
     pub hash: String,
+    pub raw_separator: Option<Vec<u8>>,
+    pub byte_placeholder: bool,
 }
 
 /// This struct is built from the WordlistValues struct and will be used to generate the wordlist.
+/// The variable characters of `mask_indexes` are drawn from `dict`, except the ones of `byte_indexes` (`?b` in the mask) which are any byte from 0x00 to 0xFF.
 ///
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct WordlistConfig {
-    pub dict: Vec<u8>,
+    pub dict: Vec<char>,
     pub mask_indexes: Vec<usize>,
+    pub byte_indexes: Vec<usize>,
     pub formated_mask: Vec<char>,
     pub hash: String,
+    pub raw_separator: Option<Vec<u8>>,
+}
+
+/// This struct is the engine of the wordlist generation, it iterates over the passwords of a WordlistConfig in order.
+/// The password is kept in a template of bytes, only the part after the first changed variable character is encoded again when the indexes move forward,
+/// so the characters of the dictionary can have different sizes in bytes. A byte placeholder is written as is, the password may not be valid UTF-8.
+/// The indexes work like an odometer: the last variable character of the mask is the least significant one.
+///
+/// The passwords can be consumed as owned `Vec<u8>` with the Iterator trait or without any allocation with `next_password()`.
+/// `nth()` jumps directly to the wanted password without generating the previous ones.
+///
+#[derive(Debug, Clone)]
+pub struct WordlistIterator {
+    dict: Vec<char>,
+    template: Vec<u8>,
+    suffixes: Vec<Vec<u8>>,
+    positions: Vec<usize>,
+    radixes: Vec<usize>,
+    dict_indexes: Vec<usize>,
+    next_index: u64,
+    remaining: u64,
+    pending_tick: bool,
+}
+
+impl WordlistIterator {
+    /// This function is responsible for creating an iterator over all the passwords of the wordlist.
+    ///
+    /// # Arguments
+    ///
+    /// * `wordlist_config` - The WordlistConfig struct containing the settings of the wordlist.
+    ///
+    /// # Returns
+    ///
+    /// The WordlistIterator struct.
+    ///
+    pub fn new(wordlist_config: &WordlistConfig) -> Self {
+        WordlistIterator::with_range(wordlist_config, 0, get_nb_of_passwords(wordlist_config))
+    }
+
+    /// This function is responsible for creating an iterator over a part of the wordlist.
+    ///
+    /// # Arguments
+    ///
+    /// * `wordlist_config` - The WordlistConfig struct containing the settings of the wordlist.
+    /// * `offset` - The index of the first password to generate.
+    /// * `nb_of_passwords` - The number of passwords to generate, it's truncated to the end of the wordlist.
+    ///
+    /// # Returns
+    ///
+    /// The WordlistIterator struct.
+    ///
+    pub fn with_range(wordlist_config: &WordlistConfig, offset: u64, nb_of_passwords: u64) -> Self {
+        let (template, suffixes) = build_template(&wordlist_config.formated_mask, &wordlist_config.mask_indexes);
+        let mut iterator: WordlistIterator = WordlistIterator {
+            dict: wordlist_config.dict.clone(),
+            positions: vec![template.len(); suffixes.len()],
+            radixes: get_radixes(wordlist_config),
+            template,
+            suffixes,
+            dict_indexes: Vec::new(),
+            next_index: 0,
+            remaining: get_nb_of_passwords(wordlist_config).saturating_sub(offset).min(nb_of_passwords),
+            pending_tick: false,
+        };
+        if iterator.remaining > 0 {
+            iterator.seek(offset);
+        }
+
+        iterator
+    }
+
+    /// This function is responsible for returning the next password without allocating it.
+    /// The returned slice is only valid until the next call.
+    ///
+    /// # Returns
+    ///
+    /// The bytes of the next password, None if the end of the wordlist has been reached.
+    ///
+    pub fn next_password(&mut self) -> Option<&[u8]> {
+        if self.remaining == 0 {
+            return None;
+        }
+        if self.pending_tick {
+            self.tick();
+        }
+
+        self.pending_tick = true;
+        self.next_index += 1;
+        self.remaining -= 1;
+        Some(&self.template)
+    }
+
+    /// This function is responsible for moving the template to the following password.
+    ///
+    fn tick(&mut self) {
+        for idx in (0..self.dict_indexes.len()).rev() {
+            if self.dict_indexes[idx] + 1 < self.radixes[idx] {
+                self.dict_indexes[idx] += 1;
+                self.encode_from(idx);
+                return;
+            }
+            self.dict_indexes[idx] = 0;
+        }
+        self.encode_from(0);
+    }
+
+    /// This function is responsible for encoding the template again from a variable character to the end.
+    /// The byte position of each following variable character is updated, as the characters can have different sizes in bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `first_idx` - The index of the first variable character to encode.
+    ///
+    fn encode_from(&mut self, first_idx: usize) {
+        let mut utf8_buffer: [u8; 4] = [0; 4];
+        self.template.truncate(self.positions[first_idx]);
+        for idx in first_idx..self.positions.len() {
+            self.positions[idx] = self.template.len();
+            if self.radixes[idx] == BYTE_RADIX {
+                self.template.push(self.dict_indexes[idx] as u8);
+            } else {
+                let c: char = self.dict[self.dict_indexes[idx]];
+                self.template.extend_from_slice(c.encode_utf8(&mut utf8_buffer).as_bytes());
+            }
+            self.template.extend_from_slice(&self.suffixes[idx]);
+        }
+    }
+
+    /// This function is responsible for moving the iterator back to a password of the wordlist, the passwords are then streamed up to the end of the wordlist.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the password in the wordlist.
+    ///
+    fn restart(&mut self, index: u64) {
+        let nb_of_passwords: u64 = self.radixes.iter().fold(1u64, |nb_of_passwords, radix| nb_of_passwords.saturating_mul(*radix as u64));
+        self.remaining = nb_of_passwords.saturating_sub(index);
+        if self.remaining > 0 {
+            self.seek(index);
+        }
+    }
+
+    /// This function is responsible for moving the template directly to the password at the given index.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the password in the wordlist.
+    ///
+    fn seek(&mut self, index: u64) {
+        self.dict_indexes = get_dict_indexes_from_offset(index, &self.radixes);
+        if !self.positions.is_empty() {
+            self.encode_from(0);
+        }
+        self.next_index = index;
+        self.pending_tick = false;
+    }
+}
+
+impl Iterator for WordlistIterator {
+    type Item = Vec<u8>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_password().map(<[u8]>::to_vec)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n as u64 >= self.remaining {
+            self.remaining = 0;
+            return None;
+        }
+        if n > 0 {
+            self.seek(self.next_index + n as u64);
+            self.remaining -= n as u64;
+        }
+
+        self.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining: Option<usize> = usize::try_from(self.remaining).ok();
+        (remaining.unwrap_or(usize::MAX), remaining)
+    }
+}
+
+impl ExactSizeIterator for WordlistIterator {}
+
+impl FusedIterator for WordlistIterator {}
+
+/// This trait is implemented by the sources of the passwords of a wordlist, e.g. a mask (WordlistConfig), a list of words (WordsConfig), both (HybridConfig) or several lists of words (CombineConfig).
+/// All the sources share the same multi-threaded output pipeline (see `wordlist_generation_scheduler()`), each thread streams its own part of the passwords.
+///
+pub trait PasswordSource: Sync {
+    /// The stream of the passwords of a part of the wordlist, it can borrow the source.
+    type Stream<'a>: PasswordStream
+    where
+        Self: 'a;
+
+    /// This function is responsible for returning the number of passwords of the wordlist.
+    ///
+    fn get_nb_of_passwords(&self) -> u64;
+
+    /// This function is responsible for returning a stream over a part of the wordlist.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The index of the first password to stream.
+    /// * `nb_of_passwords` - The number of passwords to stream, it's truncated to the end of the wordlist.
+    ///
+    /// # Returns
+    ///
+    /// The stream of the passwords.
+    ///
+    fn get_stream(&self, offset: u64, nb_of_passwords: u64) -> Self::Stream<'_>;
+
+    /// This function is responsible for returning the average size of the passwords as written in the file, without the newline character.
+    ///
+    fn get_password_size(&self) -> f64;
+
+    /// This function is responsible for returning the hash algorithm applied to the passwords, empty to write them in plain text.
+    ///
+    fn get_hash(&self) -> &str;
+
+    /// This function is responsible for returning the separator of the raw output, None to write one password per line.
+    ///
+    fn get_raw_separator(&self) -> Option<&[u8]>;
+
+    /// This function is responsible for returning the number of consecutive passwords which should be generated by the same thread.
+    /// The wordlist is split between the threads on multiples of this number, e.g. a hybrid wordlist is split by ranges of words.
+    /// It's only a preference: the streams can start at any offset, so it's 1 when there are fewer parts than threads.
+    ///
+    /// # Arguments
+    ///
+    /// * `nb_of_threads` - The number of threads generating the wordlist.
+    ///
+    fn get_part_granularity(&self, _nb_of_threads: usize) -> u64 {
+        1
+    }
+}
+
+/// This trait is implemented by the streams of passwords of a PasswordSource.
+///
+pub trait PasswordStream {
+    /// This function is responsible for returning the next password without allocating it.
+    /// The returned slice is only valid until the next call.
+    ///
+    /// # Returns
+    ///
+    /// The bytes of the next password, None if the end of the stream has been reached.
+    ///
+    fn next_password(&mut self) -> Option<&[u8]>;
+}
+
+impl PasswordStream for WordlistIterator {
+    fn next_password(&mut self) -> Option<&[u8]> {
+        WordlistIterator::next_password(self)
+    }
+}
+
+impl PasswordSource for WordlistConfig {
+    type Stream<'a> = WordlistIterator;
+
+    fn get_nb_of_passwords(&self) -> u64 {
+        get_nb_of_passwords(self)
+    }
+
+    fn get_stream(&self, offset: u64, nb_of_passwords: u64) -> WordlistIterator {
+        WordlistIterator::with_range(self, offset, nb_of_passwords)
+    }
+
+    fn get_password_size(&self) -> f64 {
+        get_password_size(self)
+    }
+
+    fn get_hash(&self) -> &str {
+        &self.hash
+    }
+
+    fn get_raw_separator(&self) -> Option<&[u8]> {
+        self.raw_separator.as_deref()
+    }
+}
+
+/// This struct is the source of the dictionary mode: the words of a file are written to the wordlist as is, in their order.
+/// It's the base of the mutation-based generation. The words are kept as raw bytes, as a list of words may not be valid UTF-8.
+///
+#[derive(Debug, Clone)]
+pub struct WordsConfig {
+    pub words: Vec<Vec<u8>>,
+    pub hash: String,
+    pub raw_separator: Option<Vec<u8>>,
+}
+
+impl PasswordStream for std::slice::Iter<'_, Vec<u8>> {
+    fn next_password(&mut self) -> Option<&[u8]> {
+        self.next().map(Vec::as_slice)
+    }
+}
+
+impl PasswordSource for WordsConfig {
+    type Stream<'a> = std::slice::Iter<'a, Vec<u8>>;
+
+    fn get_nb_of_passwords(&self) -> u64 {
+        self.words.len() as u64
+    }
+
+    fn get_stream(&self, offset: u64, nb_of_passwords: u64) -> std::slice::Iter<'_, Vec<u8>> {
+        let first_idx: usize = (offset as usize).min(self.words.len());
+        let last_idx: usize = first_idx.saturating_add(nb_of_passwords as usize).min(self.words.len());
+        self.words[first_idx..last_idx].iter()
+    }
+
+    fn get_password_size(&self) -> f64 {
+        if self.words.is_empty() {
+            return 0.0;
+        }
+        let total_size: usize = self.words.iter().map(|word| get_written_size(word, self.raw_separator.as_deref())).sum();
+        total_size as f64 / self.words.len() as f64
+    }
+
+    fn get_hash(&self) -> &str {
+        &self.hash
+    }
+
+    fn get_raw_separator(&self) -> Option<&[u8]> {
+        self.raw_separator.as_deref()
+    }
+}
+
+/// This enum represents the position of the mask relative to the word in the hybrid mode.
+///
+/// * `Append` - The expansions of the mask are appended to the word (`word???`).
+/// * `Prepend` - The expansions of the mask are prepended to the word (`???word`).
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HybridPosition {
+    Append,
+    Prepend,
+}
+
+/// This struct is the source of the hybrid mode: each word is combined with every expansion of the mask, before or after it.
+/// The passwords are ordered by word then by expansion, so the wordlist is split between the threads by ranges of words.
+/// The hash algorithm and the raw separator of the mask config are used.
+///
+#[derive(Debug, Clone)]
+pub struct HybridConfig {
+    pub words: Vec<Vec<u8>>,
+    pub mask: WordlistConfig,
+    pub position: HybridPosition,
+}
+
+/// This struct is the stream of the passwords of a part of a HybridConfig.
+/// The odometer of the mask (a WordlistIterator) is restarted for each word.
+///
+#[derive(Debug)]
+pub struct HybridStream<'a> {
+    words: std::slice::Iter<'a, Vec<u8>>,
+    word: &'a [u8],
+    expansions: WordlistIterator,
+    position: HybridPosition,
+    password: Vec<u8>,
+    remaining: u64,
+}
+
+impl PasswordStream for HybridStream<'_> {
+    fn next_password(&mut self) -> Option<&[u8]> {
+        if self.remaining == 0 {
+            return None;
+        }
+        if self.expansions.remaining == 0 {
+            self.word = self.words.next()?;
+            self.expansions.restart(0);
+        }
+
+        let expansion: &[u8] = self.expansions.next_password()?;
+        self.password.clear();
+        match self.position {
+            HybridPosition::Append => {
+                self.password.extend_from_slice(self.word);
+                self.password.extend_from_slice(expansion);
+            }
+            HybridPosition::Prepend => {
+                self.password.extend_from_slice(expansion);
+                self.password.extend_from_slice(self.word);
+            }
+        }
+        self.remaining -= 1;
+        Some(&self.password)
+    }
+}
+
+impl PasswordSource for HybridConfig {
+    type Stream<'a> = HybridStream<'a>;
+
+    fn get_nb_of_passwords(&self) -> u64 {
+        (self.words.len() as u64).saturating_mul(get_nb_of_passwords(&self.mask))
+    }
+
+    fn get_stream(&self, offset: u64, nb_of_passwords: u64) -> HybridStream<'_> {
+        let nb_of_expansions: u64 = get_nb_of_passwords(&self.mask).max(1);
+        let first_word_idx: usize = (offset / nb_of_expansions).min(self.words.len() as u64) as usize;
+        let mut words: std::slice::Iter<'_, Vec<u8>> = self.words[first_word_idx..].iter();
+        HybridStream {
+            word: words.next().map(Vec::as_slice).unwrap_or_default(),
+            words,
+            expansions: WordlistIterator::with_range(&self.mask, offset % nb_of_expansions, u64::MAX),
+            position: self.position,
+            password: Vec::new(),
+            remaining: self.get_nb_of_passwords().saturating_sub(offset).min(nb_of_passwords),
+        }
+    }
+
+    fn get_password_size(&self) -> f64 {
+        if self.words.is_empty() {
+            return 0.0;
+        }
+        // A password is written in the $HEX[...] notation if its word or its expansion of the mask isn't printable.
+        let mask_size: f64 = get_raw_password_size(&self.mask);
+        let mask_printable_ratio: f64 = get_printable_ratio(&self.mask);
+        let total_size: f64 = self
+            .words
+            .iter()
+            .map(|word| {
+                let password_size: f64 = word.len() as f64 + mask_size;
+                let printable_ratio: f64 = if is_printable(word) { mask_printable_ratio } else { 0.0 };
+                match &self.mask.raw_separator {
+                    Some(separator) => password_size + separator.len() as f64 - 1.0,
+                    None => printable_ratio * password_size + (1.0 - printable_ratio) * (2.0 * password_size + "$HEX[]".len() as f64),
+                }
+            })
+            .sum();
+        total_size / self.words.len() as f64
+    }
+
+    fn get_hash(&self) -> &str {
+        &self.mask.hash
+    }
+
+    fn get_raw_separator(&self) -> Option<&[u8]> {
+        self.mask.raw_separator.as_deref()
+    }
+
+    fn get_part_granularity(&self, nb_of_threads: usize) -> u64 {
+        // With fewer words than threads, the words are split too, the streams start in the middle of a word.
+        if self.words.len() < nb_of_threads {
+            return 1;
+        }
+        get_nb_of_passwords(&self.mask)
+    }
+}
+
+/// This struct is the source of the combinator mode: each password is made of a word of each list, in the order of the lists, joined with the separator.
+/// The passwords are ordered like an odometer (the last list is the least significant one), so the wordlist is split between the threads by ranges of words of the first list.
+///
+#[derive(Debug, Clone)]
+pub struct CombineConfig {
+    pub lists: Vec<Vec<Vec<u8>>>,
+    pub separator: Vec<u8>,
+    pub hash: String,
+    pub raw_separator: Option<Vec<u8>>,
+}
+
+/// This struct is the stream of the passwords of a part of a CombineConfig.
+///
+#[derive(Debug)]
+pub struct CombineStream<'a> {
+    lists: &'a [Vec<Vec<u8>>],
+    separator: &'a [u8],
+    word_indexes: Vec<usize>,
+    password: Vec<u8>,
+    pending_tick: bool,
+    remaining: u64,
+}
+
+impl PasswordStream for CombineStream<'_> {
+    fn next_password(&mut self) -> Option<&[u8]> {
+        if self.remaining == 0 {
+            return None;
+        }
+        if self.pending_tick {
+            for idx in (0..self.word_indexes.len()).rev() {
+                self.word_indexes[idx] += 1;
+                if self.word_indexes[idx] < self.lists[idx].len() {
+                    break;
+                }
+                self.word_indexes[idx] = 0;
+            }
+        }
+
+        self.pending_tick = true;
+        self.password.clear();
+        for (idx, word_idx) in self.word_indexes.iter().enumerate() {
+            if idx > 0 {
+                self.password.extend_from_slice(self.separator);
+            }
+            self.password.extend_from_slice(&self.lists[idx][*word_idx]);
+        }
+        self.remaining -= 1;
+        Some(&self.password)
+    }
+}
+
+impl PasswordSource for CombineConfig {
+    type Stream<'a> = CombineStream<'a>;
+
+    fn get_nb_of_passwords(&self) -> u64 {
+        self.lists.iter().fold(1u64, |nb_of_passwords, words| nb_of_passwords.saturating_mul(words.len() as u64))
+    }
+
+    fn get_stream(&self, offset: u64, nb_of_passwords: u64) -> CombineStream<'_> {
+        let remaining: u64 = self.get_nb_of_passwords().saturating_sub(offset).min(nb_of_passwords);
+        let radixes: Vec<usize> = self.lists.iter().map(Vec::len).collect();
+        CombineStream {
+            lists: &self.lists,
+            separator: &self.separator,
+            word_indexes: if remaining > 0 { get_dict_indexes_from_offset(offset, &radixes) } else { Vec::new() },
+            password: Vec::new(),
+            pending_tick: false,
+            remaining,
+        }
+    }
+
+    fn get_password_size(&self) -> f64 {
+        if self.lists.is_empty() || self.lists.iter().any(Vec::is_empty) {
+            return 0.0;
+        }
+        // The passwords with a non-printable word are written in the $HEX[...] notation, each word of a list is used the same number of times.
+        let password_size: f64 = self.lists.iter().map(|words| words.iter().map(Vec::len).sum::<usize>() as f64 / words.len() as f64).sum::<f64>()
+            + (self.separator.len() * (self.lists.len() - 1)) as f64;
+        let printable_ratio: f64 = if is_printable(&self.separator) {
+            self.lists.iter().map(|words| words.iter().filter(|word| is_printable(word)).count() as f64 / words.len() as f64).product()
+        } else {
+            0.0
+        };
+        match &self.raw_separator {
+            Some(separator) => password_size + separator.len() as f64 - 1.0,
+            None => printable_ratio * password_size + (1.0 - printable_ratio) * (2.0 * password_size + "$HEX[]".len() as f64),
+        }
+    }
+
+    fn get_hash(&self) -> &str {
+        &self.hash
+    }
+
+    fn get_raw_separator(&self) -> Option<&[u8]> {
+        self.raw_separator.as_deref()
+    }
+
+    fn get_part_granularity(&self, nb_of_threads: usize) -> u64 {
+        // With fewer words in the first list than threads, the words are split too, the streams start in the middle of a word.
+        if self.lists.first().is_none_or(|words| words.len() < nb_of_threads) {
+            return 1;
+        }
+        self.lists.iter().skip(1).fold(1u64, |nb_of_passwords, words| nb_of_passwords.saturating_mul(words.len() as u64))
+    }
 }
 
 /// This function is responsible for building the final dictionary from the user's choices.
-/// It returns a vector of u8 containing the characters that will be used to generate the wordlist.
+/// It returns a vector of char containing the characters that will be used to generate the wordlist.
+/// The characters of the custom charset come last, without the ones already selected.
 ///
 /// # Arguments
 ///
@@ -59,25 +624,31 @@ pub struct WordlistConfig {
 ///
 /// # Returns
 ///
-/// The vector of u8 containing the characters that will be used to generate the wordlist.
+/// The vector of char containing the characters that will be used to generate the wordlist.
 ///
-fn create_wordlist_content(wordlist_values: &WordlistValues) -> Vec<u8> {
-    let mut final_dict: Vec<u8> = Vec::new();
+pub fn create_wordlist_content(wordlist_values: &WordlistValues) -> Vec<char> {
+    let mut final_dict: Vec<char> = Vec::new();
 
     if wordlist_values.uppercase {
-        final_dict.extend(dict::UPPERCASE);
+        final_dict.extend(dict::UPPERCASE.iter().map(|c| char::from(*c)));
     }
 
     if wordlist_values.lowercase {
-        final_dict.extend(dict::LOWERCASE);
+        final_dict.extend(dict::LOWERCASE.iter().map(|c| char::from(*c)));
     }
 
     if wordlist_values.numbers {
-        final_dict.extend(dict::NUMBERS);
+        final_dict.extend(dict::NUMBERS.iter().map(|c| char::from(*c)));
     }
 
     if wordlist_values.special_characters {
-        final_dict.extend(dict::SPECIAL_CHARACTERS);
+        final_dict.extend(dict::SPECIAL_CHARACTERS.iter().map(|c| char::from(*c)));
+    }
+
+    for c in wordlist_values.charset.chars() {
+        if !final_dict.contains(&c) {
+            final_dict.push(c);
+        }
     }
 
     final_dict
@@ -85,48 +656,45 @@ fn create_wordlist_content(wordlist_values: &WordlistValues) -> Vec<u8> {
 
 /// This function is responsible for converting the mask into a vector of char and indexes.
 /// This will be used to generate the wordlist.
+/// A `?` is a variable character drawn from the dictionary and `\` escapes the next character (e.g. `\?`).
+/// With `byte_placeholder`, `?b` is any byte from 0x00 to 0xFF and `?\b` is a variable character followed by a `b`.
 ///
 /// # Arguments
 ///
 /// * `mask` - The mask provided by the user.
+/// * `byte_placeholder` - Whether `?b` is a byte placeholder or a variable character followed by a `b`.
 ///
 /// # Returns
 ///
-/// A tuple containing the vector of char (formated_mask) and the vector of indexes (mask_indexes).
+/// A tuple containing the vector of char (formated_mask), the vector of indexes of all the variable characters (mask_indexes)
+/// and the vector of indexes of the bytes (byte_indexes).
 ///
-fn format_mask_to_indexes(mask: &str) -> (Vec<char>, Vec<usize>) {
+pub fn format_mask_to_indexes(mask: &str, byte_placeholder: bool) -> (Vec<char>, Vec<usize>, Vec<usize>) {
     let mut mask_indexes: Vec<usize> = Vec::new();
+    let mut byte_indexes: Vec<usize> = Vec::new();
     let mut formated_mask: Vec<char> = Vec::new();
-    let mut escaped: bool = false;
-    let mut idx_formated_mask: usize = 0;
-    for c in mask.chars() {
+    let mut chars: std::iter::Peekable<std::str::Chars> = mask.chars().peekable();
+    while let Some(c) = chars.next() {
         match c {
             '\\' => {
-                if escaped {
-                    escaped = false;
-                    formated_mask.push(c);
-                } else {
-                    escaped = true;
-                    continue;
+                if let Some(escaped_char) = chars.next() {
+                    formated_mask.push(escaped_char);
                 }
             }
             '?' => {
-                if escaped {
-                    escaped = false;
-                    formated_mask.push(c);
-                } else {
-                    mask_indexes.push(idx_formated_mask);
-                    formated_mask.push(0u8 as char);
+                if byte_placeholder && chars.next_if_eq(&'b').is_some() {
+                    byte_indexes.push(formated_mask.len());
                 }
+                mask_indexes.push(formated_mask.len());
+                formated_mask.push(0u8 as char);
             }
             _ => {
                 formated_mask.push(c);
             }
         }
-        idx_formated_mask += 1;
     }
 
-    (formated_mask, mask_indexes)
+    (formated_mask, mask_indexes, byte_indexes)
 }
 
 /// This function is responsible for building the WordlistValues struct from the user's values.
@@ -140,21 +708,294 @@ fn format_mask_to_indexes(mask: &str) -> (Vec<char>, Vec<usize>) {
 /// The WordlistConfig struct containing the settings of the wordlist.
 ///
 pub fn build_wordlist_config(wordlist_values: &WordlistValues) -> WordlistConfig {
-    let dict: Vec<u8> = create_wordlist_content(wordlist_values);
-    let (formated_mask, mask_indexes) = format_mask_to_indexes(&wordlist_values.mask);
+    let dict: Vec<char> = create_wordlist_content(wordlist_values);
+    let (formated_mask, mask_indexes, byte_indexes) = format_mask_to_indexes(&wordlist_values.mask, wordlist_values.byte_placeholder);
     WordlistConfig {
         dict,
         mask_indexes,
+        byte_indexes,
         formated_mask,
         hash: wordlist_values.hash.clone(),
+        raw_separator: wordlist_values.raw_separator.clone(),
     }
 }
 
-/// This function is responsible for scheduling the wordlist generation.
+/// This function is responsible for computing the number of passwords of the wordlist (its keyspace).
+///
+/// # Arguments
+///
+/// * `wordlist_config` - The WordlistConfig struct containing the settings of the wordlist.
+///
+/// # Returns
+///
+/// The number of passwords of the wordlist, saturated to u64::MAX if the keyspace is too large.
+///
+pub fn get_nb_of_passwords(wordlist_config: &WordlistConfig) -> u64 {
+    get_radixes(wordlist_config)
+        .into_iter()
+        .fold(1u64, |nb_of_passwords, radix| nb_of_passwords.saturating_mul(radix as u64))
+}
+
+/// This function is responsible for returning the number of values of each variable character of the mask.
 ///
 /// # Arguments
 ///
 /// * `wordlist_config` - The WordlistConfig struct containing the settings of the wordlist.
+///
+/// # Returns
+///
+/// The vector of the number of values of each variable character, BYTE_RADIX for a byte and the size of the dictionary otherwise.
+///
+fn get_radixes(wordlist_config: &WordlistConfig) -> Vec<usize> {
+    wordlist_config
+        .mask_indexes
+        .iter()
+        .map(|idx| {
+            if wordlist_config.byte_indexes.contains(idx) {
+                BYTE_RADIX
+            } else {
+                wordlist_config.dict.len()
+            }
+        })
+        .collect()
+}
+
+/// This function is responsible for formatting a password to write it on a line of the wordlist.
+/// The passwords that aren't valid UTF-8, contain a control character or start with `$HEX[` are written in hashcat's `$HEX[...]` notation,
+/// the other ones are written as is.
+///
+/// # Arguments
+///
+/// * `password` - The raw bytes of the password.
+/// * `buffer` - The buffer where the password is appended.
+///
+pub fn format_password(password: &[u8], buffer: &mut Vec<u8>) {
+    if is_printable(password) {
+        buffer.extend_from_slice(password);
+        return;
+    }
+
+    buffer.extend_from_slice(b"$HEX[");
+    for byte in password {
+        buffer.extend_from_slice(format!("{:02x}", byte).as_bytes());
+    }
+    buffer.push(b']');
+}
+
+/// This function is responsible for checking if a password can be written as is on a line of the wordlist (see `format_password()`).
+///
+/// # Arguments
+///
+/// * `password` - The raw bytes of the password.
+///
+/// # Returns
+///
+/// true if the password is valid UTF-8 without any control character and doesn't start with `$HEX[`, false otherwise.
+///
+fn is_printable(password: &[u8]) -> bool {
+    std::str::from_utf8(password).is_ok_and(|password| !password.starts_with("$HEX[") && !password.chars().any(char::is_control))
+}
+
+/// This function is responsible for computing the size of a password once written in the wordlist, in the same unit as `get_password_size()`.
+///
+/// # Arguments
+///
+/// * `password` - The raw bytes of the password.
+/// * `raw_separator` - The separator of the raw output, None if the passwords are written one per line.
+///
+/// # Returns
+///
+/// The size in bytes, without the newline character.
+///
+pub fn get_written_size(password: &[u8], raw_separator: Option<&[u8]>) -> usize {
+    match raw_separator {
+        Some(separator) => password.len() + separator.len() - 1,
+        None if is_printable(password) => password.len(),
+        None => 2 * password.len() + "$HEX[]".len(),
+    }
+}
+
+/// This function is responsible for loading the words of the dictionary mode from a file.
+///
+/// # Arguments
+///
+/// * `path` - The path of the file containing the words, one word per line.
+/// * `unique` - A boolean to specify if the duplicates must be removed.
+///
+/// # Returns
+///
+/// The vector of the words, WorgenXError if the file can't be read or doesn't contain any word.
+///
+pub fn load_words(path: &str, unique: bool) -> Result<Vec<Vec<u8>>, WorgenXError> {
+    let content: Vec<u8> = fs::read(path).map_err(|e| WorgenXError::SystemError(SystemError::UnableToReadFile(path.to_string(), e.to_string())))?;
+    let words: Vec<Vec<u8>> = parse_words(&content, unique);
+    if words.is_empty() {
+        return Err(WorgenXError::ArgError(ArgError::EmptyWordlist(path.to_string())));
+    }
+
+    Ok(words)
+}
+
+/// This function is responsible for extracting the words of the dictionary mode from the content of a file.
+/// Each line is a word, the line endings (`\n` or `\r\n`) are removed and the empty lines are skipped.
+/// The lines in hashcat's `$HEX[...]` notation are decoded, so a wordlist generated by WorgenX can be used as input.
+///
+/// # Arguments
+///
+/// * `content` - The raw content of the file, it may not be valid UTF-8.
+/// * `unique` - A boolean to specify if the duplicates must be removed, the first occurrence of each word is kept.
+///
+/// # Returns
+///
+/// The vector of the words, in the order of the file.
+///
+pub fn parse_words(content: &[u8], unique: bool) -> Vec<Vec<u8>> {
+    let mut seen_words: HashSet<Vec<u8>> = HashSet::new();
+    content
+        .split(|byte| *byte == b'\n')
+        .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+        .filter(|line| !line.is_empty())
+        .map(|line| parse_hex_notation(line).unwrap_or_else(|| line.to_vec()))
+        .filter(|word| !unique || seen_words.insert(word.clone()))
+        .collect()
+}
+
+/// This function is responsible for decoding a word written in hashcat's `$HEX[...]` notation.
+///
+/// # Arguments
+///
+/// * `line` - The line of the file.
+///
+/// # Returns
+///
+/// The decoded bytes, None if the line isn't a valid `$HEX[...]` notation.
+///
+fn parse_hex_notation(line: &[u8]) -> Option<Vec<u8>> {
+    let hex: &[u8] = line.strip_prefix(b"$HEX[")?.strip_suffix(b"]")?;
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+
+    hex.chunks(2)
+        .map(|pair| std::str::from_utf8(pair).ok().and_then(|pair| u8::from_str_radix(pair, 16).ok()))
+        .collect()
+}
+
+/// This function is responsible for parsing the separator written after each password in the raw output.
+/// The escape sequences `\n`, `\r`, `\t`, `\0`, `\\` and `\xHH` (any byte) are supported, the other characters are used as is.
+///
+/// # Arguments
+///
+/// * `separator` - The separator given by the user.
+///
+/// # Returns
+///
+/// The bytes of the separator, WorgenXError if an escape sequence is invalid.
+///
+pub fn parse_separator(separator: &str) -> Result<Vec<u8>, WorgenXError> {
+    let invalid_separator = || WorgenXError::ArgError(ArgError::InvalidSeparator(separator.to_string()));
+    let mut bytes: Vec<u8> = Vec::new();
+    let mut chars: std::str::Chars = separator.chars();
+    let mut utf8_buffer: [u8; 4] = [0; 4];
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            bytes.extend_from_slice(c.encode_utf8(&mut utf8_buffer).as_bytes());
+            continue;
+        }
+        bytes.push(match chars.next() {
+            Some('n') => b'\n',
+            Some('r') => b'\r',
+            Some('t') => b'\t',
+            Some('0') => 0,
+            Some('\\') => b'\\',
+            Some('x') => {
+                let hex: String = chars.by_ref().take(2).collect();
+                if hex.len() != 2 {
+                    return Err(invalid_separator());
+                }
+                u8::from_str_radix(&hex, 16).map_err(|_| invalid_separator())?
+            }
+            _ => return Err(invalid_separator()),
+        });
+    }
+
+    Ok(bytes)
+}
+
+/// This function is responsible for computing the average size of the passwords of the wordlist in bytes, once encoded in UTF-8.
+/// Each character of the dictionary is used the same number of times at each variable position of the whole wordlist.
+/// With a byte placeholder, only the share of the passwords which aren't printable is counted in the `$HEX[...]` notation, unless they are written raw.
+/// The size of the raw separator replaces the size of the newline character.
+///
+/// # Arguments
+///
+/// * `wordlist_config` - The WordlistConfig struct containing the settings of the wordlist.
+///
+/// # Returns
+///
+/// The average size of the passwords in bytes, without the newline character.
+///
+pub fn get_password_size(wordlist_config: &WordlistConfig) -> f64 {
+    let password_size: f64 = get_raw_password_size(wordlist_config);
+    match &wordlist_config.raw_separator {
+        Some(separator) => password_size + separator.len() as f64 - 1.0,
+        None => {
+            let printable_ratio: f64 = get_printable_ratio(wordlist_config);
+            printable_ratio * password_size + (1.0 - printable_ratio) * (2.0 * password_size + "$HEX[]".len() as f64)
+        }
+    }
+}
+
+/// This function is responsible for computing the share of the passwords of the wordlist which are printable, i.e. written as is and not in the `$HEX[...]` notation.
+/// A byte placeholder is printable if it's a printable ASCII character, as a lone byte above 0x7F isn't valid UTF-8.
+/// The share is exact with isolated byte placeholders and a lower bound with adjacent ones, as they may form a multi-byte character.
+///
+/// # Arguments
+///
+/// * `wordlist_config` - The WordlistConfig struct containing the settings of the wordlist.
+///
+/// # Returns
+///
+/// The share of the printable passwords, between 0 and 1.
+///
+fn get_printable_ratio(wordlist_config: &WordlistConfig) -> f64 {
+    (NB_OF_PRINTABLE_BYTES / BYTE_RADIX as f64).powi(wordlist_config.byte_indexes.len() as i32)
+}
+
+/// This function is responsible for computing the average size of the raw bytes of the passwords of the wordlist, before any notation or separator.
+///
+/// # Arguments
+///
+/// * `wordlist_config` - The WordlistConfig struct containing the settings of the wordlist.
+///
+/// # Returns
+///
+/// The average size of the raw passwords in bytes.
+///
+fn get_raw_password_size(wordlist_config: &WordlistConfig) -> f64 {
+    let fixed_size: usize = wordlist_config
+        .formated_mask
+        .iter()
+        .enumerate()
+        .filter(|(idx, _)| wordlist_config.mask_indexes.binary_search(idx).is_err())
+        .map(|(_, c)| c.len_utf8())
+        .sum();
+    let average_char_size: f64 = if wordlist_config.dict.is_empty() {
+        0.0
+    } else {
+        wordlist_config.dict.iter().map(|c| c.len_utf8()).sum::<usize>() as f64 / wordlist_config.dict.len() as f64
+    };
+    let nb_of_bytes: usize = wordlist_config.byte_indexes.len();
+    let nb_of_chars: usize = wordlist_config.mask_indexes.len() - nb_of_bytes;
+    fixed_size as f64 + nb_of_chars as f64 * average_char_size + nb_of_bytes as f64
+}
+
+/// This function is responsible for scheduling the wordlist generation.
+/// Each call works on its own run context, so several wordlists can be generated in the same process.
+///
+/// # Arguments
+///
+/// * `source` - The source of the passwords, e.g. a WordlistConfig struct or a WordsConfig struct.
 /// * `nb_of_passwords` - The number of passwords to generate.
 /// * `nb_of_threads` - The number of threads to use.
 /// * `file_path` - The path of the file where the wordlist will be saved.
@@ -164,35 +1005,18 @@ pub fn build_wordlist_config(wordlist_values: &WordlistValues) -> WordlistConfig
 ///
 /// Ok(()) if the wordlist generation is successful, WorgenXError otherwise.
 ///
-pub fn wordlist_generation_scheduler(
-    wordlist_config: &WordlistConfig,
+pub fn wordlist_generation_scheduler<S: PasswordSource>(
+    source: &S,
     nb_of_passwords: u64,
     nb_of_threads: usize,
     file_path: &str,
     no_loading_bar: bool,
 ) -> Result<(), WorgenXError> {
-    let pb: Arc<Mutex<indicatif::ProgressBar>> = Arc::new(Mutex::new(system::get_progress_bar()));
-    let pb_clone: Arc<Mutex<indicatif::ProgressBar>> = Arc::clone(&pb);
+    let context: RunContext = RunContext::new(ProgressGoal::Passwords(nb_of_passwords), no_loading_bar);
     let start: Instant = Instant::now();
-    
-    let main_thread: JoinHandle<Result<(), WorgenXError>> = thread::spawn(move || {
-        while GLOBAL_COUNTER.load(Ordering::SeqCst) < nb_of_passwords {
-            if !no_loading_bar {
-                build_wordlist_progress_bar(GLOBAL_COUNTER.load(Ordering::SeqCst), nb_of_passwords, &pb_clone);
-            }
-            thread::sleep(std::time::Duration::from_millis(100));
-        }
-        Ok(())
-    });
-
-    run_wordlist_generation(wordlist_config, nb_of_passwords, nb_of_threads, file_path)?;
-    if let Err(e) = main_thread.join() {
-        if let Some(err) = e.downcast_ref::<WorgenXError>() {
-            return Err(err.clone());
-        }
 
-        return Err(WorgenXError::SystemError(SystemError::ThreadError(format!("{:?}", e))));
-    }
+    run_wordlist_generation(source, nb_of_passwords, nb_of_threads, file_path, &context)?;
+    context.progress.finish("Wordlist generated");
 
     println!("\nWordlist generated in {}", system::get_elapsed_time(start));
     Ok(())
@@ -202,26 +1026,23 @@ pub fn wordlist_generation_scheduler(
 ///
 /// # Arguments
 ///
-/// * `wordlist_config` - The WordlistConfig struct containing the settings of the wordlist.
+/// * `source` - The source of the passwords, shared between the threads.
 /// * `nb_of_passwords` - The number of passwords to generate.
 /// * `nb_of_threads` - The number of threads to use.
 /// * `file_path` - The path of the file where the wordlist will be saved.
+/// * `context` - The context of the run shared between the threads.
 ///
 /// # Returns
 ///
 /// Ok(()) if the wordlist generation is successful, WorgenXError otherwise.
 ///
-fn run_wordlist_generation(
-    wordlist_config: &WordlistConfig,
+fn run_wordlist_generation<S: PasswordSource>(
+    source: &S,
     nb_of_passwords: u64,
     nb_of_threads: usize,
     file_path: &str,
+    context: &RunContext,
 ) -> Result<(), WorgenXError> {
-    let shared_formated_mask: Arc<Vec<char>> = Arc::new(wordlist_config.formated_mask.clone());
-    let shared_mask_indexes: Arc<Vec<usize>> = Arc::new(wordlist_config.mask_indexes.clone());
-    let dict_size: usize = wordlist_config.dict.len();
-    let shared_dict: Arc<Vec<u8>> = Arc::new(wordlist_config.dict.clone());
-
     let file: File = OpenOptions::new()
         .write(true)
         .create(true)
@@ -233,170 +1054,178 @@ fn run_wordlist_generation(
         )))?;
 
     let shared_file: Arc<Mutex<File>> = Arc::new(Mutex::new(file));
-    let mut threads: Vec<JoinHandle<Result<(), WorgenXError>>> = Vec::new();
-    let dict_indexes: Vec<usize> = vec![0; wordlist_config.mask_indexes.len()];
-    let mut nb_of_passwd_per_thread: u64 = nb_of_passwords / nb_of_threads as u64;
-    let nb_of_passwd_last_thread: u64 = nb_of_passwd_per_thread + nb_of_passwords % nb_of_threads as u64;
-    let mut temp: Vec<usize> = dict_indexes;
-
-    for i in 0..nb_of_threads {
-        if i == nb_of_threads - 1 {
-            nb_of_passwd_per_thread = nb_of_passwd_last_thread;
+    // The library can be called with 0 threads, at least one thread generates the wordlist.
+    let nb_of_threads: usize = nb_of_threads.max(1);
+    // The threads get ranges of parts, e.g. ranges of words in the hybrid mode, the last thread also gets the remaining parts.
+    let granularity: u64 = source.get_part_granularity(nb_of_threads).max(1);
+    let nb_of_parts_per_thread: u64 = nb_of_passwords.div_ceil(granularity) / nb_of_threads as u64;
+
+    thread::scope(|scope| {
+        let threads: Vec<ScopedJoinHandle<Result<(), WorgenXError>>> = (0..nb_of_threads)
+            .map(|i| {
+                let offset: u64 = (i as u64 * nb_of_parts_per_thread).saturating_mul(granularity).min(nb_of_passwords);
+                let nb_of_passwords: u64 = if i == nb_of_threads - 1 {
+                    nb_of_passwords - offset
+                } else {
+                    nb_of_parts_per_thread.saturating_mul(granularity).min(nb_of_passwords - offset)
+                };
+                let file: &Arc<Mutex<File>> = &shared_file;
+                scope.spawn(move || {
+                    let result: Result<(), WorgenXError> = generate_wordlist_part(nb_of_passwords, offset, source, file, context);
+                    if result.is_err() {
+                        context.stop(); // Stop the other threads of the run
+                    }
+                    result
+                })
+            })
+            .collect();
+
+        let mut result: Result<(), WorgenXError> = Ok(());
+        for thread in threads {
+            match thread.join() {
+                Ok(Ok(())) => (),
+                Ok(Err(e)) => {
+                    if result.is_ok() {
+                        result = Err(e);
+                    }
+                }
+                Err(_) => {
+                    return Err(WorgenXError::SystemError(SystemError::ThreadError(
+                        "wordlist generation".to_string(),
+                    )))
+                }
+            }
         }
 
-        let shared_formated_mask: Arc<Vec<char>> = Arc::clone(&shared_formated_mask);
-        let shared_mask_indexes: Arc<Vec<usize>> = Arc::clone(&shared_mask_indexes);
-        let shared_dict: Arc<Vec<u8>> = Arc::clone(&shared_dict);
-        let shared_hash: String = wordlist_config.hash.clone();
-        let file: Arc<Mutex<File>> = Arc::clone(&shared_file);
-        let temp_clone: Vec<usize> = temp.clone();
-        let thread: JoinHandle<Result<(), WorgenXError>> = thread::spawn(move || {
-            generate_wordlist_part(
-                nb_of_passwd_per_thread,
-                temp_clone,
-                &shared_formated_mask,
-                &shared_mask_indexes,
-                &shared_dict,
-                &file,
-                &shared_hash,
-            )
-        });
-        threads.push(thread);
+        result
+    })
+}
 
-        for _ in 0..nb_of_passwd_per_thread {
-            for idx in (0..temp.len()).rev() {
-                if temp[idx] < dict_size - 1 {
-                    temp[idx] += 1;
-                    break;
-                }
-                temp[idx] = 0;
-            }
+/// This function is responsible for computing the state of the dictionary indexes after a given number of passwords.
+/// The indexes work like an odometer: the last index is the least significant one.
+/// It allows each thread to start directly at its own offset in the wordlist.
+///
+/// # Arguments
+///
+/// * `offset` - The number of passwords generated before the wanted one.
+/// * `radixes` - The number of values of each variable character in the mask (see `get_radixes()`).
+///
+/// # Returns
+///
+/// The vector of indexes of the dictionary (or of the byte values) corresponding to the offset.
+///
+fn get_dict_indexes_from_offset(mut offset: u64, radixes: &[usize]) -> Vec<usize> {
+    let mut dict_indexes: Vec<usize> = vec![0; radixes.len()];
+    for idx in (0..radixes.len()).rev() {
+        if offset == 0 {
+            break;
         }
+        dict_indexes[idx] = (offset % radixes[idx] as u64) as usize;
+        offset /= radixes[idx] as u64;
     }
 
-    for thread in threads {
-        if thread.join().is_err() {
-            return Err(WorgenXError::SystemError(SystemError::ThreadError(
-                "wordlist generation".to_string(),
-            )))
+    dict_indexes
+}
+
+/// This function is responsible for converting the formated mask into the fixed parts of the template of bytes.
+/// The variable characters will be encoded between these parts during the wordlist generation.
+///
+/// # Arguments
+///
+/// * `formated_mask` - The final mask.
+/// * `mask_indexes` - The indexes of the mask.
+///
+/// # Returns
+///
+/// A tuple containing the UTF-8 bytes of the mask before the first variable character
+/// and the UTF-8 bytes of the fixed characters following each variable character.
+///
+fn build_template(formated_mask: &[char], mask_indexes: &[usize]) -> (Vec<u8>, Vec<Vec<u8>>) {
+    let mut template: Vec<u8> = Vec::with_capacity(formated_mask.len());
+    let mut suffixes: Vec<Vec<u8>> = Vec::with_capacity(mask_indexes.len());
+    let mut utf8_buffer: [u8; 4] = [0; 4];
+
+    for (i, c) in formated_mask.iter().enumerate() {
+        if mask_indexes.binary_search(&i).is_ok() {
+            suffixes.push(Vec::new());
+            continue;
+        }
+        let bytes: &[u8] = c.encode_utf8(&mut utf8_buffer).as_bytes();
+        match suffixes.last_mut() {
+            Some(suffix) => suffix.extend_from_slice(bytes),
+            None => template.extend_from_slice(bytes),
         }
     }
 
-    Ok(())
+    (template, suffixes)
 }
 
 /// This function is responsible for generating a part of the wordlist or the whole wordlist if there is only one thread.
-/// It sends the progress value through the channel (to update the progess bar).
+/// The passwords come from the stream of the source and are appended to a buffer which is written to the file once it's full.
+/// The progress reporter of the run is updated each time the buffer is written.
+/// The generation ends early if the run has been stopped by another thread.
 ///
 /// # Arguments
 ///
 /// * `nb_of_passwd` - The number of passwords to generate.
-/// * `dict_indexes` - The indexes of the dictionary.
-/// * `formated_mask` - The final mask.
-/// * `mask_indexes` - The indexes of the mask.
-/// * `dict` - The dictionary.
+/// * `offset` - The index of the first password to generate.
+/// * `source` - The source of the passwords.
 /// * `file` - The file to write to, wrapped in an `Arc<Mutex<File>>`.
-/// * `hash` - The hash algorithm to use, if any.
+/// * `context` - The context of the run.
 ///
 /// # Returns
 ///
 /// Ok(()) if the wordlist generation is successful, WorgenXError otherwise.
 ///
-fn generate_wordlist_part(
+fn generate_wordlist_part<S: PasswordSource>(
     nb_of_passwords: u64,
-    mut dict_indexes: Vec<usize>,
-    formated_mask: &[char],
-    mask_indexes: &[usize],
-    dict: &[u8],
+    offset: u64,
+    source: &S,
     file: &Arc<Mutex<File>>,
-    hash: &str,
+    context: &RunContext,
 ) -> Result<(), WorgenXError> {
-    let mut buffer: Vec<String> = Vec::new();
-    let mut line: Vec<char> = Vec::with_capacity(formated_mask.len());
-
-    // This closure is used to hash the password if the user has specified a hash algorithm.
-    let process_line: Box<dyn Fn(String) -> Result<String, WorgenXError>> = if hash.is_empty() {
-        Box::new(|line_str: String| -> Result<String, WorgenXError> { Ok(line_str) })
-    } else {
-        Box::new(|line_str: String| -> Result<String, WorgenXError> {
-            match system::manage_hash(&line_str, hash) {
-                Ok(hashed_passwd) => Ok(hashed_passwd),
-                Err(e) => Err(WorgenXError::SystemError(e)),
-            }
-        })
-    };
-
-    for _ in 0..nb_of_passwords {
-        line.clear();
-
-        (0..formated_mask.len()).for_each(|i| {
-            let mut found: bool = false;
-            for idx in 0..mask_indexes.len() {
-                if i == mask_indexes[idx] {
-                    found = true;
-                    line.push(dict[dict_indexes[idx]] as char);
-                    break;
-                }
-            }
-
-            if !found {
-                line.push(formated_mask[i])
-            }
-        });
-        for idx in (0..dict_indexes.len()).rev() {
-            if dict_indexes[idx] < dict.len() - 1 {
-                dict_indexes[idx] += 1;
-                break;
-            }
-            dict_indexes[idx] = 0;
+    let hash: &str = source.get_hash();
+    let mut passwords: S::Stream<'_> = source.get_stream(offset, nb_of_passwords);
+    let raw_separator: Option<&[u8]> = source.get_raw_separator();
+    let separator: &[u8] = raw_separator.unwrap_or(b"\n");
+    let mut buffer: Vec<u8> = Vec::with_capacity(BUFFER_SIZE);
+    let mut nb_of_passwd_in_buffer: u64 = 0;
+
+    while let Some(password) = passwords.next_password() {
+        if !hash.is_empty() {
+            // The raw bytes are hashed, not their $HEX[...] notation.
+            let hashed_passwd: String = system::manage_hash(password, hash).map_err(WorgenXError::SystemError)?;
+            buffer.extend_from_slice(hashed_passwd.as_bytes());
+        } else if raw_separator.is_some() {
+            buffer.extend_from_slice(password);
+        } else {
+            format_password(password, &mut buffer);
         }
+        buffer.extend_from_slice(separator);
+        nb_of_passwd_in_buffer += 1;
 
-        buffer.push(process_line(line.iter().collect::<String>())?);
-        GLOBAL_COUNTER.fetch_add(1, Ordering::SeqCst);
-
-        if buffer.len() == BUFFER_SIZE {
-            system::save_passwd_to_file(&Arc::clone(file), &buffer.join("\n"))?;
+        if buffer.len() >= BUFFER_SIZE {
+            system::save_buffer_to_file(file, &buffer)?;
+            context.progress.add(nb_of_passwd_in_buffer, buffer.len() as u64);
+            nb_of_passwd_in_buffer = 0;
             buffer.clear();
+            if context.is_stopped() {
+                return Ok(());
+            }
         }
     }
 
     if !buffer.is_empty() {
-        system::save_passwd_to_file(&Arc::clone(file), &buffer.join("\n"))?;
+        system::save_buffer_to_file(file, &buffer)?;
+        context.progress.add(nb_of_passwd_in_buffer, buffer.len() as u64);
     }
     Ok(())
 }
 
-/// This function is responsible for building the progress bar during the wordlist generation.
-///
-/// # Arguments
-///
-/// * `nb_of_passwd_generated` - The number of passwords generated.
-/// * `total_nb_of_passwd` - The total number of passwords to generate.
-/// * `pb` - The progress bar instance (from the indicatif crate).
-///
-pub fn build_wordlist_progress_bar(
-    nb_of_passwd_generated: u64,
-    total_nb_of_passwd: u64,
-    pb: &Arc<Mutex<ProgressBar>>,
-) {
-    let mut pourcentage: u64 = (nb_of_passwd_generated * 100) / total_nb_of_passwd;
-    if pourcentage == 0 {
-        pourcentage += 1;
-    }
-    if let Ok(pb) = pb.try_lock() {
-        if nb_of_passwd_generated < total_nb_of_passwd {
-            pb.set_position(pourcentage);
-            pb.set_message("Loading...");
-        } else {
-            pb.set_position(100);
-            pb.finish_with_message(String::from("Wordlist generated"));
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::thread::JoinHandle;
 
     #[test]
     fn test_create_wordlist_content() {
@@ -405,19 +1234,34 @@ mod tests {
             special_characters: true,
             uppercase: true,
             lowercase: true,
+            charset: String::new(),
             mask: String::from("????"),
             hash: String::from(""),
+            raw_separator: None,
+            byte_placeholder: false,
         };
-        let result: Vec<u8> = create_wordlist_content(&wordlist_values);
+        let result: Vec<char> = create_wordlist_content(&wordlist_values);
         assert_eq!(result.len(), 91);
     }
 
     #[test]
     fn test_format_mask_to_indexes() {
         let mask: String = String::from("????");
-        let (formated_mask, mask_indexes) = format_mask_to_indexes(&mask);
+        let (formated_mask, mask_indexes, byte_indexes) = format_mask_to_indexes(&mask, false);
         assert_eq!(formated_mask, vec!['\0', '\0', '\0', '\0']);
         assert_eq!(mask_indexes, vec![0, 1, 2, 3]);
+        assert!(byte_indexes.is_empty());
+
+        let (formated_mask, mask_indexes, byte_indexes) = format_mask_to_indexes("?b?\\b\\?\\\\a?b", true);
+        assert_eq!(formated_mask, vec!['\0', '\0', 'b', '?', '\\', 'a', '\0']);
+        assert_eq!(mask_indexes, vec![0, 1, 6]);
+        assert_eq!(byte_indexes, vec![0, 6]);
+
+        // Without the byte placeholder, the masks written before it keep their meaning.
+        let (formated_mask, mask_indexes, byte_indexes) = format_mask_to_indexes("pass?b??b", false);
+        assert_eq!(formated_mask, vec!['p', 'a', 's', 's', '\0', 'b', '\0', '\0', 'b']);
+        assert_eq!(mask_indexes, vec![4, 6, 7]);
+        assert!(byte_indexes.is_empty());
     }
 
     #[test]
@@ -427,8 +1271,11 @@ mod tests {
             special_characters: true,
             uppercase: true,
             lowercase: true,
+            charset: String::new(),
             mask: String::from("????"),
             hash: String::from(""),
+            raw_separator: None,
+            byte_placeholder: false,
         };
         let wordlist_config: WordlistConfig = build_wordlist_config(&wordlist_values);
         assert_eq!(wordlist_config.dict.len(), 91);
@@ -436,24 +1283,483 @@ mod tests {
         assert_eq!(wordlist_config.formated_mask, vec!['\0', '\0', '\0', '\0']);
     }
 
+    #[test]
+    fn test_get_nb_of_passwords() {
+        let (formated_mask, mask_indexes, byte_indexes) = format_mask_to_indexes("a??\\?", false);
+        let wordlist_config: WordlistConfig = WordlistConfig {
+            dict: dict::NUMBERS.iter().map(|c| char::from(*c)).collect(),
+            mask_indexes,
+            byte_indexes,
+            formated_mask,
+            hash: String::new(),
+            raw_separator: None,
+        };
+        assert_eq!(get_nb_of_passwords(&wordlist_config), 100);
+    }
+
+    #[test]
+    fn test_wordlist_iterator() {
+        let (formated_mask, mask_indexes, byte_indexes) = format_mask_to_indexes("?-?", false);
+        let wordlist_config: WordlistConfig = WordlistConfig {
+            dict: vec!['a', 'b', 'c'],
+            mask_indexes,
+            byte_indexes,
+            formated_mask,
+            hash: String::new(),
+            raw_separator: None,
+        };
+        let mut passwords: WordlistIterator = WordlistIterator::new(&wordlist_config);
+        assert_eq!(passwords.len(), 9);
+        assert_eq!(passwords.next(), Some(b"a-a".to_vec()));
+        assert_eq!(passwords.next(), Some(b"a-b".to_vec()));
+        assert_eq!(passwords.len(), 7);
+        assert_eq!(passwords.nth(3), Some(b"b-c".to_vec()));
+        assert_eq!(passwords.next_password(), Some(&b"c-a"[..]));
+        assert_eq!(passwords.nth(1), Some(b"c-c".to_vec()));
+        assert_eq!(passwords.next(), None);
+
+        let mut passwords: WordlistIterator = WordlistIterator::new(&wordlist_config);
+        assert_eq!(passwords.nth(9), None);
+        assert_eq!(passwords.next(), None);
+
+        let passwords: Vec<Vec<u8>> = WordlistIterator::with_range(&wordlist_config, 7, 10).collect();
+        assert_eq!(passwords, vec![b"c-b".to_vec(), b"c-c".to_vec()]);
+    }
+
+    #[test]
+    fn test_wordlist_iterator_without_variable_character() {
+        let (formated_mask, mask_indexes, byte_indexes) = format_mask_to_indexes("abc\\?", false);
+        let wordlist_config: WordlistConfig = WordlistConfig {
+            dict: dict::NUMBERS.iter().map(|c| char::from(*c)).collect(),
+            mask_indexes,
+            byte_indexes,
+            formated_mask,
+            hash: String::new(),
+            raw_separator: None,
+        };
+        let passwords: Vec<Vec<u8>> = WordlistIterator::new(&wordlist_config).collect();
+        assert_eq!(passwords, vec![b"abc?".to_vec()]);
+    }
+
+    #[test]
+    fn test_get_dict_indexes_from_offset() {
+        assert_eq!(get_dict_indexes_from_offset(0, &[4; 4]), vec![0, 0, 0, 0]);
+        assert_eq!(get_dict_indexes_from_offset(10, &[4; 4]), vec![0, 0, 2, 2]);
+        assert_eq!(get_dict_indexes_from_offset(255, &[4; 4]), vec![3, 3, 3, 3]);
+    }
+
+    #[test]
+    fn test_build_template() {
+        let (formated_mask, mask_indexes, _) = format_mask_to_indexes("é?\\b?", false);
+        let (template, suffixes) = build_template(&formated_mask, &mask_indexes);
+        assert_eq!(template, vec![0xC3, 0xA9]);
+        assert_eq!(suffixes, vec![vec![b'b'], vec![]]);
+    }
+
+    #[test]
+    fn test_wordlist_iterator_with_unicode_charset() {
+        let wordlist_values: WordlistValues = WordlistValues {
+            numbers: false,
+            special_characters: false,
+            uppercase: false,
+            lowercase: false,
+            charset: String::from("aжaé🔑"),
+            mask: String::from("ü?-?"),
+            hash: String::new(),
+            raw_separator: None,
+            byte_placeholder: false,
+        };
+        let wordlist_config: WordlistConfig = build_wordlist_config(&wordlist_values);
+        assert_eq!(wordlist_config.dict, vec!['a', 'ж', 'é', '🔑']);
+
+        let passwords: Vec<String> = WordlistIterator::new(&wordlist_config).map(|password| String::from_utf8(password).unwrap()).collect();
+        assert_eq!(passwords.len(), 16);
+        assert_eq!(&passwords[..5], ["üa-a", "üa-ж", "üa-é", "üa-🔑", "üж-a"]);
+        assert_eq!(passwords[15], "ü🔑-🔑");
+        let mut passwords_from_offset: WordlistIterator = WordlistIterator::with_range(&wordlist_config, 7, 2);
+        assert_eq!(passwords_from_offset.next_password(), Some(passwords[7].as_bytes()));
+        assert_eq!(passwords_from_offset.next_password(), Some(passwords[8].as_bytes()));
+
+        let total_size: usize = passwords.iter().map(String::len).sum();
+        assert_eq!(get_password_size(&wordlist_config) * 16.0, total_size as f64);
+    }
+
+    #[test]
+    fn test_wordlist_iterator_with_bytes() {
+        let (formated_mask, mask_indexes, byte_indexes) = format_mask_to_indexes("?b-?", true);
+        let wordlist_config: WordlistConfig = WordlistConfig {
+            dict: vec!['a', 'é'],
+            mask_indexes,
+            byte_indexes,
+            formated_mask,
+            hash: String::new(),
+            raw_separator: None,
+        };
+        assert_eq!(get_nb_of_passwords(&wordlist_config), 512);
+
+        let passwords: Vec<Vec<u8>> = WordlistIterator::new(&wordlist_config).collect();
+        assert_eq!(passwords[0], vec![0x00, b'-', b'a']);
+        assert_eq!(passwords[1], vec![0x00, b'-', 0xC3, 0xA9]);
+        assert_eq!(passwords[2], vec![0x01, b'-', b'a']);
+        assert_eq!(passwords[511], vec![0xFF, b'-', 0xC3, 0xA9]);
+        assert_eq!(WordlistIterator::with_range(&wordlist_config, 300, 1).next(), Some(vec![150, b'-', b'a']));
+
+        // Only the passwords with a non-printable byte are written in the $HEX[...] notation.
+        let total_size: usize = passwords.iter().map(|password| get_written_size(password, None)).sum();
+        assert!((get_password_size(&wordlist_config) * 512.0 - total_size as f64).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_format_password() {
+        let mut buffer: Vec<u8> = Vec::new();
+        format_password(b"abc", &mut buffer);
+        format_password("pâté".as_bytes(), &mut buffer);
+        assert_eq!(buffer, "abcpâté".as_bytes());
+
+        for (password, expected) in [
+            (&b"a\x00b"[..], "$HEX[610062]"),
+            (&b"\xff\xfe"[..], "$HEX[fffe]"),
+            (&b"tab\t"[..], "$HEX[74616209]"),
+            (&b"$HEX[41]"[..], "$HEX[244845585b34315d]"),
+        ] {
+            let mut buffer: Vec<u8> = Vec::new();
+            format_password(password, &mut buffer);
+            assert_eq!(String::from_utf8(buffer).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_parse_separator() {
+        assert_eq!(parse_separator("\\0").unwrap(), vec![0]);
+        assert_eq!(parse_separator("\\x1F;\\n").unwrap(), vec![0x1F, b';', b'\n']);
+        assert_eq!(parse_separator("||").unwrap(), b"||".to_vec());
+        assert_eq!(parse_separator("\\\\t").unwrap(), b"\\t".to_vec());
+        assert!(parse_separator("\\x1").is_err());
+        assert!(parse_separator("\\xzz").is_err());
+        assert!(parse_separator("\\q").is_err());
+    }
+
+    #[test]
+    fn test_parse_words() {
+        let content: &[u8] = b"password\r\n123456\n\npassword\n$HEX[e9e8]\n$HEX[zz]\ncaf\xe9\n";
+        assert_eq!(
+            parse_words(content, false),
+            vec![b"password".to_vec(), b"123456".to_vec(), b"password".to_vec(), vec![0xe9, 0xe8], b"$HEX[zz]".to_vec(), b"caf\xe9".to_vec()]
+        );
+        assert_eq!(
+            parse_words(content, true),
+            vec![b"password".to_vec(), b"123456".to_vec(), vec![0xe9, 0xe8], b"$HEX[zz]".to_vec(), b"caf\xe9".to_vec()]
+        );
+        assert!(parse_words(b"\n\r\n", true).is_empty());
+        assert!(load_words("test_missing_words.txt", false).is_err());
+    }
+
+    #[test]
+    fn test_words_config() {
+        let mut words_config: WordsConfig = WordsConfig {
+            words: vec![b"abc".to_vec(), b"de".to_vec(), vec![0xff], b"$HEX[00]".to_vec()],
+            hash: String::new(),
+            raw_separator: None,
+        };
+        assert_eq!(words_config.get_nb_of_passwords(), 4);
+        assert_eq!(words_config.get_stream(1, 2).collect::<Vec<&Vec<u8>>>(), vec![&b"de".to_vec(), &vec![0xff]]);
+        assert_eq!(words_config.get_stream(3, 10).count(), 1);
+        assert_eq!(words_config.get_stream(5, 10).count(), 0);
+        assert_eq!(words_config.get_password_size(), (3 + 2 + 8 + 22) as f64 / 4.0);
+
+        words_config.raw_separator = Some(vec![0]);
+        assert_eq!(words_config.get_password_size(), (3 + 2 + 1 + 8) as f64 / 4.0);
+    }
+
+    #[test]
+    fn test_generate_wordlist_with_words() {
+        let words: Vec<Vec<u8>> = (0..1000).map(|idx| format!("word{}", idx).into_bytes()).collect();
+        let words_config: WordsConfig = WordsConfig {
+            words: [words.clone(), vec![vec![0x01]]].concat(),
+            hash: String::new(),
+            raw_separator: None,
+        };
+        wordlist_generation_scheduler(&words_config, words_config.get_nb_of_passwords(), 3, "test19.txt", true).unwrap();
+
+        let mut lines: Vec<String> = std::fs::read_to_string("test19.txt").unwrap().lines().map(String::from).collect();
+        std::fs::remove_file("test19.txt").unwrap();
+        let mut expected_lines: Vec<String> = words.into_iter().map(|word| String::from_utf8(word).unwrap()).collect();
+        expected_lines.push(String::from("$HEX[01]"));
+        lines.sort();
+        expected_lines.sort();
+        assert_eq!(lines, expected_lines);
+    }
+
+    #[test]
+    fn test_hybrid_config() {
+        let (formated_mask, mask_indexes, byte_indexes) = format_mask_to_indexes("-?", false);
+        let mut hybrid_config: HybridConfig = HybridConfig {
+            words: vec![b"abc".to_vec(), b"de".to_vec()],
+            mask: WordlistConfig {
+                dict: vec!['1', '2', '3'],
+                mask_indexes,
+                byte_indexes,
+                formated_mask,
+                hash: String::new(),
+                raw_separator: None,
+            },
+            position: HybridPosition::Append,
+        };
+        let collect = |hybrid_config: &HybridConfig, offset: u64, nb_of_passwords: u64| -> Vec<String> {
+            let mut stream: HybridStream<'_> = hybrid_config.get_stream(offset, nb_of_passwords);
+            let mut passwords: Vec<String> = Vec::new();
+            while let Some(password) = stream.next_password() {
+                passwords.push(String::from_utf8(password.to_vec()).unwrap());
+            }
+            passwords
+        };
+
+        assert_eq!(hybrid_config.get_nb_of_passwords(), 6);
+        assert_eq!(hybrid_config.get_part_granularity(2), 3);
+        assert_eq!(hybrid_config.get_part_granularity(4), 1);
+        assert_eq!(collect(&hybrid_config, 0, 6), ["abc-1", "abc-2", "abc-3", "de-1", "de-2", "de-3"]);
+        assert_eq!(collect(&hybrid_config, 2, 2), ["abc-3", "de-1"]);
+        assert_eq!(collect(&hybrid_config, 5, 10), ["de-3"]);
+        assert!(collect(&hybrid_config, 6, 10).is_empty());
+        assert_eq!(hybrid_config.get_password_size(), 4.5);
+
+        hybrid_config.position = HybridPosition::Prepend;
+        assert_eq!(collect(&hybrid_config, 1, 3), ["-2abc", "-3abc", "-1de"]);
+
+        hybrid_config.words.push(vec![0xff]);
+        assert_eq!(hybrid_config.get_password_size(), (5.0 + 4.0 + 12.0) / 3.0);
+    }
+
+    #[test]
+    fn test_generate_wordlist_hybrid() {
+        let words: Vec<Vec<u8>> = (0..50).map(|idx| format!("word{}", idx).into_bytes()).collect();
+        let wordlist_values: WordlistValues = WordlistValues {
+            numbers: true,
+            special_characters: false,
+            uppercase: false,
+            lowercase: false,
+            charset: String::new(),
+            mask: String::from("??"),
+            hash: String::new(),
+            raw_separator: None,
+            byte_placeholder: false,
+        };
+        let hybrid_config: HybridConfig = HybridConfig {
+            words,
+            mask: build_wordlist_config(&wordlist_values),
+            position: HybridPosition::Prepend,
+        };
+        wordlist_generation_scheduler(&hybrid_config, hybrid_config.get_nb_of_passwords(), 3, "test24.txt", true).unwrap();
+
+        let mut lines: Vec<String> = std::fs::read_to_string("test24.txt").unwrap().lines().map(String::from).collect();
+        std::fs::remove_file("test24.txt").unwrap();
+        let mut expected_lines: Vec<String> = (0..50).flat_map(|word_idx| (0..100).map(move |idx| format!("{:02}word{}", idx, word_idx))).collect();
+        lines.sort();
+        expected_lines.sort();
+        assert_eq!(lines, expected_lines);
+
+        // With fewer words than threads, the threads start in the middle of a word.
+        let hybrid_config: HybridConfig = HybridConfig {
+            words: hybrid_config.words[..2].to_vec(),
+            ..hybrid_config
+        };
+        wordlist_generation_scheduler(&hybrid_config, hybrid_config.get_nb_of_passwords(), 8, "test27.txt", true).unwrap();
+
+        let mut lines: Vec<String> = std::fs::read_to_string("test27.txt").unwrap().lines().map(String::from).collect();
+        std::fs::remove_file("test27.txt").unwrap();
+        let mut expected_lines: Vec<String> = (0..2).flat_map(|word_idx| (0..100).map(move |idx| format!("{:02}word{}", idx, word_idx))).collect();
+        lines.sort();
+        expected_lines.sort();
+        assert_eq!(lines, expected_lines);
+    }
+
+    #[test]
+    fn test_combine_config() {
+        let mut combine_config: CombineConfig = CombineConfig {
+            lists: vec![vec![b"red".to_vec(), b"blue".to_vec()], vec![b"cat".to_vec(), b"dog".to_vec(), b"fox".to_vec()]],
+            separator: b"-".to_vec(),
+            hash: String::new(),
+            raw_separator: None,
+        };
+        let collect = |combine_config: &CombineConfig, offset: u64, nb_of_passwords: u64| -> Vec<Vec<u8>> {
+            let mut stream: CombineStream<'_> = combine_config.get_stream(offset, nb_of_passwords);
+            let mut passwords: Vec<Vec<u8>> = Vec::new();
+            while let Some(password) = stream.next_password() {
+                passwords.push(password.to_vec());
+            }
+            passwords
+        };
+
+        assert_eq!(combine_config.get_nb_of_passwords(), 6);
+        assert_eq!(combine_config.get_part_granularity(2), 3);
+        assert_eq!(combine_config.get_part_granularity(3), 1);
+        assert_eq!(collect(&combine_config, 0, 6), [b"red-cat".to_vec(), b"red-dog".to_vec(), b"red-fox".to_vec(), b"blue-cat".to_vec(), b"blue-dog".to_vec(), b"blue-fox".to_vec()]);
+        assert_eq!(collect(&combine_config, 2, 2), [b"red-fox".to_vec(), b"blue-cat".to_vec()]);
+        assert!(collect(&combine_config, 6, 1).is_empty());
+        assert_eq!(combine_config.get_password_size(), 7.5);
+
+        // Three-way combination, half of the passwords contain a non-printable byte.
+        combine_config.lists.push(vec![b"1".to_vec(), vec![0xff]]);
+        combine_config.separator.clear();
+        assert_eq!(combine_config.get_nb_of_passwords(), 12);
+        assert_eq!(combine_config.get_part_granularity(2), 6);
+        assert_eq!(collect(&combine_config, 3, 3), [b"reddog\xff".to_vec(), b"redfox1".to_vec(), b"redfox\xff".to_vec()]);
+        assert_eq!(combine_config.get_password_size(), 0.5 * 7.5 + 0.5 * (2.0 * 7.5 + 6.0));
+    }
+
+    #[test]
+    fn test_generate_wordlist_combined() {
+        let combine_config: CombineConfig = CombineConfig {
+            lists: vec![(0..30).map(|idx| format!("left{}", idx).into_bytes()).collect(), (0..40).map(|idx| format!("right{}", idx).into_bytes()).collect()],
+            separator: b"_".to_vec(),
+            hash: String::from("sha1"),
+            raw_separator: None,
+        };
+        wordlist_generation_scheduler(&combine_config, combine_config.get_nb_of_passwords(), 4, "test25.txt", true).unwrap();
+
+        let mut lines: Vec<String> = std::fs::read_to_string("test25.txt").unwrap().lines().map(String::from).collect();
+        std::fs::remove_file("test25.txt").unwrap();
+        let mut expected_lines: Vec<String> = (0..30)
+            .flat_map(|left_idx| (0..40).map(move |right_idx| format!("left{}_right{}", left_idx, right_idx)))
+            .map(|password| system::manage_hash(password.as_bytes(), "sha1").unwrap())
+            .collect();
+        lines.sort();
+        expected_lines.sort();
+        assert_eq!(lines, expected_lines);
+
+        // 0 threads from the library is handled as a single thread.
+        wordlist_generation_scheduler(&combine_config, combine_config.get_nb_of_passwords(), 0, "test25.txt", true).unwrap();
+        let mut lines: Vec<String> = std::fs::read_to_string("test25.txt").unwrap().lines().map(String::from).collect();
+        std::fs::remove_file("test25.txt").unwrap();
+        lines.sort();
+        assert_eq!(lines, expected_lines);
+    }
+
+    #[test]
+    fn test_generate_wordlist_part_with_bytes() {
+        let (formated_mask, mask_indexes, byte_indexes) = format_mask_to_indexes("?b", true);
+        let mut wordlist_config: WordlistConfig = WordlistConfig {
+            dict: Vec::new(),
+            mask_indexes,
+            byte_indexes,
+            formated_mask,
+            hash: String::new(),
+            raw_separator: None,
+        };
+        let run = |wordlist_config: &WordlistConfig, file_path: &str| -> Vec<u8> {
+            let file: Arc<Mutex<File>> = Arc::new(Mutex::new(File::create(file_path).unwrap()));
+            generate_wordlist_part(3, 0x40, wordlist_config, &file, &RunContext::new(ProgressGoal::Passwords(3), true)).unwrap();
+            let content: Vec<u8> = std::fs::read(file_path).unwrap();
+            std::fs::remove_file(file_path).unwrap();
+            content
+        };
+
+        assert_eq!(run(&wordlist_config, "test21.txt"), b"@\nA\nB\n".to_vec());
+        assert_eq!(get_password_size(&wordlist_config), (95.0 * 1.0 + 161.0 * 8.0) / 256.0);
+
+        wordlist_config.raw_separator = Some(vec![0]);
+        assert_eq!(run(&wordlist_config, "test22.txt"), b"@\0A\0B\0".to_vec());
+        assert_eq!(get_password_size(&wordlist_config), 1.0);
+
+        // The raw bytes are hashed, not the $HEX[...] notation.
+        wordlist_config.raw_separator = None;
+        wordlist_config.hash = String::from("md5");
+        let content: Vec<u8> = {
+            let (formated_mask, mask_indexes, byte_indexes) = format_mask_to_indexes("\\x?b", true);
+            let wordlist_config: WordlistConfig = WordlistConfig { formated_mask, mask_indexes, byte_indexes, ..wordlist_config.clone() };
+            let file: Arc<Mutex<File>> = Arc::new(Mutex::new(File::create("test23.txt").unwrap()));
+            generate_wordlist_part(1, 0, &wordlist_config, &file, &RunContext::new(ProgressGoal::Passwords(1), true)).unwrap();
+            let content: Vec<u8> = std::fs::read("test23.txt").unwrap();
+            std::fs::remove_file("test23.txt").unwrap();
+            content
+        };
+        assert_eq!(String::from_utf8(content).unwrap(), format!("{}\n", system::manage_hash(b"x\x00", "md5").unwrap()));
+    }
+
+    #[test]
+    fn test_generate_wordlist_part_with_fixed_characters() {
+        let (formated_mask, mask_indexes, byte_indexes) = format_mask_to_indexes("x?-?", false);
+        let wordlist_config: WordlistConfig = WordlistConfig {
+            dict: vec!['a', 'b'],
+            mask_indexes,
+            byte_indexes,
+            formated_mask,
+            hash: String::new(),
+            raw_separator: None,
+        };
+        let file: Arc<Mutex<File>> = Arc::new(Mutex::new(File::create("test15.txt").unwrap()));
+        let result: Result<(), WorgenXError> = generate_wordlist_part(
+            3,
+            1,
+            &wordlist_config,
+            &Arc::clone(&file),
+            &RunContext::new(ProgressGoal::Passwords(3), true),
+        );
+        assert!(result.is_ok());
+
+        let content: String = std::fs::read_to_string("test15.txt").unwrap();
+        assert_eq!(content, "xa-b\nxb-a\nxb-b\n");
+        std::fs::remove_file("test15.txt").unwrap();
+    }
+
+    #[test]
+    fn test_concurrent_wordlist_generations() {
+        let wordlist_values: WordlistValues = WordlistValues {
+            numbers: true,
+            special_characters: false,
+            uppercase: false,
+            lowercase: false,
+            charset: String::new(),
+            mask: String::from("??"),
+            hash: String::from(""),
+            raw_separator: None,
+            byte_placeholder: false,
+        };
+        let wordlist_config: Arc<WordlistConfig> = Arc::new(build_wordlist_config(&wordlist_values));
+        let generations: Vec<JoinHandle<Result<(), WorgenXError>>> = ["test16.txt", "test17.txt"]
+            .into_iter()
+            .map(|file_path| {
+                let wordlist_config: Arc<WordlistConfig> = Arc::clone(&wordlist_config);
+                thread::spawn(move || wordlist_generation_scheduler(wordlist_config.as_ref(), 100, 2, file_path, true))
+            })
+            .collect();
+
+        for generation in generations {
+            assert!(generation.join().unwrap().is_ok());
+        }
+        for file_path in ["test16.txt", "test17.txt"] {
+            let mut lines: Vec<String> = std::fs::read_to_string(file_path).unwrap().lines().map(String::from).collect();
+            lines.sort();
+            assert_eq!(lines.len(), 100);
+            assert_eq!(lines.first().unwrap(), "00");
+            assert_eq!(lines.last().unwrap(), "99");
+            std::fs::remove_file(file_path).unwrap();
+        }
+    }
+
     #[test]
     fn test_generate_wordlist_part_without_hash() {
         let nb_of_passwords: u64 = 10;
-        let dict_indexes: Vec<usize> = vec![0, 0, 0, 0];
-        let formated_mask: Vec<char> = vec!['\0', '\0', '\0', '\0'];
-        let mask_indexes: Vec<usize> = vec![0, 1, 2, 3];
-        let dict: Vec<u8> = vec![b'a', b'b', b'c', b'd'];
+        let offset: u64 = 0;
+        let wordlist_config: WordlistConfig = WordlistConfig {
+            dict: vec!['a', 'b', 'c', 'd'],
+            mask_indexes: vec![0, 1, 2, 3],
+            byte_indexes: Vec::new(),
+            formated_mask: vec!['\0', '\0', '\0', '\0'],
+            hash: String::from(""),
+            raw_separator: None,
+        };
         let file: Arc<Mutex<File>> = Arc::new(Mutex::new(File::create("test1.txt").unwrap()));
+        let context: RunContext = RunContext::new(ProgressGoal::Passwords(nb_of_passwords), true);
         let result: Result<(), WorgenXError> = generate_wordlist_part(
             nb_of_passwords,
-            dict_indexes,
-            &formated_mask,
-            &mask_indexes,
-            &dict,
+            offset,
+            &wordlist_config,
             &Arc::clone(&file),
-            "",
+            &context,
         );
         assert!(result.is_ok());
+        assert_eq!(context.progress.get_nb_of_passwords(), 10);
 
         let content: String = std::fs::read_to_string("test1.txt").unwrap();
         let expected_content: String = String::from("aaaa\naaab\naaac\naaad\naaba\naabb\naabc\naabd\naaca\naacb\n");
@@ -465,19 +1771,22 @@ mod tests {
     #[test]
     fn test_generate_wordlist_part_with_md5_hash() {
         let nb_of_passwords: u64 = 16;
-        let dict_indexes: Vec<usize> = vec![0, 0];
-        let formated_mask: Vec<char> = vec!['\0', '\0'];
-        let mask_indexes: Vec<usize> = vec![0, 1];
-        let dict: Vec<u8> = vec![b'0', b'1', b'2', b'3'];
+        let offset: u64 = 0;
+        let wordlist_config: WordlistConfig = WordlistConfig {
+            dict: vec!['0', '1', '2', '3'],
+            mask_indexes: vec![0, 1],
+            byte_indexes: Vec::new(),
+            formated_mask: vec!['\0', '\0'],
+            hash: String::from("md5"),
+            raw_separator: None,
+        };
         let file: Arc<Mutex<File>> = Arc::new(Mutex::new(File::create("test2.txt").unwrap()));
         let result: Result<(), WorgenXError> = generate_wordlist_part(
             nb_of_passwords,
-            dict_indexes,
-            &formated_mask,
-            &mask_indexes,
-            &dict,
+            offset,
+            &wordlist_config,
             &Arc::clone(&file),
-            "md5",
+            &RunContext::new(ProgressGoal::Passwords(16), true),
         );
         assert!(result.is_ok());
 
@@ -509,19 +1818,22 @@ c16a5320fa475530d9583c34fd356ef5
     #[test]
     fn test_generate_wordlist_part_with_sha1_hash() {
         let nb_of_passwords: u64 = 16;
-        let dict_indexes: Vec<usize> = vec![0, 0];
-        let formated_mask: Vec<char> = vec!['\0', '\0'];
-        let mask_indexes: Vec<usize> = vec![0, 1];
-        let dict: Vec<u8> = vec![b'0', b'1', b'2', b'3'];
+        let offset: u64 = 0;
+        let wordlist_config: WordlistConfig = WordlistConfig {
+            dict: vec!['0', '1', '2', '3'],
+            mask_indexes: vec![0, 1],
+            byte_indexes: Vec::new(),
+            formated_mask: vec!['\0', '\0'],
+            hash: String::from("sha1"),
+            raw_separator: None,
+        };
         let file: Arc<Mutex<File>> = Arc::new(Mutex::new(File::create("test3.txt").unwrap()));
         let result: Result<(), WorgenXError> = generate_wordlist_part(
             nb_of_passwords,
-            dict_indexes,
-            &formated_mask,
-            &mask_indexes,
-            &dict,
+            offset,
+            &wordlist_config,
             &Arc::clone(&file),
-            "sha1",
+            &RunContext::new(ProgressGoal::Passwords(16), true),
         );
         assert!(result.is_ok());
 
@@ -553,19 +1865,22 @@ b6692ea5df920cad691c20319a6fffd7a4a766b8
     #[test]
     fn test_generate_wordlist_part_with_sha224_hash() {
         let nb_of_passwords: u64 = 16;
-        let dict_indexes: Vec<usize> = vec![0, 0];
-        let formated_mask: Vec<char> = vec!['\0', '\0'];
-        let mask_indexes: Vec<usize> = vec![0, 1];
-        let dict: Vec<u8> = vec![b'0', b'1', b'2', b'3'];
+        let offset: u64 = 0;
+        let wordlist_config: WordlistConfig = WordlistConfig {
+            dict: vec!['0', '1', '2', '3'],
+            mask_indexes: vec![0, 1],
+            byte_indexes: Vec::new(),
+            formated_mask: vec!['\0', '\0'],
+            hash: String::from("sha224"),
+            raw_separator: None,
+        };
         let file: Arc<Mutex<File>> = Arc::new(Mutex::new(File::create("test4.txt").unwrap()));
         let result: Result<(), WorgenXError> = generate_wordlist_part(
             nb_of_passwords,
-            dict_indexes,
-            &formated_mask,
-            &mask_indexes,
-            &dict,
+            offset,
+            &wordlist_config,
             &Arc::clone(&file),
-            "sha224",
+            &RunContext::new(ProgressGoal::Passwords(16), true),
         );
         assert!(result.is_ok());
 
@@ -597,19 +1912,22 @@ f193e8b0c6e8e436ed6fbff804917367733cddc04514d1865452f399
     #[test]
     fn test_generate_wordlist_part_with_sha256_hash() {
         let nb_of_passwords: u64 = 16;
-        let dict_indexes: Vec<usize> = vec![0, 0];
-        let formated_mask: Vec<char> = vec!['\0', '\0'];
-        let mask_indexes: Vec<usize> = vec![0, 1];
-        let dict: Vec<u8> = vec![b'0', b'1', b'2', b'3'];
+        let offset: u64 = 0;
+        let wordlist_config: WordlistConfig = WordlistConfig {
+            dict: vec!['0', '1', '2', '3'],
+            mask_indexes: vec![0, 1],
+            byte_indexes: Vec::new(),
+            formated_mask: vec!['\0', '\0'],
+            hash: String::from("sha256"),
+            raw_separator: None,
+        };
         let file: Arc<Mutex<File>> = Arc::new(Mutex::new(File::create("test5.txt").unwrap()));
         let result: Result<(), WorgenXError> = generate_wordlist_part(
             nb_of_passwords,
-            dict_indexes,
-            &formated_mask,
-            &mask_indexes,
-            &dict,
+            offset,
+            &wordlist_config,
             &Arc::clone(&file),
-            "sha256",
+            &RunContext::new(ProgressGoal::Passwords(16), true),
         );
         assert!(result.is_ok());
 
@@ -641,19 +1959,22 @@ c6f3ac57944a531490cd39902d0f777715fd005efac9a30622d5f5205e7f6894
     #[test]
     fn test_generate_wordlist_part_with_sha384_hash() {
         let nb_of_passwords: u64 = 16;
-        let dict_indexes: Vec<usize> = vec![0, 0];
-        let formated_mask: Vec<char> = vec!['\0', '\0'];
-        let mask_indexes: Vec<usize> = vec![0, 1];
-        let dict: Vec<u8> = vec![b'0', b'1', b'2', b'3'];
+        let offset: u64 = 0;
+        let wordlist_config: WordlistConfig = WordlistConfig {
+            dict: vec!['0', '1', '2', '3'],
+            mask_indexes: vec![0, 1],
+            byte_indexes: Vec::new(),
+            formated_mask: vec!['\0', '\0'],
+            hash: String::from("sha384"),
+            raw_separator: None,
+        };
         let file: Arc<Mutex<File>> = Arc::new(Mutex::new(File::create("test6.txt").unwrap()));
         let result: Result<(), WorgenXError> = generate_wordlist_part(
             nb_of_passwords,
-            dict_indexes,
-            &formated_mask,
-            &mask_indexes,
-            &dict,
+            offset,
+            &wordlist_config,
             &Arc::clone(&file),
-            "sha384",
+            &RunContext::new(ProgressGoal::Passwords(16), true),
         );
         assert!(result.is_ok());
 
@@ -683,19 +2004,22 @@ fd21efb0c2863b1d2460f7e6048d757beb2326c6e1bbee5194826be2c626a9de3bc8d6f2488617e5
     #[test]
     fn test_generate_wordlist_part_with_sha512_hash() {
         let nb_of_passwords: u64 = 16;
-        let dict_indexes: Vec<usize> = vec![0, 0];
-        let formated_mask: Vec<char> = vec!['\0', '\0'];
-        let mask_indexes: Vec<usize> = vec![0, 1];
-        let dict: Vec<u8> = vec![b'0', b'1', b'2', b'3'];
+        let offset: u64 = 0;
+        let wordlist_config: WordlistConfig = WordlistConfig {
+            dict: vec!['0', '1', '2', '3'],
+            mask_indexes: vec![0, 1],
+            byte_indexes: Vec::new(),
+            formated_mask: vec!['\0', '\0'],
+            hash: String::from("sha512"),
+            raw_separator: None,
+        };
         let file: Arc<Mutex<File>> = Arc::new(Mutex::new(File::create("test7.txt").unwrap()));
         let result: Result<(), WorgenXError> = generate_wordlist_part(
             nb_of_passwords,
-            dict_indexes,
-            &formated_mask,
-            &mask_indexes,
-            &dict,
+            offset,
+            &wordlist_config,
             &Arc::clone(&file),
-            "sha512",
+            &RunContext::new(ProgressGoal::Passwords(16), true),
         );
         assert!(result.is_ok());
 
@@ -725,19 +2049,22 @@ e63006bd9f35f06cd20582fc8b34ae76a15080297be886decd6dfd42f59e5174a537e8cd92ef5772
     #[test]
     fn test_generate_wordlist_part_with_sha3_224_hash() {
         let nb_of_passwords: u64 = 16;
-        let dict_indexes: Vec<usize> = vec![0, 0];
-        let formated_mask: Vec<char> = vec!['\0', '\0'];
-        let mask_indexes: Vec<usize> = vec![0, 1];
-        let dict: Vec<u8> = vec![b'0', b'1', b'2', b'3'];
+        let offset: u64 = 0;
+        let wordlist_config: WordlistConfig = WordlistConfig {
+            dict: vec!['0', '1', '2', '3'],
+            mask_indexes: vec![0, 1],
+            byte_indexes: Vec::new(),
+            formated_mask: vec!['\0', '\0'],
+            hash: String::from("sha3-224"),
+            raw_separator: None,
+        };
         let file: Arc<Mutex<File>> = Arc::new(Mutex::new(File::create("test8.txt").unwrap()));
         let result: Result<(), WorgenXError> = generate_wordlist_part(
             nb_of_passwords,
-            dict_indexes,
-            &formated_mask,
-            &mask_indexes,
-            &dict,
+            offset,
+            &wordlist_config,
             &Arc::clone(&file),
-            "sha3-224",
+            &RunContext::new(ProgressGoal::Passwords(16), true),
         );
         assert!(result.is_ok());
 
@@ -769,19 +2096,22 @@ b89f89ea282cc149f04623635c24f5a4ca6671200c07673080c9f201
     #[test]
     fn test_generate_wordlist_part_with_sha3_256_hash() {
         let nb_of_passwords: u64 = 16;
-        let dict_indexes: Vec<usize> = vec![0, 0];
-        let formated_mask: Vec<char> = vec!['\0', '\0'];
-        let mask_indexes: Vec<usize> = vec![0, 1];
-        let dict: Vec<u8> = vec![b'0', b'1', b'2', b'3'];
+        let offset: u64 = 0;
+        let wordlist_config: WordlistConfig = WordlistConfig {
+            dict: vec!['0', '1', '2', '3'],
+            mask_indexes: vec![0, 1],
+            byte_indexes: Vec::new(),
+            formated_mask: vec!['\0', '\0'],
+            hash: String::from("sha3-256"),
+            raw_separator: None,
+        };
         let file: Arc<Mutex<File>> = Arc::new(Mutex::new(File::create("test9.txt").unwrap()));
         let result: Result<(), WorgenXError> = generate_wordlist_part(
             nb_of_passwords,
-            dict_indexes,
-            &formated_mask,
-            &mask_indexes,
-            &dict,
+            offset,
+            &wordlist_config,
             &Arc::clone(&file),
-            "sha3-256",
+            &RunContext::new(ProgressGoal::Passwords(16), true),
         );
         assert!(result.is_ok());
 
@@ -813,19 +2143,22 @@ f372fd5a0bce0ade7c2339a622d124fd1950a9bc0584611f8c334931e39ced32
     #[test]
     fn test_generate_wordlist_part_with_sha3_384_hash() {
         let nb_of_passwords: u64 = 16;
-        let dict_indexes: Vec<usize> = vec![0, 0];
-        let formated_mask: Vec<char> = vec!['\0', '\0'];
-        let mask_indexes: Vec<usize> = vec![0, 1];
-        let dict: Vec<u8> = vec![b'0', b'1', b'2', b'3'];
+        let offset: u64 = 0;
+        let wordlist_config: WordlistConfig = WordlistConfig {
+            dict: vec!['0', '1', '2', '3'],
+            mask_indexes: vec![0, 1],
+            byte_indexes: Vec::new(),
+            formated_mask: vec!['\0', '\0'],
+            hash: String::from("sha3-384"),
+            raw_separator: None,
+        };
         let file: Arc<Mutex<File>> = Arc::new(Mutex::new(File::create("test10.txt").unwrap()));
         let result: Result<(), WorgenXError> = generate_wordlist_part(
             nb_of_passwords,
-            dict_indexes,
-            &formated_mask,
-            &mask_indexes,
-            &dict,
+            offset,
+            &wordlist_config,
             &Arc::clone(&file),
-            "sha3-384",
+            &RunContext::new(ProgressGoal::Passwords(16), true),
         );
         assert!(result.is_ok());
 
@@ -855,19 +2188,22 @@ c7441fbb2b270adfff0ef242adc120ed41cc7d3ad0550555dd0d19bd2caa8b87dcae4ebfdf14dd06
     #[test]
     fn test_generate_wordlist_part_with_sha3_512_hash() {
         let nb_of_passwords: u64 = 16;
-        let dict_indexes: Vec<usize> = vec![0, 0];
-        let formated_mask: Vec<char> = vec!['\0', '\0'];
-        let mask_indexes: Vec<usize> = vec![0, 1];
-        let dict: Vec<u8> = vec![b'0', b'1', b'2', b'3'];
+        let offset: u64 = 0;
+        let wordlist_config: WordlistConfig = WordlistConfig {
+            dict: vec!['0', '1', '2', '3'],
+            mask_indexes: vec![0, 1],
+            byte_indexes: Vec::new(),
+            formated_mask: vec!['\0', '\0'],
+            hash: String::from("sha3-512"),
+            raw_separator: None,
+        };
         let file: Arc<Mutex<File>> = Arc::new(Mutex::new(File::create("test11.txt").unwrap()));
         let result: Result<(), WorgenXError> = generate_wordlist_part(
             nb_of_passwords,
-            dict_indexes,
-            &formated_mask,
-            &mask_indexes,
-            &dict,
+            offset,
+            &wordlist_config,
             &Arc::clone(&file),
-            "sha3-512",
+            &RunContext::new(ProgressGoal::Passwords(16), true),
         );
         assert!(result.is_ok());
 
@@ -897,19 +2233,22 @@ a114cd1299fea4e32d86d9bc877dddf99dee3bf306477e744e894d25a81c713c7d7109883779e6f3
     #[test]
     fn test_generate_wordlist_part_with_blake2b_512_hash() {
         let nb_of_passwords: u64 = 16;
-        let dict_indexes: Vec<usize> = vec![0, 0];
-        let formated_mask: Vec<char> = vec!['\0', '\0'];
-        let mask_indexes: Vec<usize> = vec![0, 1];
-        let dict: Vec<u8> = vec![b'0', b'1', b'2', b'3'];
+        let offset: u64 = 0;
+        let wordlist_config: WordlistConfig = WordlistConfig {
+            dict: vec!['0', '1', '2', '3'],
+            mask_indexes: vec![0, 1],
+            byte_indexes: Vec::new(),
+            formated_mask: vec!['\0', '\0'],
+            hash: String::from("blake2b-512"),
+            raw_separator: None,
+        };
         let file: Arc<Mutex<File>> = Arc::new(Mutex::new(File::create("test12.txt").unwrap()));
         let result: Result<(), WorgenXError> = generate_wordlist_part(
             nb_of_passwords,
-            dict_indexes,
-            &formated_mask,
-            &mask_indexes,
-            &dict,
+            offset,
+            &wordlist_config,
             &Arc::clone(&file),
-            "blake2b-512",
+            &RunContext::new(ProgressGoal::Passwords(16), true),
         );
         assert!(result.is_ok());
 
@@ -940,19 +2279,22 @@ afd85ebe3a733ccf64c11c681e05595490854c4a485be585dfff1f2c1958823a0fa11069467c8260
     #[test]
     fn test_generate_wordlist_part_with_blake2s_256_hash() {
         let nb_of_passwords: u64 = 16;
-        let dict_indexes: Vec<usize> = vec![0, 0];
-        let formated_mask: Vec<char> = vec!['\0', '\0'];
-        let mask_indexes: Vec<usize> = vec![0, 1];
-        let dict: Vec<u8> = vec![b'0', b'1', b'2', b'3'];
+        let offset: u64 = 0;
+        let wordlist_config: WordlistConfig = WordlistConfig {
+            dict: vec!['0', '1', '2', '3'],
+            mask_indexes: vec![0, 1],
+            byte_indexes: Vec::new(),
+            formated_mask: vec!['\0', '\0'],
+            hash: String::from("blake2s-256"),
+            raw_separator: None,
+        };
         let file: Arc<Mutex<File>> = Arc::new(Mutex::new(File::create("test13.txt").unwrap()));
         let result: Result<(), WorgenXError> = generate_wordlist_part(
             nb_of_passwords,
-            dict_indexes,
-            &formated_mask,
-            &mask_indexes,
-            &dict,
+            offset,
+            &wordlist_config,
             &Arc::clone(&file),
-            "blake2s-256",
+            &RunContext::new(ProgressGoal::Passwords(16), true),
         );
         assert!(result.is_ok());
 
@@ -985,19 +2327,22 @@ fd5f8f39e9a41e624fb837cd766f55805bd120aac42fe4bc426497eeb7aeaacf
     #[test]
     fn test_generate_wordlist_part_with_whirlpool() {
         let nb_of_passwords: u64 = 16;
-        let dict_indexes: Vec<usize> = vec![0, 0];
-        let formated_mask: Vec<char> = vec!['\0', '\0'];
-        let mask_indexes: Vec<usize> = vec![0, 1];
-        let dict: Vec<u8> = vec![b'0', b'1', b'2', b'3'];
+        let offset: u64 = 0;
+        let wordlist_config: WordlistConfig = WordlistConfig {
+            dict: vec!['0', '1', '2', '3'],
+            mask_indexes: vec![0, 1],
+            byte_indexes: Vec::new(),
+            formated_mask: vec!['\0', '\0'],
+            hash: String::from("whirlpool"),
+            raw_separator: None,
+        };
         let file: Arc<Mutex<File>> = Arc::new(Mutex::new(File::create("test14.txt").unwrap()));
         let result: Result<(), WorgenXError> = generate_wordlist_part(
             nb_of_passwords,
-            dict_indexes,
-            &formated_mask,
-            &mask_indexes,
-            &dict,
+            offset,
+            &wordlist_config,
             &Arc::clone(&file),
-            "whirlpool",
+            &RunContext::new(ProgressGoal::Passwords(16), true),
         );
         assert!(result.is_ok());
 